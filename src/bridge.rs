@@ -0,0 +1,129 @@
+use cosmwasm_std::{CanonicalAddr, HumanAddr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::Mint;
+use crate::rand::sha_256;
+
+/// stored information about a token held in escrow while it is bridged to another chain
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct StoredBridgeLock {
+    /// address that owned the token when it was locked
+    pub previous_owner: CanonicalAddr,
+    /// XP.network nonce of the chain the token was sent to
+    pub chain_nonce: u64,
+    /// address on the destination chain that receives the token
+    pub destination_address: String,
+    /// time the token was locked (in seconds since 01/01/1970)
+    pub time_locked: u64,
+}
+
+/// a burn that sent tokens back to another chain
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct BridgeBurn {
+    /// sequence number of the burn.  These increase by one with every BurnForBridge
+    pub burn_seq: u64,
+    /// unique id supplied by the relayer that requested the burn
+    pub relayer_action_id: Uint128,
+    /// ids of the burned tokens
+    pub token_ids: Vec<String>,
+    /// XP.network nonce of the destination chain
+    pub chain_nonce: u64,
+    /// address on the destination chain that receives the tokens
+    pub destination_address: String,
+    /// bridge fee paid (in uscrt)
+    pub fee: Uint128,
+    /// the block containing the burn
+    pub block_height: u64,
+    /// the time (in seconds since 01/01/1970) of the block containing the burn
+    pub block_time: u64,
+}
+
+/// where a wrapped token originally came from
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct OriginInfo {
+    /// XP.network nonce of the origin chain
+    pub chain_nonce: u64,
+    /// address of the token's contract on the origin chain
+    pub contract_address: String,
+    /// id of the token on the origin chain
+    pub token_id: String,
+    /// uri of the token on the origin chain
+    pub token_uri: String,
+}
+
+impl OriginInfo {
+    /// Returns [u8; 32] key used to index a token by its origin coordinates
+    pub fn index_key(&self) -> [u8; 32] {
+        origin_index_key(self.chain_nonce, &self.contract_address, &self.token_id)
+    }
+}
+
+/// Returns [u8; 32] key used to index a token by its origin coordinates
+///
+/// # Arguments
+///
+/// * `chain_nonce` - XP.network nonce of the origin chain
+/// * `contract_address` - address of the token's contract on the origin chain
+/// * `token_id` - id of the token on the origin chain
+pub fn origin_index_key(chain_nonce: u64, contract_address: &str, token_id: &str) -> [u8; 32] {
+    // length prefix the address so different address/id splits can not collide
+    let mut preimage = chain_nonce.to_le_bytes().to_vec();
+    preimage.extend_from_slice(&(contract_address.len() as u64).to_le_bytes());
+    preimage.extend_from_slice(contract_address.as_bytes());
+    preimage.extend_from_slice(token_id.as_bytes());
+    sha_256(&preimage)
+}
+
+/// kinds of bridge actions.  Each kind has its own space of action ids
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeActionKind {
+    /// a mint approved through ProposeMint/VoteMint
+    Mint,
+    /// a BridgeUnlock by the bridge validator
+    Unlock,
+    /// a BurnForBridge
+    Burn,
+}
+
+/// the validators that must approve bridge mints
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ValidatorSet {
+    /// validator addresses
+    pub validators: Vec<CanonicalAddr>,
+    /// number of validator votes needed to execute a mint proposal
+    pub threshold: u32,
+}
+
+/// stored mint proposal awaiting validator votes
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredMintProposal {
+    /// the mint to perform once quorum is reached
+    pub mint: Mint,
+    /// bridge action id that is consumed when the mint is performed
+    pub action_id: Uint128,
+    /// validator that proposed the mint
+    pub proposer: CanonicalAddr,
+    /// validators that have voted for the mint
+    pub votes: Vec<CanonicalAddr>,
+}
+
+/// a mint proposal awaiting validator votes
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct MintProposal {
+    /// proposal id
+    pub proposal_id: u64,
+    /// bridge action id of the mint
+    pub action_id: Uint128,
+    /// optional token id that will be minted
+    pub token_id: Option<String>,
+    /// optional owner of the token that will be minted
+    pub owner: Option<HumanAddr>,
+    /// origin of the wrapped token, if provided
+    pub origin: Option<OriginInfo>,
+    /// validators that proposed or voted for the mint
+    pub voters: Vec<HumanAddr>,
+    /// number of votes that count toward the current threshold
+    pub vote_count: u32,
+}
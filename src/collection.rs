@@ -0,0 +1,94 @@
+use cosmwasm_std::{Api, CanonicalAddr, HumanAddr, StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// collection-level metadata used by marketplaces to display the collection
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+pub struct CollectionInfo {
+    /// optional description of the collection
+    pub description: Option<String>,
+    /// optional url of the collection's image
+    pub image: Option<String>,
+    /// optional url of the collection's external website
+    pub external_link: Option<String>,
+    /// optional url of the collection's banner image
+    pub banner_image: Option<String>,
+    /// optional category of the collection
+    pub category: Option<String>,
+    /// optional marketplace seller fee in basis points (1/100th of a percent)
+    pub seller_fee_basis_points: Option<u16>,
+    /// optional address to send seller fees to
+    pub fee_recipient: Option<HumanAddr>,
+}
+
+impl CollectionInfo {
+    /// Returns StdResult<StoredCollectionInfo> from creating a StoredCollectionInfo from a
+    /// CollectionInfo
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn to_stored<A: Api>(&self, api: &A) -> StdResult<StoredCollectionInfo> {
+        if self.seller_fee_basis_points.unwrap_or(0) > 10000 {
+            return Err(StdError::generic_err(
+                "Seller fee basis points can not exceed 10000",
+            ));
+        }
+        Ok(StoredCollectionInfo {
+            description: self.description.clone(),
+            image: self.image.clone(),
+            external_link: self.external_link.clone(),
+            banner_image: self.banner_image.clone(),
+            category: self.category.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            fee_recipient: self
+                .fee_recipient
+                .as_ref()
+                .map(|r| api.canonical_address(r))
+                .transpose()?,
+        })
+    }
+}
+
+/// stored collection-level metadata
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct StoredCollectionInfo {
+    /// optional description of the collection
+    pub description: Option<String>,
+    /// optional url of the collection's image
+    pub image: Option<String>,
+    /// optional url of the collection's external website
+    pub external_link: Option<String>,
+    /// optional url of the collection's banner image
+    pub banner_image: Option<String>,
+    /// optional category of the collection
+    pub category: Option<String>,
+    /// optional marketplace seller fee in basis points (1/100th of a percent)
+    pub seller_fee_basis_points: Option<u16>,
+    /// optional address to send seller fees to
+    pub fee_recipient: Option<CanonicalAddr>,
+}
+
+impl StoredCollectionInfo {
+    /// Returns StdResult<CollectionInfo> from creating a CollectionInfo from a
+    /// StoredCollectionInfo
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn to_human<A: Api>(&self, api: &A) -> StdResult<CollectionInfo> {
+        Ok(CollectionInfo {
+            description: self.description.clone(),
+            image: self.image.clone(),
+            external_link: self.external_link.clone(),
+            banner_image: self.banner_image.clone(),
+            category: self.category.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            fee_recipient: self
+                .fee_recipient
+                .as_ref()
+                .map(|r| api.human_address(r))
+                .transpose()?,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use cosmwasm_std::{
-    log, to_binary, Api, Binary, BlockInfo, CanonicalAddr, CosmosMsg, Env, Extern, HandleResponse,
-    HandleResult, HumanAddr, InitResponse, InitResult, Querier, QueryResult, ReadonlyStorage,
-    StdError, StdResult, Storage, WasmMsg,
+    from_binary, log, to_binary, Api, BankMsg, Binary, BlockInfo, CanonicalAddr, Coin, CosmosMsg,
+    Env, Extern, HandleResponse, HandleResult, HumanAddr, InitResponse, InitResult, Querier,
+    QueryResult, ReadonlyStorage, StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
 use primitive_types::U256;
@@ -14,26 +14,52 @@ use secret_toolkit::{
     utils::{pad_handle_result, pad_query_result},
 };
 
+use crate::bridge::{
+    origin_index_key, BridgeActionKind, BridgeBurn, MintProposal, OriginInfo, StoredBridgeLock,
+    StoredMintProposal, ValidatorSet,
+};
+use crate::collection::{CollectionInfo, StoredCollectionInfo};
 use crate::expiration::Expiration;
 use crate::inventory::{Inventory, InventoryIter};
+use crate::market::{
+    split_sale_price, Offer, StoredAuction, StoredBid, StoredListing, StoredOffer,
+    AUCTION_EXTENSION,
+};
 use crate::mint_run::{SerialNumber, StoredMintRunInfo};
 use crate::msg::{
     AccessLevel, BatchNftDossierElement, Burn, ContractStatus, Cw721Approval, Cw721OwnerOfResponse,
-    HandleAnswer, HandleMsg, InitMsg, Mint, QueryAnswer, QueryMsg, QueryWithPermit, ReceiverInfo,
-    ResponseStatus::Success, Send, Snip721Approval, Transfer, ViewerInfo,
+    HandleAnswer, HandleMsg, InitMsg, LineageToken, Mint, QueryAnswer, QueryMsg, QueryWithPermit,
+    ReceiveMsg, ReceiverInfo, ResponseStatus::Success, Send, Snip721Approval, TraitTable, Transfer,
+    ViewerInfo,
 };
-use crate::rand::sha_256;
+use crate::provenance::{chain_link, StoredProvenance};
+use crate::rand::{sha_256, Prng};
 use crate::receiver::{batch_receive_nft_msg, receive_nft_msg};
 use crate::royalties::{RoyaltyInfo, StoredRoyaltyInfo};
+use crate::sale::{
+    register_receive_msg, snip20_transfer_msg, PaymentToken, PresaleEntry, SaleConfig,
+    StoredPaymentToken,
+};
 use crate::state::{
-    get_txs, json_may_load, json_save, load, may_load, remove, save, store_burn, store_mint,
-    store_transfer, AuthList, Config, Permission, PermissionType, ReceiveRegistration, BLOCK_KEY,
-    CONFIG_KEY, CREATOR_KEY, DEFAULT_ROYALTY_KEY, MINTERS_KEY, MY_ADDRESS_KEY,
-    PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST, PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX,
-    PREFIX_MINT_RUN, PREFIX_MINT_RUN_NUM, PREFIX_OWNER_PRIV, PREFIX_PRIV_META, PREFIX_PUB_META,
-    PREFIX_RECEIVERS, PREFIX_REVOKED_PERMITS, PREFIX_ROYALTY_INFO, PREFIX_VIEW_KEY, PRNG_SEED_KEY,
+    get_txs, json_load, json_may_load, json_save, load, may_load, remove, save, store_bridge_burn,
+    store_burn, store_compose, store_mint, store_recover, store_revoke, store_transfer, AuthList,
+    Config, MinterQuota, Permission, PermissionType, ReceiveRegistration, BLOCK_KEY,
+    BRIDGE_VALIDATOR_KEY, COLLECTION_INFO_KEY, CONFIG_KEY, CREATOR_KEY, DEFAULT_ROYALTY_KEY,
+    MINTERS_KEY, MINTER_QUOTAS_KEY, MY_ADDRESS_KEY, PAYMENT_TOKENS_KEY, PENDING_PROPOSALS_KEY,
+    PREFIX_ALL_PERMISSIONS, PREFIX_AUCTIONS, PREFIX_AUTHLIST, PREFIX_BRIDGE_BURNS,
+    PREFIX_BRIDGE_LOCK, PREFIX_CHILDREN, PREFIX_CONSUMED_BURNS, PREFIX_CONSUMED_MINTS,
+    PREFIX_CONSUMED_UNLOCKS, PREFIX_DERIVED, PREFIX_INFOS, PREFIX_LINEAGE, PREFIX_LISTINGS,
+    PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_META_HISTORY, PREFIX_MINT_PROPOSALS,
+    PREFIX_MINT_RUN, PREFIX_MINT_RUN_NUM, PREFIX_OFFERS, PREFIX_ORIGIN, PREFIX_ORIGIN_INDEX,
+    PREFIX_OWNER_PRIV, PREFIX_PARENT, PREFIX_PRESALE, PREFIX_PRIV_META, PREFIX_PROPOSED_MINTS,
+    PREFIX_PUB_META, PREFIX_PURCHASES, PREFIX_RECEIVERS, PREFIX_REVOKED_PERMITS,
+    PREFIX_ROYALTY_INFO, PREFIX_VIEW_KEY, PRESALE_CONFIG_KEY, PRESALE_EPOCH_KEY, PRNG_SEED_KEY,
+    PROCEEDS_KEY, PROVENANCE_KEY, SALE_CONFIG_KEY, TRAIT_SCHEMA_KEY, VALIDATORS_KEY,
+};
+use crate::token::{
+    Extension, Metadata, StoredMetadataVersion, StoredUser, Token, TokenUser, Trait,
 };
-use crate::token::{Metadata, Token};
+use crate::trait_schema::TraitSchema;
 use crate::viewing_key::{ViewingKey, VIEWING_KEY_SIZE};
 
 /// pad handle responses and log attributes to blocks of 256 bytes to prevent leaking info based on
@@ -41,6 +67,14 @@ use crate::viewing_key::{ViewingKey, VIEWING_KEY_SIZE};
 pub const BLOCK_SIZE: usize = 256;
 /// max number of token ids to keep in id list block
 pub const ID_BLOCK_SIZE: u32 = 64;
+/// max number of mint proposals that may be pending at once
+pub const MAX_PENDING_PROPOSALS: usize = 100;
+/// max number of unexpired offers that may be made on a token at once
+pub const MAX_OFFERS_PER_TOKEN: usize = 30;
+/// number of prior metadata versions kept for each token if no limit is configured
+pub const DEFAULT_METADATA_HISTORY_LIMIT: u32 = 10;
+/// max number of prior metadata versions that may be kept for each token
+pub const MAX_METADATA_HISTORY_LIMIT: u32 = 100;
 
 ////////////////////////////////////// Init ///////////////////////////////////////
 /// Returns InitResult
@@ -71,6 +105,15 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
         .unwrap_or(creator_raw);
     let prng_seed: Vec<u8> = sha_256(base64::encode(msg.entropy).as_bytes()).to_vec();
     let init_config = msg.config.unwrap_or_default();
+    let metadata_history_limit = init_config
+        .metadata_history_limit
+        .unwrap_or(DEFAULT_METADATA_HISTORY_LIMIT);
+    if metadata_history_limit > MAX_METADATA_HISTORY_LIMIT {
+        return Err(StdError::generic_err(format!(
+            "The metadata history limit can not be more than {}",
+            MAX_METADATA_HISTORY_LIMIT
+        )));
+    }
 
     let config = Config {
         name: msg.name,
@@ -87,6 +130,13 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
         minter_may_update_metadata: init_config.minter_may_update_metadata.unwrap_or(true),
         owner_may_update_metadata: init_config.owner_may_update_metadata.unwrap_or(false),
         burn_is_enabled: init_config.enable_burn.unwrap_or(false),
+        bridge_burn_cnt: 0,
+        mint_proposal_cnt: 0,
+        max_supply: init_config.max_supply,
+        max_supply_includes_burned: init_config.max_supply_includes_burned.unwrap_or(false),
+        metadata_history_limit,
+        metadata_frozen: false,
+        reveal_not_before: init_config.reveal_not_before,
     };
 
     let minters = vec![admin_raw];
@@ -96,6 +146,10 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
     // TODO remove this after BlockInfo becomes available to queries
     save(&mut deps.storage, BLOCK_KEY, &env.block)?;
 
+    if let Some(collection_info) = msg.collection_info.as_ref() {
+        let stored = collection_info.to_stored(&deps.api)?;
+        save(&mut deps.storage, COLLECTION_INFO_KEY, &stored)?;
+    }
     if msg.royalty_info.is_some() {
         store_royalties(
             &mut deps.storage,
@@ -106,18 +160,22 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
         )?;
     }
 
+    // register with the SNIP-20 tokens accepted as payment
+    let mut messages: Vec<CosmosMsg> = if let Some(tokens) = init_config.payment_tokens {
+        store_payment_tokens(deps, &env, &tokens)?
+    } else {
+        Vec::new()
+    };
     // perform the post init callback if needed
-    let messages: Vec<CosmosMsg> = if let Some(callback) = msg.post_init_callback {
+    if let Some(callback) = msg.post_init_callback {
         let execute = WasmMsg::Execute {
             msg: callback.msg,
             contract_addr: callback.contract_address,
             callback_code_hash: callback.code_hash,
             send: callback.send,
         };
-        vec![execute.into()]
-    } else {
-        Vec::new()
-    };
+        messages.push(execute.into());
+    }
     Ok(InitResponse {
         messages,
         log: vec![],
@@ -150,6 +208,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             serial_number,
             royalty_info,
             transferable,
+            origin,
             memo,
             ..
         } => mint(
@@ -164,6 +223,7 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             serial_number,
             royalty_info,
             transferable,
+            origin,
             memo,
         ),
         HandleMsg::BatchMintNft { mints, .. } => batch_mint(
@@ -195,6 +255,28 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             royalty_info,
             memo,
         ),
+        HandleMsg::MintRandomized {
+            token_id,
+            owner,
+            public_metadata,
+            private_metadata,
+            trait_tables,
+            entropy,
+            memo,
+            ..
+        } => mint_randomized(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            owner,
+            public_metadata,
+            private_metadata,
+            trait_tables,
+            &entropy,
+            memo,
+        ),
         HandleMsg::SetMetadata {
             token_id,
             public_metadata,
@@ -221,6 +303,22 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             token_id.as_deref(),
             royalty_info.as_ref(),
         ),
+        HandleMsg::SetTraitSchema { trait_schema, .. } => set_trait_schema(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            trait_schema,
+        ),
+        HandleMsg::SetCollectionInfo {
+            collection_info, ..
+        } => set_collection_info(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            collection_info.as_ref(),
+        ),
         HandleMsg::Reveal { token_id, .. } => reveal(
             deps,
             env,
@@ -228,6 +326,51 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             ContractStatus::StopTransactions.to_u8(),
             &token_id,
         ),
+        HandleMsg::RevealAll {
+            start_index, limit, ..
+        } => reveal_all(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            start_index,
+            limit,
+        ),
+        HandleMsg::CommitMetadata {
+            commitment, count, ..
+        } => commit_metadata(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &commitment,
+            count,
+        ),
+        HandleMsg::RevealCommittedMetadata {
+            metadata,
+            next_link,
+            ..
+        } => reveal_committed_metadata(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            metadata,
+            &next_link,
+        ),
+        HandleMsg::FreezeMetadata { token_id, .. } => freeze_metadata(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::FreezeAllMetadata { .. } => freeze_all_metadata(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::StopTransactions.to_u8(),
+        ),
         HandleMsg::MakeOwnershipPrivate { .. } => {
             make_owner_private(deps, env, &config, ContractStatus::StopTransactions.to_u8())
         }
@@ -431,6 +574,14 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
             ContractStatus::StopTransactions.to_u8(),
             &minters,
         ),
+        HandleMsg::SetMinterQuota { minter, quota, .. } => set_minter_quota(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &minter,
+            quota,
+        ),
         HandleMsg::ChangeAdmin { address, .. } => change_admin(
             deps,
             env,
@@ -444,187 +595,670 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
         HandleMsg::RevokePermit { permit_name, .. } => {
             revoke_permit(&mut deps.storage, &env.message.sender, &permit_name)
         }
-    };
-    pad_handle_result(response, BLOCK_SIZE)
-}
-
-/// Returns HandleResult
-///
-/// mint a new token
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id, if not specified, use token index
-/// * `owner` - optional owner of this token, if not specified, use the minter's address
-/// * `public_metadata` - optional public metadata viewable by everyone
-/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
-/// * `serial_number` - optional serial number information for this token
-/// * `royalty_info` - optional royalties information for this token
-/// * `transferable` - optionally true if this token is transferable
-/// * `memo` - optional memo for the mint tx
-#[allow(clippy::too_many_arguments)]
-pub fn mint<S: Storage, A: Api, Q: Querier>(
-    deps: &mut Extern<S, A, Q>,
-    env: Env,
-    config: &mut Config,
-    priority: u8,
-    token_id: Option<String>,
-    owner: Option<HumanAddr>,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
-    serial_number: Option<SerialNumber>,
-    royalty_info: Option<RoyaltyInfo>,
-    transferable: Option<bool>,
-    memo: Option<String>,
-) -> HandleResult {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    let mints = vec![Mint {
-        token_id,
-        owner,
-        public_metadata,
-        private_metadata,
-        serial_number,
-        royalty_info,
-        transferable,
-        memo,
-    }];
-    let mut minted = mint_list(deps, &env, config, &sender_raw, mints)?;
-    let minted_str = minted.pop().unwrap_or_default();
-    Ok(HandleResponse {
-        messages: vec![],
-        log: vec![log("minted", &minted_str)],
-        data: Some(to_binary(&HandleAnswer::MintNft {
-            token_id: minted_str,
-        })?),
-    })
-}
-
-/// Returns HandleResult
-///
-/// mints many tokens
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `mints` - the list of mints to perform
-pub fn batch_mint<S: Storage, A: Api, Q: Querier>(
-    deps: &mut Extern<S, A, Q>,
-    env: Env,
-    config: &mut Config,
-    priority: u8,
-    mints: Vec<Mint>,
-) -> HandleResult {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    let minted = mint_list(deps, &env, config, &sender_raw, mints)?;
-    Ok(HandleResponse {
-        messages: vec![],
-        log: vec![log("minted", format!("{:?}", &minted))],
-        data: Some(to_binary(&HandleAnswer::BatchMintNft {
-            token_ids: minted,
-        })?),
-    })
-}
-
-/// Returns HandleResult
-///
-/// mints clones of a token
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `mint_run_id` - optional id used to track subsequent mint runs
-/// * `quantity` - number of clones to mint
-/// * `owner` - optional owner of this token, if not specified, use the minter's address
-/// * `public_metadata` - optional public metadata viewable by everyone
-/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
-/// * `royalty_info` - optional royalties information for these clones
-/// * `memo` - optional memo for the mint txs
-#[allow(clippy::too_many_arguments)]
-pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
-    deps: &mut Extern<S, A, Q>,
-    env: Env,
-    config: &mut Config,
-    priority: u8,
-    mint_run_id: Option<&String>,
-    quantity: u32,
-    owner: Option<HumanAddr>,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
-    royalty_info: Option<RoyaltyInfo>,
-    memo: Option<String>,
-) -> HandleResult {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    if quantity == 0 {
-        return Err(StdError::generic_err("Quantity can not be zero"));
-    }
-    let mint_run = mint_run_id
-        .map(|i| {
-            let key = i.as_bytes();
-            let mut run_store = PrefixedStorage::new(PREFIX_MINT_RUN_NUM, &mut deps.storage);
-            let last_num: u32 = may_load(&run_store, key)?.unwrap_or(0);
-            let this_num: u32 = last_num.checked_add(1).ok_or_else(|| {
-                StdError::generic_err(format!(
-                    "Mint run ID {} has already reached its maximum possible value",
-                    i
-                ))
-            })?;
-            save(&mut run_store, key, &this_num)?;
-            Ok(this_num)
-        })
-        .transpose()?;
-    let mut serial_number = SerialNumber {
-        mint_run,
-        serial_number: 1,
-        quantity_minted_this_run: Some(quantity),
-    };
-    let mut mints: Vec<Mint> = Vec::new();
-    for _ in 0..quantity {
-        mints.push(Mint {
-            token_id: None,
-            owner: owner.clone(),
-            public_metadata: public_metadata.clone(),
-            private_metadata: private_metadata.clone(),
-            serial_number: Some(serial_number.clone()),
-            royalty_info: royalty_info.clone(),
-            transferable: Some(true),
-            memo: memo.clone(),
-        });
-        serial_number.serial_number += 1;
-    }
-    let mut minted = mint_list(deps, &env, config, &sender_raw, mints)?;
-    // if mint_list did not error, there must be at least one token id
-    let first_minted = minted
+        HandleMsg::BridgeLock {
+            token_id,
+            chain_nonce,
+            destination_address,
+            memo,
+            ..
+        } => bridge_lock(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            chain_nonce,
+            destination_address,
+            memo,
+        ),
+        HandleMsg::BridgeUnlock {
+            token_id,
+            recipient,
+            action_id,
+            memo,
+            ..
+        } => bridge_unlock(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            &recipient,
+            action_id,
+            memo,
+        ),
+        HandleMsg::BurnForBridge {
+            token_ids,
+            chain_nonce,
+            destination_address,
+            fee,
+            action_id,
+            memo,
+            ..
+        } => burn_for_bridge(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_ids,
+            chain_nonce,
+            destination_address,
+            fee,
+            action_id,
+            memo,
+        ),
+        HandleMsg::SetBridgeValidator { address, .. } => set_bridge_validator(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &address,
+        ),
+        HandleMsg::SetValidators {
+            validators,
+            threshold,
+            ..
+        } => set_validators(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &validators,
+            threshold,
+        ),
+        HandleMsg::ProposeMint {
+            mint, action_id, ..
+        } => propose_mint(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            mint,
+            action_id,
+        ),
+        HandleMsg::VoteMint { proposal_id, .. } => vote_mint(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            proposal_id,
+        ),
+        HandleMsg::CancelMintProposal { proposal_id, .. } => cancel_mint_proposal(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            proposal_id,
+        ),
+        HandleMsg::SetSaleConfig { sale, .. } => set_sale_config(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            sale,
+        ),
+        HandleMsg::PurchaseMint {
+            quantity,
+            entropy,
+            memo,
+            ..
+        } => purchase_mint(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            quantity,
+            entropy,
+            memo,
+        ),
+        HandleMsg::SetPresaleConfig { presale, .. } => set_presale_config(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            presale,
+        ),
+        HandleMsg::SetPresaleList { entries, .. } => update_presale_list(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &entries,
+            true,
+        ),
+        HandleMsg::AddToPresaleList { entries, .. } => update_presale_list(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &entries,
+            false,
+        ),
+        HandleMsg::PresaleMint {
+            quantity,
+            entropy,
+            memo,
+            ..
+        } => presale_mint(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            quantity,
+            entropy,
+            memo,
+        ),
+        HandleMsg::AddPaymentTokens { tokens, .. } => add_payment_tokens(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &tokens,
+        ),
+        HandleMsg::Receive {
+            from, amount, msg, ..
+        } => receive(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            &from,
+            amount,
+            msg,
+        ),
+        HandleMsg::ListForSale {
+            token_id,
+            price,
+            denom,
+            payment_token,
+            expires,
+            ..
+        } => list_for_sale(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            price,
+            denom,
+            payment_token,
+            expires,
+        ),
+        HandleMsg::CancelListing { token_id, .. } => cancel_listing(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::MakeOffer {
+            token_id, expires, ..
+        } => make_offer(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            expires,
+        ),
+        HandleMsg::WithdrawOffer { token_id, .. } => withdraw_offer(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::AcceptOffer {
+            token_id,
+            bidder,
+            memo,
+            ..
+        } => accept_offer(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            &bidder,
+            memo,
+        ),
+        HandleMsg::StartAuction {
+            token_id,
+            denom,
+            reserve_price,
+            end_time,
+            ..
+        } => start_auction(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            denom,
+            reserve_price,
+            end_time,
+        ),
+        HandleMsg::PlaceBid { token_id, .. } => place_bid(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::SettleAuction { token_id, memo, .. } => settle_auction(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            memo,
+        ),
+        HandleMsg::CancelAuction { token_id, .. } => cancel_auction(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::SetUser {
+            token_id,
+            user,
+            expires,
+            ..
+        } => set_user(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            user,
+            expires,
+        ),
+        HandleMsg::Compose {
+            inputs,
+            output,
+            memo,
+            ..
+        } => compose(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            inputs,
+            output,
+            memo,
+        ),
+        HandleMsg::Derive {
+            parent_token_ids,
+            mints,
+            ..
+        } => derive(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            parent_token_ids,
+            mints,
+        ),
+        HandleMsg::AttachToParent {
+            token_id,
+            parent_token_id,
+            ..
+        } => attach_to_parent(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            &parent_token_id,
+        ),
+        HandleMsg::DetachFromParent { token_id, .. } => detach_from_parent(
+            deps,
+            env,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        HandleMsg::RecoverNonTransferable {
+            token_id,
+            new_owner,
+            memo,
+            ..
+        } => recover_non_transferable(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            &new_owner,
+            memo,
+        ),
+        HandleMsg::RevokeCredential { token_id, memo, .. } => revoke_credential(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            memo,
+        ),
+        HandleMsg::Buy { token_id, memo, .. } => buy(
+            deps,
+            env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            memo,
+        ),
+        HandleMsg::WithdrawProceeds { recipient, .. } => withdraw_proceeds(
+            deps,
+            env,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            recipient,
+        ),
+    };
+    pad_handle_result(response, BLOCK_SIZE)
+}
+
+/// Returns HandleResult
+///
+/// mint a new token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id, if not specified, use token index
+/// * `owner` - optional owner of this token, if not specified, use the minter's address
+/// * `public_metadata` - optional public metadata viewable by everyone
+/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
+/// * `serial_number` - optional serial number information for this token
+/// * `royalty_info` - optional royalties information for this token
+/// * `transferable` - optionally true if this token is transferable
+/// * `origin` - optional origin of a wrapped token minted by the bridge
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+pub fn mint<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: Option<String>,
+    owner: Option<HumanAddr>,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+    serial_number: Option<SerialNumber>,
+    royalty_info: Option<RoyaltyInfo>,
+    transferable: Option<bool>,
+    origin: Option<OriginInfo>,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    let mints = vec![Mint {
+        token_id,
+        owner,
+        public_metadata,
+        private_metadata,
+        serial_number,
+        royalty_info,
+        transferable,
+        origin,
+        memo,
+    }];
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let mut minted = mint_list(deps, &env, config, &sender_raw, mints)?;
+    let minted_str = minted.pop().unwrap_or_default();
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("minted", &minted_str)],
+        data: Some(to_binary(&HandleAnswer::MintNft {
+            token_id: minted_str,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// mints many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `mints` - the list of mints to perform
+pub fn batch_mint<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    mints: Vec<Mint>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let minted = mint_list(deps, &env, config, &sender_raw, mints)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("minted", format!("{:?}", &minted))],
+        data: Some(to_binary(&HandleAnswer::BatchMintNft {
+            token_ids: minted,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// mints a new token with traits randomly chosen from weighted trait tables
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id, if not specified, use token index
+/// * `owner` - optional owner of this token, if not specified, use the minter's address
+/// * `public_metadata` - optional public metadata viewable by everyone
+/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
+/// * `trait_tables` - the weighted tables to choose the token's traits from
+/// * `entropy` - string slice of the input String to be used as entropy in randomization
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+pub fn mint_randomized<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: Option<String>,
+    owner: Option<HumanAddr>,
+    mut public_metadata: Option<Metadata>,
+    mut private_metadata: Option<Metadata>,
+    trait_tables: Vec<TraitTable>,
+    entropy: &str,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY)?;
+    let mut rng_entropy = env.block.height.to_be_bytes().to_vec();
+    rng_entropy.extend_from_slice(&env.block.time.to_be_bytes());
+    rng_entropy.extend_from_slice(sender_raw.as_slice());
+    rng_entropy.extend_from_slice(entropy.as_bytes());
+    let mut rng = Prng::new(&prng_seed, &rng_entropy);
+    for table in trait_tables.into_iter() {
+        let total_weight: u64 = table.values.iter().map(|v| v.weight as u64).sum();
+        if total_weight == 0 {
+            return Err(StdError::generic_err(format!(
+                "Trait table {} must have at least one value with a positive weight",
+                table.trait_type
+            )));
+        }
+        let rand_bytes = rng.rand_bytes();
+        let mut roll_bytes = [0u8; 8];
+        roll_bytes.copy_from_slice(&rand_bytes[..8]);
+        let mut roll = u64::from_be_bytes(roll_bytes) % total_weight;
+        // the roll is less than the total weight, so a value will always be found
+        let value = table
+            .values
+            .into_iter()
+            .find(|v| {
+                if roll < v.weight as u64 {
+                    true
+                } else {
+                    roll -= v.weight as u64;
+                    false
+                }
+            })
+            .map(|v| v.value)
+            .unwrap_or_default();
+        let chosen = Trait {
+            display_type: table.display_type,
+            trait_type: Some(table.trait_type),
+            value,
+            max_value: None,
+        };
+        let metadata = if table.private.unwrap_or(false) {
+            &mut private_metadata
+        } else {
+            &mut public_metadata
+        };
+        metadata
+            .get_or_insert_with(Metadata::default)
+            .extension
+            .get_or_insert_with(Extension::default)
+            .attributes
+            .get_or_insert_with(Vec::new)
+            .push(chosen);
+    }
+    // advance the seed so later mints in the same block are not predictable
+    save(&mut deps.storage, PRNG_SEED_KEY, &rng.rand_bytes().to_vec())?;
+    let mints = vec![Mint {
+        token_id,
+        owner,
+        public_metadata,
+        private_metadata,
+        serial_number: None,
+        royalty_info: None,
+        transferable: None,
+        origin: None,
+        memo,
+    }];
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let mut minted = mint_list(deps, &env, config, &sender_raw, mints)?;
+    let minted_str = minted.pop().unwrap_or_default();
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("minted", &minted_str)],
+        data: Some(to_binary(&HandleAnswer::MintRandomized {
+            token_id: minted_str,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// mints clones of a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `mint_run_id` - optional id used to track subsequent mint runs
+/// * `quantity` - number of clones to mint
+/// * `owner` - optional owner of this token, if not specified, use the minter's address
+/// * `public_metadata` - optional public metadata viewable by everyone
+/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
+/// * `royalty_info` - optional royalties information for these clones
+/// * `memo` - optional memo for the mint txs
+#[allow(clippy::too_many_arguments)]
+pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    mint_run_id: Option<&String>,
+    quantity: u32,
+    owner: Option<HumanAddr>,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+    royalty_info: Option<RoyaltyInfo>,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    if quantity == 0 {
+        return Err(StdError::generic_err("Quantity can not be zero"));
+    }
+    let mint_run = mint_run_id
+        .map(|i| {
+            let key = i.as_bytes();
+            let mut run_store = PrefixedStorage::new(PREFIX_MINT_RUN_NUM, &mut deps.storage);
+            let last_num: u32 = may_load(&run_store, key)?.unwrap_or(0);
+            let this_num: u32 = last_num.checked_add(1).ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Mint run ID {} has already reached its maximum possible value",
+                    i
+                ))
+            })?;
+            save(&mut run_store, key, &this_num)?;
+            Ok(this_num)
+        })
+        .transpose()?;
+    let mut serial_number = SerialNumber {
+        mint_run,
+        serial_number: 1,
+        quantity_minted_this_run: Some(quantity),
+    };
+    let mut mints: Vec<Mint> = Vec::new();
+    for _ in 0..quantity {
+        mints.push(Mint {
+            token_id: None,
+            owner: owner.clone(),
+            public_metadata: public_metadata.clone(),
+            private_metadata: private_metadata.clone(),
+            serial_number: Some(serial_number.clone()),
+            royalty_info: royalty_info.clone(),
+            transferable: Some(true),
+            origin: None,
+            memo: memo.clone(),
+        });
+        serial_number.serial_number += 1;
+    }
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let mut minted = mint_list(deps, &env, config, &sender_raw, mints)?;
+    // if mint_list did not error, there must be at least one token id
+    let first_minted = minted
         .first()
         .ok_or_else(|| StdError::generic_err("List of minted tokens is empty"))?
         .clone();
@@ -634,427 +1268,2937 @@ pub fn mint_clones<S: Storage, A: Api, Q: Querier>(
 
     Ok(HandleResponse {
         messages: vec![],
-        log: vec![
-            log("first_minted", &first_minted),
-            log("last_minted", &last_minted),
-        ],
-        data: Some(to_binary(&HandleAnswer::MintNftClones {
-            first_minted,
-            last_minted,
+        log: vec![
+            log("first_minted", &first_minted),
+            log("last_minted", &last_minted),
+        ],
+        data: Some(to_binary(&HandleAnswer::MintNftClones {
+            first_minted,
+            last_minted,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets new public and/or private metadata
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token whose metadata should be updated
+/// * `public_metadata` - the optional new public metadata viewable by everyone
+/// * `private_metadata` - the optional new private metadata viewable by everyone
+pub fn set_metadata<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    check_metadata_updater(&deps.storage, config, &token, &sender_raw, custom_err)?;
+    check_not_frozen(config, &token, token_id)?;
+    let mut version = StoredMetadataVersion {
+        public_metadata: None,
+        private_metadata: None,
+        updater: sender_raw,
+        block_height: env.block.height,
+        block_time: env.block.time,
+    };
+    if let Some(public) = public_metadata {
+        version.public_metadata =
+            set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PUB_META, &public)?;
+    }
+    if let Some(private) = private_metadata {
+        version.private_metadata =
+            set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PRIV_META, &private)?;
+    }
+    if version.public_metadata.is_some() || version.private_metadata.is_some() {
+        append_metadata_version(&mut deps.storage, config, idx, version)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetMetadata { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets new royalty information for a specified token or if no token ID is provided, sets new
+/// royalty information as the contract's default
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id String slice of token whose royalty info should be updated
+/// * `royalty_info` - a optional reference to the new RoyaltyInfo
+pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: Option<&str>,
+    royalty_info: Option<&RoyaltyInfo>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    // set a token's royalties
+    if let Some(id) = token_id {
+        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they are not authorized for that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(custom_err)
+        };
+        let (token, idx) = get_token(&deps.storage, id, opt_err)?;
+        if !token.transferable {
+            return Err(StdError::generic_err(
+                "Non-transferable tokens can not be sold, so royalties are meaningless",
+            ));
+        }
+        let token_key = idx.to_le_bytes();
+        let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
+        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
+        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
+            return Err(StdError::generic_err(custom_err));
+        }
+        let default_roy = royalty_info.as_ref().map_or_else(
+            || may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY),
+            |_r| Ok(None),
+        )?;
+        let mut roy_store = PrefixedStorage::new(PREFIX_ROYALTY_INFO, &mut deps.storage);
+        store_royalties(
+            &mut roy_store,
+            &deps.api,
+            royalty_info,
+            default_roy.as_ref(),
+            &token_key,
+        )?;
+    // set default royalty
+    } else {
+        let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+        if !minters.contains(&sender_raw) {
+            return Err(StdError::generic_err(
+                "Only designated minters can set default royalties for the contract",
+            ));
+        }
+        store_royalties(
+            &mut deps.storage,
+            &deps.api,
+            royalty_info,
+            None,
+            DEFAULT_ROYALTY_KEY,
+        )?;
+    };
+
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetRoyaltyInfo {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets or deletes the schema that metadata attributes must follow
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `trait_schema` - optional new trait schema
+pub fn set_trait_schema<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    trait_schema: Option<TraitSchema>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(schema) = trait_schema {
+        schema.check()?;
+        save(&mut deps.storage, TRAIT_SCHEMA_KEY, &schema)?;
+    } else {
+        remove(&mut deps.storage, TRAIT_SCHEMA_KEY);
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetTraitSchema {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets or deletes the collection-level metadata
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `collection_info` - optional reference to the new collection-level metadata
+pub fn set_collection_info<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    collection_info: Option<&CollectionInfo>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(info) = collection_info {
+        let stored = info.to_stored(&deps.api)?;
+        save(&mut deps.storage, COLLECTION_INFO_KEY, &stored)?;
+    } else {
+        remove(&mut deps.storage, COLLECTION_INFO_KEY);
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetCollectionInfo {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// makes the sealed private metadata public
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token whose metadata should be updated
+pub fn reveal<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    check_reveal_enabled(config, &env.block)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let custom_err = format!("You do not own token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they do not own that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    if token.unwrapped {
+        return Err(StdError::generic_err(
+            "This token has already been unwrapped",
+        ));
+    }
+    if token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    check_not_frozen(config, &token, token_id)?;
+    reveal_impl(&mut deps.storage, config, &mut token, idx)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::Reveal { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// reveals the sealed metadata of a range of tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `start_index` - index of the first token to reveal
+/// * `limit` - optional number of indexes to process
+pub fn reveal_all<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    start_index: u32,
+    limit: Option<u32>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    check_reveal_enabled(config, &env.block)?;
+    if config.metadata_frozen {
+        return Err(StdError::generic_err(
+            "The metadata of all tokens is frozen",
+        ));
+    }
+    let end = start_index
+        .saturating_add(limit.unwrap_or(100))
+        .min(config.mint_cnt);
+    let mut revealed = 0u32;
+    for idx in start_index..end {
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let may_token: Option<Token> = json_may_load(&info_store, &idx.to_le_bytes())?;
+        // skip burned, already unwrapped, and frozen tokens
+        if let Some(mut token) = may_token.filter(|t| !t.unwrapped && !t.metadata_frozen) {
+            reveal_impl(&mut deps.storage, config, &mut token, idx)?;
+            revealed += 1;
+        }
+    }
+    let next_index = if end < config.mint_cnt {
+        Some(end)
+    } else {
+        None
+    };
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RevealAll {
+            revealed,
+            next_index,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// commits to the shuffled metadata of a blind mint
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `commitment` - a reference to the first link of the metadata hash chain
+/// * `count` - number of metadata entries committed to
+pub fn commit_metadata<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    commitment: &Binary,
+    count: u32,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let may_prov: Option<StoredProvenance> = may_load(&deps.storage, PROVENANCE_KEY)?;
+    if may_prov.is_some() {
+        return Err(StdError::generic_err("Metadata has already been committed"));
+    }
+    if config.mint_cnt > 0 {
+        return Err(StdError::generic_err(
+            "Metadata must be committed before any tokens are minted",
+        ));
+    }
+    if commitment.len() != 32 {
+        return Err(StdError::generic_err(
+            "The metadata commitment must be a 32-byte sha256 hash",
+        ));
+    }
+    if count == 0 {
+        return Err(StdError::generic_err(
+            "The metadata commitment must include at least one entry",
+        ));
+    }
+    let provenance = StoredProvenance {
+        commitment: commitment.0.clone(),
+        count,
+        offset: None,
+        commit_height: env.block.height,
+        entropy: commitment.0.clone(),
+        revealed: 0,
+        expected_link: commitment.0.clone(),
+    };
+    save(&mut deps.storage, PROVENANCE_KEY, &provenance)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::CommitMetadata {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// verifies the next chunk of committed metadata and assigns it to tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `metadata` - the next entries of the committed metadata list
+/// * `next_link` - a reference to the chain link of the entry following this chunk
+pub fn reveal_committed_metadata<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    metadata: Vec<Metadata>,
+    next_link: &Binary,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut provenance: StoredProvenance = may_load(&deps.storage, PROVENANCE_KEY)?
+        .ok_or_else(|| StdError::generic_err("No metadata has been committed"))?;
+    if config.metadata_frozen {
+        return Err(StdError::generic_err(
+            "The metadata of all tokens is frozen",
+        ));
+    }
+    if provenance.revealed == provenance.count {
+        return Err(StdError::generic_err(
+            "All committed metadata has already been revealed",
+        ));
+    }
+    let remaining = (provenance.count - provenance.revealed) as usize;
+    if metadata.is_empty() || metadata.len() > remaining {
+        return Err(StdError::generic_err(format!(
+            "Expected between 1 and {} metadata entries",
+            remaining
+        )));
+    }
+    if next_link.len() != 32 {
+        return Err(StdError::generic_err(
+            "The next chain link must be a 32-byte sha256 hash",
+        ));
+    }
+    if metadata.len() == remaining && next_link.as_slice() != [0u8; 32] {
+        return Err(StdError::generic_err(
+            "The last chunk of metadata must be followed by a link of 32 zero bytes",
+        ));
+    }
+    // verify the chunk against the commitment
+    let mut link = next_link.0.clone();
+    for meta in metadata.iter().rev() {
+        link = chain_link(meta, &link)?.to_vec();
+    }
+    if link != provenance.expected_link {
+        return Err(StdError::generic_err(
+            "The metadata does not match the commitment",
+        ));
+    }
+    // generate the assignment offset when the reveal starts.  The private prng seed is mixed
+    // in, so the admin can not predict the offset when deciding when to reveal
+    let offset = if let Some(o) = provenance.offset {
+        o
+    } else {
+        if config.mint_cnt < provenance.count {
+            return Err(StdError::generic_err(format!(
+                "All {} committed tokens must be minted before their metadata is revealed",
+                provenance.count
+            )));
+        }
+        let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY)?;
+        let o = provenance.generate_offset(&prng_seed, &env.block);
+        provenance.offset = Some(o);
+        o
+    };
+    for (i, meta) in metadata.iter().enumerate() {
+        let idx = provenance.assigned_index(provenance.revealed + i as u32, offset);
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let may_token: Option<Token> = json_may_load(&info_store, &idx.to_le_bytes())?;
+        // skip burned tokens and tokens with frozen metadata
+        if let Some(token) = may_token.filter(|t| !t.metadata_frozen) {
+            let prior = set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PUB_META, meta)?;
+            // placeholder metadata is kept in the history so the reveal can be audited
+            if prior.is_some() {
+                let version = StoredMetadataVersion {
+                    public_metadata: prior,
+                    private_metadata: None,
+                    updater: sender_raw.clone(),
+                    block_height: env.block.height,
+                    block_time: env.block.time,
+                };
+                append_metadata_version(&mut deps.storage, config, idx, version)?;
+            }
+        }
+    }
+    provenance.revealed += metadata.len() as u32;
+    provenance.expected_link = next_link.0.clone();
+    save(&mut deps.storage, PROVENANCE_KEY, &provenance)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RevealCommittedMetadata {
+            revealed: provenance.revealed,
+            remaining: provenance.count - provenance.revealed,
+        })?),
+    })
+}
+
+/// Returns StdResult<()>
+///
+/// returns an error if sealed metadata is not enabled or may not be revealed yet
+///
+/// # Arguments
+///
+/// * `config` - a reference to the Config
+/// * `block` - a reference to the current BlockInfo
+fn check_reveal_enabled(config: &Config, block: &BlockInfo) -> StdResult<()> {
+    if !config.sealed_metadata_is_enabled {
+        return Err(StdError::generic_err(
+            "Sealed metadata functionality is not enabled for this contract",
+        ));
+    }
+    if let Some(not_before) = config.reveal_not_before.as_ref() {
+        if !not_before.is_expired(block) {
+            return Err(StdError::generic_err(format!(
+                "Sealed metadata may not be revealed before {}",
+                not_before
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// marks a token as unwrapped and moves its sealed metadata to public metadata unless the
+/// contract unwraps to private
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `token` - a mutable reference to the token being revealed
+/// * `idx` - the token identifier index
+fn reveal_impl<S: Storage>(
+    storage: &mut S,
+    config: &Config,
+    token: &mut Token,
+    idx: u32,
+) -> StdResult<()> {
+    token.unwrapped = true;
+    let token_key = idx.to_le_bytes();
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, storage);
+    json_save(&mut info_store, &token_key, token)?;
+    if !config.unwrap_to_private {
+        let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, storage);
+        let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
+        if let Some(metadata) = may_priv {
+            remove(&mut priv_store, &token_key);
+            let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, storage);
+            save(&mut pub_store, &token_key, &metadata)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns HandleResult
+///
+/// permanently freezes a token's metadata
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token whose metadata should be frozen
+pub fn freeze_metadata<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let custom_err = format!("Not authorized to freeze metadata of token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    check_metadata_updater(&deps.storage, config, &token, &sender_raw, custom_err)?;
+    check_not_frozen(config, &token, token_id)?;
+    token.metadata_frozen = true;
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &idx.to_le_bytes(), &token)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::FreezeMetadata {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// permanently freezes the metadata of every token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+pub fn freeze_all_metadata<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if !config.metadata_frozen {
+        config.metadata_frozen = true;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::FreezeAllMetadata {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// grants/revokes trasfer permission on a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `spender` - a reference to the address being granted permission
+/// * `token_id` - string slice of the token id to grant permission to
+/// * `expires` - optional Expiration for this approval
+/// * `is_approve` - true if this is an Approve call
+#[allow(clippy::too_many_arguments)]
+pub fn approve_revoke<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    spender: &HumanAddr,
+    token_id: &str,
+    expires: Option<Expiration>,
+    is_approve: bool,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let address_raw = deps.api.canonical_address(spender)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let custom_err = format!(
+        "Not authorized to grant/revoke transfer permission for token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
+    let mut all_perm: Option<Vec<Permission>> = None;
+    let mut from_oper = false;
+    let transfer_idx = PermissionType::Transfer.to_usize();
+    // if not called by the owner, check if message sender has operator status
+    if token.owner != sender_raw {
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
+        if let Some(list) = may_list.clone() {
+            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
+                if let Some(exp) = perm.expirations[transfer_idx] {
+                    if exp.is_expired(&env.block) {
+                        return Err(StdError::generic_err(format!(
+                            "Transfer authority for all tokens of {} has expired",
+                            &deps.api.human_address(&token.owner)?
+                        )));
+                    } else {
+                        from_oper = true;
+                    }
+                }
+            }
+        }
+        if !from_oper {
+            return Err(StdError::generic_err(custom_err));
+        }
+        all_perm = may_list;
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    let response: HandleAnswer;
+    if is_approve {
+        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
+        response = HandleAnswer::Approve { status: Success };
+    } else {
+        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
+        response = HandleAnswer::Revoke { status: Success };
+    }
+    let owner = token.owner.clone();
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given: true,
+        accesses,
+        expires,
+        from_oper,
+    };
+    process_accesses(
+        &mut deps.storage,
+        &env,
+        &address_raw,
+        &owner,
+        &mut proc_info,
+        all_perm,
+    )?;
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&response)?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// makes an address' token ownership private
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+pub fn make_owner_private<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    // only need to do this if the contract has public ownership
+    if config.owner_is_public {
+        let mut priv_store = PrefixedStorage::new(PREFIX_OWNER_PRIV, &mut deps.storage);
+        save(&mut priv_store, sender_raw.as_slice(), &false)?
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::MakeOwnershipPrivate {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// adds/revokes access for everyone
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id to apply approvals to
+/// * `view_owner` - optional access level for viewing token ownership
+/// * `view_private_metadata` - optional access level for viewing private metadata
+/// * `expires` - optional Expiration for this approval
+#[allow(clippy::too_many_arguments)]
+pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: Option<String>,
+    view_owner: Option<AccessLevel>,
+    view_private_metadata: Option<AccessLevel>,
+    expires: Option<Expiration>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let token_given: bool;
+    // use this "address" to represent global permission
+    let global_raw = CanonicalAddr(Binary::from(b"public"));
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let mut custom_err = String::new();
+    let (token, idx) = if let Some(id) = token_id {
+        token_given = true;
+        custom_err = format!("You do not own token {}", id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        get_token(&deps.storage, &id, opt_err)?
+    } else {
+        token_given = false;
+        (
+            Token {
+                owner: sender_raw.clone(),
+                permissions: Vec::new(),
+                unwrapped: false,
+                transferable: true,
+                bridge_locked: false,
+                user: None,
+                metadata_frozen: false,
+            },
+            0,
+        )
+    };
+    // if trying to set token permissions when you are not the owner
+    if token_given && token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
+    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given,
+        accesses,
+        expires,
+        from_oper: false,
+    };
+    process_accesses(
+        &mut deps.storage,
+        &env,
+        &global_raw,
+        &sender_raw,
+        &mut proc_info,
+        None,
+    )?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetGlobalApproval {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets specified permissions for an address
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `address` - a reference to the address being granted permission
+/// * `token_id` - optional token id to apply approvals to
+/// * `view_owner` - optional access level for viewing token ownership
+/// * `view_private_metadata` - optional access level for viewing private metadata
+/// * `transfer` - optional access level for transferring tokens
+/// * `expires` - optional Expiration for this approval
+/// * `response_type` - which response to return for SetWhitelistedApproval, ApproveAll, or RevokeAll
+#[allow(clippy::too_many_arguments)]
+pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    address: &HumanAddr,
+    token_id: Option<String>,
+    view_owner: Option<AccessLevel>,
+    view_private_metadata: Option<AccessLevel>,
+    transfer: Option<AccessLevel>,
+    expires: Option<Expiration>,
+    response_type: SetAppResp,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let token_given: bool;
+    let address_raw = deps.api.canonical_address(address)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let mut custom_err = String::new();
+    let (token, idx) = if let Some(id) = token_id {
+        token_given = true;
+        custom_err = format!("You do not own token {}", id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        get_token(&deps.storage, &id, opt_err)?
+    } else {
+        token_given = false;
+        (
+            Token {
+                owner: sender_raw.clone(),
+                permissions: Vec::new(),
+                unwrapped: false,
+                transferable: true,
+                bridge_locked: false,
+                user: None,
+                metadata_frozen: false,
+            },
+            0,
+        )
+    };
+    // if trying to set token permissions when you are not the owner
+    if token_given && token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
+    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
+    accesses[PermissionType::Transfer.to_usize()] = transfer;
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given,
+        accesses,
+        expires,
+        from_oper: false,
+    };
+    process_accesses(
+        &mut deps.storage,
+        &env,
+        &address_raw,
+        &sender_raw,
+        &mut proc_info,
+        None,
+    )?;
+    let response = match response_type {
+        SetAppResp::SetWhitelistedApproval => {
+            HandleAnswer::SetWhitelistedApproval { status: Success }
+        }
+        SetAppResp::ApproveAll => HandleAnswer::ApproveAll { status: Success },
+        SetAppResp::RevokeAll => HandleAnswer::RevokeAll { status: Success },
+    };
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&response)?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// burns many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `burns` - the list of burns to perform
+pub fn batch_burn_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    burns: Vec<Burn>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let messages = burn_list(deps, &env, config, &sender_raw, burns, &BurnType::Standard)?;
+    let res = HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BatchBurnNft { status: Success })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// burns a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - token id String of token to be burnt
+/// * `memo` - optional memo for the burn tx
+fn burn_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let burns = vec![Burn {
+        token_ids: vec![token_id],
+        memo,
+    }];
+    let messages = burn_list(deps, &env, config, &sender_raw, burns, &BurnType::Standard)?;
+    let res = HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BurnNft { status: Success })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// transfer many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `transfers` - list of transfers to perform
+pub fn batch_transfer_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    transfers: Vec<Transfer>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let _m = send_list(deps, &env, config, &sender_raw, Some(transfers), None)?;
+
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BatchTransferNft {
+            status: Success,
+        })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// transfer a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipient` - the address receiving the token
+/// * `token_id` - token id String of token to be transferred
+/// * `memo` - optional memo for the mint tx
+pub fn transfer_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    recipient: HumanAddr,
+    token_id: String,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let transfers = Some(vec![Transfer {
+        recipient,
+        token_ids: vec![token_id],
+        memo,
+    }]);
+    let _m = send_list(deps, &env, config, &sender_raw, transfers, None)?;
+
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::TransferNft { status: Success })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// sends tokens to contracts, and calls those contracts' ReceiveNft.  Will error if any
+/// contract has not registered its ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `sends` - list of SendNfts to perform
+fn batch_send_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    sends: Vec<Send>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let messages = send_list(deps, &env, config, &sender_raw, None, Some(sends))?;
+
+    let res = HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BatchSendNft { status: Success })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// sends a token to a contract, and calls that contract's ReceiveNft.  Will error if the
+/// contract has not registered its ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `contract` - the address of the contract receiving the token
+/// * `receiver_info` - optional code hash and BatchReceiveNft implementation status of
+///   the recipient contract
+/// * `token_id` - ID String of the token that was sent
+/// * `msg` - optional msg used to control ReceiveNft logic
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+fn send_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    contract: HumanAddr,
+    receiver_info: Option<ReceiverInfo>,
+    token_id: String,
+    msg: Option<Binary>,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let sends = Some(vec![Send {
+        contract,
+        receiver_info,
+        token_ids: vec![token_id],
+        msg,
+        memo,
+    }]);
+    let messages = send_list(deps, &env, config, &sender_raw, None, sends)?;
+
+    let res = HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SendNft { status: Success })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// registers a contract's ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `code_hash` - code hash String of the registering contract
+/// * `impl_batch` - optionally true if the contract also implements BatchReceiveNft
+pub fn register_receive_nft<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    code_hash: String,
+    impl_batch: Option<bool>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let regrec = ReceiveRegistration {
+        code_hash,
+        impl_batch: impl_batch.unwrap_or(false),
+    };
+    let mut store = PrefixedStorage::new(PREFIX_RECEIVERS, &mut deps.storage);
+    save(&mut store, sender_raw.as_slice(), &regrec)?;
+    let res = HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RegisterReceiveNft {
+            status: Success,
+        })?),
+    };
+    Ok(res)
+}
+
+/// Returns HandleResult
+///
+/// creates a viewing key
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `entropy` - string slice of the input String to be used as entropy in randomization
+pub fn create_key<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    entropy: &str,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY)?;
+    let key = ViewingKey::new(&env, &prng_seed, entropy.as_ref());
+    let message_sender = deps.api.canonical_address(&env.message.sender)?;
+    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
+    save(&mut key_store, message_sender.as_slice(), &key.to_hashed())?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::ViewingKey {
+            key: format!("{}", key),
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets the viewing key to the input String
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `key` - String to be used as the viewing key
+pub fn set_key<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    key: String,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let vk = ViewingKey(key.clone());
+    let message_sender = deps.api.canonical_address(&env.message.sender)?;
+    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
+    save(&mut key_store, message_sender.as_slice(), &vk.to_hashed())?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::ViewingKey { key })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// add a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `new_minters` - list of minter addresses to add
+pub fn add_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    new_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    let old_len = minters.len();
+    for minter in new_minters {
+        let minter_raw = deps.api.canonical_address(minter)?;
+        if !minters.contains(&minter_raw) {
+            minters.push(minter_raw);
+        }
+    }
+    // only save if the list changed
+    if old_len != minters.len() {
+        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::AddMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// remove a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `no_minters` - list of minter addresses to remove
+pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    no_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let may_minters: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY)?;
+    if let Some(mut minters) = may_minters {
+        let old_len = minters.len();
+        let no_raw: Vec<CanonicalAddr> = no_minters
+            .iter()
+            .map(|x| deps.api.canonical_address(x))
+            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+        minters.retain(|m| !no_raw.contains(m));
+        let new_len = minters.len();
+        if new_len > 0 {
+            if old_len != new_len {
+                save(&mut deps.storage, MINTERS_KEY, &minters)?;
+            }
+        } else {
+            remove(&mut deps.storage, MINTERS_KEY);
+        }
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RemoveMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// define the exact list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `human_minters` - exact list of minter addresses
+pub fn set_minters<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    human_minters: &[HumanAddr],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    // remove duplicates from the minters list
+    let minters_raw: Vec<CanonicalAddr> = human_minters
+        .iter()
+        .map(|x| deps.api.canonical_address(x))
+        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
+    sortable.sort_unstable();
+    sortable.dedup();
+    let minters: Vec<CanonicalAddr> = sortable
+        .iter()
+        .map(|x| CanonicalAddr(Binary(x.to_vec())))
+        .collect();
+    if minters.is_empty() {
+        remove(&mut deps.storage, MINTERS_KEY);
+    } else {
+        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetMinters { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets or removes the quota of tokens a minter may mint
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `minter` - a reference to the minter's address
+/// * `quota` - optional maximum number of tokens the minter may mint
+pub fn set_minter_quota<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    minter: &HumanAddr,
+    quota: Option<u32>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let minter_raw = deps.api.canonical_address(minter)?;
+    let mut quotas: Vec<MinterQuota> =
+        may_load(&deps.storage, MINTER_QUOTAS_KEY)?.unwrap_or_default();
+    let pos = quotas.iter().position(|q| q.minter == minter_raw);
+    match (quota, pos) {
+        // keep counting from where the existing quota left off
+        (Some(qty), Some(p)) => quotas[p].quota = qty,
+        (Some(qty), None) => quotas.push(MinterQuota {
+            minter: minter_raw,
+            quota: qty,
+            minted: 0,
+        }),
+        (None, Some(p)) => {
+            quotas.swap_remove(p);
+        }
+        (None, None) => (),
+    }
+    if quotas.is_empty() {
+        remove(&mut deps.storage, MINTER_QUOTAS_KEY);
+    } else {
+        save(&mut deps.storage, MINTER_QUOTAS_KEY, &quotas)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetMinterQuota {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// change the admin address
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `address` - new admin address
+pub fn change_admin<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    address: &HumanAddr,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let new_admin = deps.api.canonical_address(address)?;
+    if new_admin != config.admin {
+        config.admin = new_admin;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::ChangeAdmin { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// set the contract status level
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `level` - new ContractStatus
+pub fn set_contract_status<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    level: ContractStatus,
+) -> HandleResult {
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let new_status = level.to_u8();
+    if config.status != new_status {
+        config.status = new_status;
+        save(&mut deps.storage, CONFIG_KEY, &config)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetContractStatus {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// revoke the ability to use a specified permit
+///
+/// # Arguments
+///
+/// * `storage` - mutable reference to the contract's storage
+/// * `sender` - a reference to the message sender
+/// * `permit_name` - string slice of the name of the permit to revoke
+fn revoke_permit<S: Storage>(
+    storage: &mut S,
+    sender: &HumanAddr,
+    permit_name: &str,
+) -> HandleResult {
+    RevokedPermits::revoke_permit(storage, PREFIX_REVOKED_PERMITS, sender, permit_name);
+
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::RevokePermit { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// moves a token into the contract's escrow while it is bridged to another chain
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - token id String of token being bridged
+/// * `chain_nonce` - XP.network nonce of the destination chain
+/// * `destination_address` - address on the destination chain that will receive the token
+/// * `memo` - optional memo for the transfer tx
+#[allow(clippy::too_many_arguments)]
+pub fn bridge_lock<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    chain_nonce: u64,
+    destination_address: String,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let contract_raw: CanonicalAddr = load(&deps.storage, MY_ADDRESS_KEY)?;
+    let mut oper_for: Vec<CanonicalAddr> = Vec::new();
+    let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
+    let previous_owner = transfer_impl(
+        deps,
+        &env.block,
+        config,
+        &sender_raw,
+        token_id.clone(),
+        contract_raw,
+        &mut oper_for,
+        &mut inv_updates,
+        memo,
+    )?;
+    // transfer_impl already verified the token exists and the sender is permitted
+    let (mut token, idx) = get_token(&deps.storage, &token_id, None)?;
+    token.bridge_locked = true;
+    let token_key = idx.to_le_bytes();
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &token_key, &token)?;
+    let lock = StoredBridgeLock {
+        previous_owner,
+        chain_nonce,
+        destination_address,
+        time_locked: env.block.time,
+    };
+    let mut lock_store = PrefixedStorage::new(PREFIX_BRIDGE_LOCK, &mut deps.storage);
+    save(&mut lock_store, &token_key, &lock)?;
+    save(&mut deps.storage, CONFIG_KEY, &config)?;
+    update_owner_inventory(
+        &mut deps.storage,
+        &inv_updates,
+        PermissionType::ViewOwner.num_types(),
+    )?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BridgeLock { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// releases a bridge-locked token from the contract's escrow to a Secret owner
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - token id String of token being returned
+/// * `recipient` - a reference to the address that will own the token
+/// * `action_id` - unique id of this bridge action
+/// * `memo` - optional memo for the transfer tx
+#[allow(clippy::too_many_arguments)]
+pub fn bridge_unlock<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    recipient: &HumanAddr,
+    action_id: Uint128,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let validator: Option<CanonicalAddr> = may_load(&deps.storage, BRIDGE_VALIDATOR_KEY)?;
+    if validator.as_ref() != Some(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only the bridge validator may unlock bridged tokens",
+        ));
+    }
+    consume_action(&mut deps.storage, BridgeActionKind::Unlock, action_id)?;
+    let (mut token, idx) = get_token(&deps.storage, &token_id, None)?;
+    if !token.bridge_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is not locked by the bridge",
+            token_id
+        )));
+    }
+    let recipient_raw = deps.api.canonical_address(recipient)?;
+    let escrow = token.owner;
+    token.owner = recipient_raw.clone();
+    token.permissions.clear();
+    token.bridge_locked = false;
+    let token_key = idx.to_le_bytes();
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &token_key, &token)?;
+    let mut lock_store = PrefixedStorage::new(PREFIX_BRIDGE_LOCK, &mut deps.storage);
+    remove(&mut lock_store, &token_key);
+    let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
+    log_inventory_move(
+        &mut deps.storage,
+        &mut inv_updates,
+        idx,
+        &escrow,
+        &recipient_raw,
+    )?;
+    store_transfer(
+        &mut deps.storage,
+        config,
+        &env.block,
+        token_id,
+        escrow,
+        Some(sender_raw),
+        recipient_raw,
+        memo,
+    )?;
+    save(&mut deps.storage, CONFIG_KEY, &config)?;
+    update_owner_inventory(
+        &mut deps.storage,
+        &inv_updates,
+        PermissionType::ViewOwner.num_types(),
+    )?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::BridgeUnlock { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// burns tokens to send them back to another chain, and forwards the bridge fee to the
+/// bridge validator
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_ids` - list of token ids to burn
+/// * `chain_nonce` - XP.network nonce of the destination chain
+/// * `destination_address` - address on the destination chain that will receive the tokens
+/// * `fee` - the bridge fee
+/// * `action_id` - unique id supplied by the relayer
+/// * `memo` - optional memo for the burn txs
+#[allow(clippy::too_many_arguments)]
+pub fn burn_for_bridge<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_ids: Vec<String>,
+    chain_nonce: u64,
+    destination_address: String,
+    fee: Uint128,
+    action_id: Uint128,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    if token_ids.is_empty() {
+        return Err(StdError::generic_err("No tokens were specified to burn"));
+    }
+    // any other coins sent would be stranded in the contract
+    let paid_exact = match env.message.sent_funds.as_slice() {
+        [] => fee.u128() == 0,
+        [coin] => coin.denom == "uscrt" && coin.amount == fee,
+        _ => false,
+    };
+    if !paid_exact {
+        return Err(StdError::generic_err(format!(
+            "The bridge fee of {}uscrt must be sent with BurnForBridge",
+            fee
+        )));
+    }
+    let validator: CanonicalAddr = may_load(&deps.storage, BRIDGE_VALIDATOR_KEY)?
+        .ok_or_else(|| StdError::generic_err("The bridge validator has not been set"))?;
+    consume_action(&mut deps.storage, BridgeActionKind::Burn, action_id)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let burn_seq = config.bridge_burn_cnt;
+    config.bridge_burn_cnt = burn_seq.checked_add(1).ok_or_else(|| {
+        StdError::generic_err("Bridge burn count has reached the implementation limit")
+    })?;
+    let bridge_burn = BridgeBurn {
+        burn_seq,
+        relayer_action_id: action_id,
+        token_ids: token_ids.clone(),
+        chain_nonce,
+        destination_address,
+        fee,
+        block_height: env.block.height,
+        block_time: env.block.time,
+    };
+    let burns = vec![Burn { token_ids, memo }];
+    let mut messages = burn_list(
+        deps,
+        &env,
+        config,
+        &sender_raw,
+        burns,
+        &BurnType::Bridge(&bridge_burn),
+    )?;
+    let mut burn_store = PrefixedStorage::new(PREFIX_BRIDGE_BURNS, &mut deps.storage);
+    save(&mut burn_store, &burn_seq.to_le_bytes(), &bridge_burn)?;
+    if fee.u128() > 0 {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            from_address: env.contract.address,
+            to_address: deps.api.human_address(&validator)?,
+            amount: vec![Coin {
+                denom: "uscrt".to_string(),
+                amount: fee,
+            }],
+        }));
+    }
+    Ok(HandleResponse {
+        messages,
+        log: vec![log("bridge_burn_seq", burn_seq)],
+        data: Some(to_binary(&HandleAnswer::BurnForBridge { burn_seq })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets the address allowed to unlock bridge-locked tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `address` - a reference to the bridge validator's address
+pub fn set_bridge_validator<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    address: &HumanAddr,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let validator_raw = deps.api.canonical_address(address)?;
+    save(&mut deps.storage, BRIDGE_VALIDATOR_KEY, &validator_raw)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetBridgeValidator {
+            status: Success,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// sets the validators that must approve bridge mints
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `validators` - list of validator addresses
+/// * `threshold` - number of validator votes needed to execute a mint
+pub fn set_validators<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    validators: &[HumanAddr],
+    threshold: u32,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut validator_set = ValidatorSet::default();
+    for validator in validators.iter() {
+        let validator_raw = deps.api.canonical_address(validator)?;
+        if !validator_set.validators.contains(&validator_raw) {
+            validator_set.validators.push(validator_raw);
+        }
+    }
+    if validator_set.validators.is_empty() {
+        remove(&mut deps.storage, VALIDATORS_KEY);
+    } else {
+        if threshold == 0 || threshold as usize > validator_set.validators.len() {
+            return Err(StdError::generic_err(
+                "Threshold must be between 1 and the number of validators",
+            ));
+        }
+        validator_set.threshold = threshold;
+        save(&mut deps.storage, VALIDATORS_KEY, &validator_set)?;
+    }
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::SetValidators { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// proposes a bridge mint, and performs it if the proposer's vote reaches the threshold
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `mint` - the mint to perform
+/// * `action_id` - unique id of this bridge action
+pub fn propose_mint<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    mint: Mint,
+    action_id: Uint128,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let validator_set = load_validator_set(&deps.storage, &sender_raw)?;
+    check_action_unconsumed(&deps.storage, BridgeActionKind::Mint, action_id)?;
+    let key = action_id.u128().to_le_bytes();
+    let proposed_store = ReadonlyPrefixedStorage::new(PREFIX_PROPOSED_MINTS, &deps.storage);
+    if let Some(pending_id) = may_load::<u64, _>(&proposed_store, &key)? {
+        return Err(StdError::generic_err(format!(
+            "Bridge action {} has already been proposed as mint proposal {}",
+            action_id, pending_id
+        )));
+    }
+    let pending: Vec<u64> = may_load(&deps.storage, PENDING_PROPOSALS_KEY)?.unwrap_or_default();
+    if pending.len() >= MAX_PENDING_PROPOSALS {
+        return Err(StdError::generic_err(format!(
+            "There can not be more than {} pending mint proposals",
+            MAX_PENDING_PROPOSALS
+        )));
+    }
+    let proposal_id = config.mint_proposal_cnt;
+    config.mint_proposal_cnt = proposal_id.checked_add(1).ok_or_else(|| {
+        StdError::generic_err("Mint proposal count has reached the implementation limit")
+    })?;
+    let proposal = StoredMintProposal {
+        mint,
+        action_id,
+        proposer: sender_raw.clone(),
+        votes: vec![sender_raw],
+    };
+    let token_id =
+        execute_mint_proposal(deps, &env, config, &validator_set, proposal_id, proposal)?;
+    save(&mut deps.storage, CONFIG_KEY, &config)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("mint_proposal_id", proposal_id)],
+        data: Some(to_binary(&HandleAnswer::ProposeMint {
+            proposal_id,
+            token_id,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// votes for a pending mint proposal, and performs the mint if the threshold is reached
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `proposal_id` - id of the proposal being voted for
+pub fn vote_mint<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    proposal_id: u64,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let validator_set = load_validator_set(&deps.storage, &sender_raw)?;
+    let prop_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_PROPOSALS, &deps.storage);
+    let mut proposal: StoredMintProposal = json_may_load(&prop_store, &proposal_id.to_le_bytes())?
+        .ok_or_else(|| {
+            StdError::generic_err(format!("Mint proposal {} is not pending", proposal_id))
+        })?;
+    if proposal.votes.contains(&sender_raw) {
+        return Err(StdError::generic_err(format!(
+            "You have already voted for mint proposal {}",
+            proposal_id
+        )));
+    }
+    proposal.votes.push(sender_raw);
+    let token_id =
+        execute_mint_proposal(deps, &env, config, &validator_set, proposal_id, proposal)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("mint_proposal_id", proposal_id)],
+        data: Some(to_binary(&HandleAnswer::VoteMint {
+            proposal_id,
+            token_id,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// cancels a pending mint proposal.  This allows the admin to remove proposals whose mint
+/// can no longer be performed
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `proposal_id` - id of the proposal being cancelled
+pub fn cancel_mint_proposal<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    proposal_id: u64,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let key = proposal_id.to_le_bytes();
+    let mut prop_store = PrefixedStorage::new(PREFIX_MINT_PROPOSALS, &mut deps.storage);
+    let proposal: StoredMintProposal = json_may_load(&prop_store, &key)?.ok_or_else(|| {
+        StdError::generic_err(format!("Mint proposal {} is not pending", proposal_id))
+    })?;
+    remove(&mut prop_store, &key);
+    let mut proposed_store = PrefixedStorage::new(PREFIX_PROPOSED_MINTS, &mut deps.storage);
+    remove(
+        &mut proposed_store,
+        &proposal.action_id.u128().to_le_bytes(),
+    );
+    let mut pending: Vec<u64> = may_load(&deps.storage, PENDING_PROPOSALS_KEY)?.unwrap_or_default();
+    pending.retain(|p| *p != proposal_id);
+    save(&mut deps.storage, PENDING_PROPOSALS_KEY, &pending)?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("mint_proposal_id", proposal_id)],
+        data: Some(to_binary(&HandleAnswer::CancelMintProposal {
+            status: Success,
         })?),
     })
 }
 
+/// Returns StdResult<ValidatorSet>
+///
+/// loads the validator set, and verifies the sender is one of the validators
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `sender` - a reference to the message sender address
+fn load_validator_set<S: ReadonlyStorage>(
+    storage: &S,
+    sender: &CanonicalAddr,
+) -> StdResult<ValidatorSet> {
+    let validator_set: ValidatorSet = may_load(storage, VALIDATORS_KEY)?.unwrap_or_default();
+    if !validator_set.validators.contains(sender) {
+        return Err(StdError::generic_err(
+            "Only validators may propose or vote on mints",
+        ));
+    }
+    Ok(validator_set)
+}
+
+/// Returns StdResult<Option<String>>
+///
+/// performs a proposed mint and consumes its action id if it has reached the vote threshold,
+/// otherwise saves the proposal as pending.  Returns the minted token id if the mint was
+/// performed
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `validator_set` - a reference to the current validator set
+/// * `proposal_id` - id of the proposal
+/// * `proposal` - the mint proposal
+fn execute_mint_proposal<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &mut Config,
+    validator_set: &ValidatorSet,
+    proposal_id: u64,
+    proposal: StoredMintProposal,
+) -> StdResult<Option<String>> {
+    let key = proposal_id.to_le_bytes();
+    let action_key = proposal.action_id.u128().to_le_bytes();
+    let mut pending: Vec<u64> = may_load(&deps.storage, PENDING_PROPOSALS_KEY)?.unwrap_or_default();
+    // only count votes of addresses that are still validators
+    let vote_count = proposal
+        .votes
+        .iter()
+        .filter(|v| validator_set.validators.contains(v))
+        .count();
+    if vote_count < validator_set.threshold as usize {
+        let mut prop_store = PrefixedStorage::new(PREFIX_MINT_PROPOSALS, &mut deps.storage);
+        json_save(&mut prop_store, &key, &proposal)?;
+        if !pending.contains(&proposal_id) {
+            pending.push(proposal_id);
+            save(&mut deps.storage, PENDING_PROPOSALS_KEY, &pending)?;
+            let mut proposed_store = PrefixedStorage::new(PREFIX_PROPOSED_MINTS, &mut deps.storage);
+            save(&mut proposed_store, &action_key, &proposal_id)?;
+        }
+        return Ok(None);
+    }
+    consume_action(
+        &mut deps.storage,
+        BridgeActionKind::Mint,
+        proposal.action_id,
+    )?;
+    let mut minted = mint_list(deps, env, config, &proposal.proposer, vec![proposal.mint])?;
+    let mut prop_store = PrefixedStorage::new(PREFIX_MINT_PROPOSALS, &mut deps.storage);
+    remove(&mut prop_store, &key);
+    let mut proposed_store = PrefixedStorage::new(PREFIX_PROPOSED_MINTS, &mut deps.storage);
+    remove(&mut proposed_store, &action_key);
+    if let Some(pos) = pending.iter().position(|p| *p == proposal_id) {
+        pending.remove(pos);
+        save(&mut deps.storage, PENDING_PROPOSALS_KEY, &pending)?;
+    }
+    Ok(minted.pop())
+}
+
+/// Returns &'static [u8] of the storage prefix of the processed action ids of a kind
+///
+/// # Arguments
+///
+/// * `kind` - the kind of bridge action
+fn consumed_prefix(kind: BridgeActionKind) -> &'static [u8] {
+    match kind {
+        BridgeActionKind::Mint => PREFIX_CONSUMED_MINTS,
+        BridgeActionKind::Unlock => PREFIX_CONSUMED_UNLOCKS,
+        BridgeActionKind::Burn => PREFIX_CONSUMED_BURNS,
+    }
+}
+
+/// Returns StdResult<()>
+///
+/// returns an error if a bridge action id has already been processed
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `kind` - the kind of bridge action
+/// * `action_id` - the bridge action id
+fn check_action_unconsumed<S: ReadonlyStorage>(
+    storage: &S,
+    kind: BridgeActionKind,
+    action_id: Uint128,
+) -> StdResult<()> {
+    let consumed_store = ReadonlyPrefixedStorage::new(consumed_prefix(kind), storage);
+    if may_load::<bool, _>(&consumed_store, &action_id.u128().to_le_bytes())?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Bridge action {} has already been processed",
+            action_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// marks a bridge action id as processed, or returns an error if it already was
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `kind` - the kind of bridge action
+/// * `action_id` - the bridge action id
+fn consume_action<S: Storage>(
+    storage: &mut S,
+    kind: BridgeActionKind,
+    action_id: Uint128,
+) -> StdResult<()> {
+    check_action_unconsumed(storage, kind, action_id)?;
+    let mut consumed_store = PrefixedStorage::new(consumed_prefix(kind), storage);
+    save(&mut consumed_store, &action_id.u128().to_le_bytes(), &true)
+}
+
+/// Returns StdResult<()>
+///
+/// verifies that bridge mints are not performed without validator approval.  While
+/// validators are set, mints that specify an origin, and any direct mint by a validator or
+/// the bridge validator, must go through ProposeMint/VoteMint.  Other minters may still
+/// mint tokens that do not specify an origin
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `sender` - a reference to the address performing the mints
+/// * `mints` - a slice of the mints to perform
+fn check_bridge_mints<S: ReadonlyStorage>(
+    storage: &S,
+    sender: &CanonicalAddr,
+    mints: &[Mint],
+) -> StdResult<()> {
+    let validator_set: ValidatorSet = match may_load(storage, VALIDATORS_KEY)? {
+        Some(set) => set,
+        None => return Ok(()),
+    };
+    if mints.iter().any(|m| m.origin.is_some()) {
+        return Err(StdError::generic_err(
+            "Mints of bridged tokens must be approved by the validators",
+        ));
+    }
+    let bridge_validator: Option<CanonicalAddr> = may_load(storage, BRIDGE_VALIDATOR_KEY)?;
+    if validator_set.validators.contains(sender) || bridge_validator.as_ref() == Some(sender) {
+        return Err(StdError::generic_err(
+            "Bridge validators must mint through ProposeMint/VoteMint",
+        ));
+    }
+    Ok(())
+}
+
 /// Returns HandleResult
 ///
-/// sets new public and/or private metadata
+/// sets or removes the configuration of the paid public mint
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - token id String slice of token whose metadata should be updated
-/// * `public_metadata` - the optional new public metadata viewable by everyone
-/// * `private_metadata` - the optional new private metadata viewable by everyone
-pub fn set_metadata<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `sale` - optional sale configuration
+pub fn set_sale_config<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    token_id: &str,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
+    sale: Option<SaleConfig>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if !(token.owner == sender_raw && config.owner_may_update_metadata) {
-        let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        if !(minters.contains(&sender_raw) && config.minter_may_update_metadata) {
-            return Err(StdError::generic_err(custom_err));
-        }
-    }
-    if let Some(public) = public_metadata {
-        set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PUB_META, &public)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
     }
-    if let Some(private) = private_metadata {
-        set_metadata_impl(&mut deps.storage, &token, idx, PREFIX_PRIV_META, &private)?;
+    if let Some(sale_config) = sale {
+        save(&mut deps.storage, SALE_CONFIG_KEY, &sale_config)?;
+    } else {
+        remove(&mut deps.storage, SALE_CONFIG_KEY);
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetMetadata { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::SetSaleConfig { status: Success })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// sets new royalty information for a specified token or if no token ID is provided, sets new
-/// royalty information as the contract's default
+/// mints tokens to the message sender if they paid the sale price
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id String slice of token whose royalty info should be updated
-/// * `royalty_info` - a optional reference to the new RoyaltyInfo
-pub fn set_royalty_info<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `quantity` - optional number of tokens to purchase
+/// * `entropy` - optional entropy mixed into the assignment of committed metadata
+/// * `memo` - optional memo for the mint txs
+pub fn purchase_mint<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    token_id: Option<&str>,
-    royalty_info: Option<&RoyaltyInfo>,
+    quantity: Option<u32>,
+    entropy: Option<String>,
+    memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    // set a token's royalties
-    if let Some(id) = token_id {
-        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they are not authorized for that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(custom_err)
-        };
-        let (token, idx) = get_token(&deps.storage, id, opt_err)?;
-        if !token.transferable {
-            return Err(StdError::generic_err(
-                "Non-transferable tokens can not be sold, so royalties are meaningless",
-            ));
-        }
-        let token_key = idx.to_le_bytes();
-        let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
-        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
-        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
-            return Err(StdError::generic_err(custom_err));
+    let minted = purchase(
+        deps,
+        &env,
+        config,
+        &sender_raw,
+        quantity,
+        entropy,
+        memo,
+        SalePayment::Native,
+    )?;
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("minted", format!("{:?}", &minted))],
+        data: Some(to_binary(&HandleAnswer::PurchaseMint {
+            token_ids: minted,
+        })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// SNIP-20 receiver interface used to purchase tokens from the public sale
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `from` - owner of the SNIP-20 tokens that were sent
+/// * `amount` - amount of SNIP-20 tokens sent
+/// * `msg` - optional base64 encoded ReceiveMsg
+pub fn receive<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    from: &HumanAddr,
+    amount: Uint128,
+    msg: Option<Binary>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let token_raw = deps.api.canonical_address(&env.message.sender)?;
+    let tokens: Vec<StoredPaymentToken> =
+        may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+    if !tokens.iter().any(|t| t.address == token_raw) {
+        return Err(StdError::generic_err(
+            "Payments from unregistered token contracts are not accepted",
+        ));
+    }
+    let receive_msg: ReceiveMsg = from_binary(&msg.ok_or_else(|| {
+        StdError::generic_err("A ReceiveMsg must be sent with the SNIP-20 tokens")
+    })?)?;
+    match receive_msg {
+        ReceiveMsg::PurchaseMint {
+            quantity,
+            entropy,
+            memo,
+        } => {
+            let from_raw = deps.api.canonical_address(from)?;
+            let minted = purchase(
+                deps,
+                &env,
+                config,
+                &from_raw,
+                quantity,
+                entropy,
+                memo,
+                SalePayment::Snip20 {
+                    token: token_raw,
+                    amount,
+                },
+            )?;
+            Ok(HandleResponse {
+                messages: vec![],
+                log: vec![log("minted", format!("{:?}", &minted))],
+                data: Some(to_binary(&HandleAnswer::Receive { token_ids: minted })?),
+            })
         }
-        let default_roy = royalty_info.as_ref().map_or_else(
-            || may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY),
-            |_r| Ok(None),
-        )?;
-        let mut roy_store = PrefixedStorage::new(PREFIX_ROYALTY_INFO, &mut deps.storage);
-        store_royalties(
-            &mut roy_store,
-            &deps.api,
-            royalty_info,
-            default_roy.as_ref(),
-            &token_key,
-        )?;
-    // set default royalty
-    } else {
-        let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        if !minters.contains(&sender_raw) {
-            return Err(StdError::generic_err(
-                "Only designated minters can set default royalties for the contract",
-            ));
+        ReceiveMsg::Buy { token_id, memo } => {
+            let messages = buy_listing(
+                deps,
+                &env,
+                config,
+                from.clone(),
+                token_id.clone(),
+                memo,
+                SalePayment::Snip20 {
+                    token: token_raw,
+                    amount,
+                },
+            )?;
+            Ok(HandleResponse {
+                messages,
+                log: vec![],
+                data: Some(to_binary(&HandleAnswer::Receive {
+                    token_ids: vec![token_id],
+                })?),
+            })
         }
-        store_royalties(
-            &mut deps.storage,
-            &deps.api,
-            royalty_info,
-            None,
-            DEFAULT_ROYALTY_KEY,
-        )?;
-    };
+    }
+}
 
+/// Returns HandleResult
+///
+/// adds SNIP-20 tokens accepted as payment for the public sale, or changes their prices
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `tokens` - SNIP-20 tokens and their prices
+pub fn add_payment_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    tokens: &[PaymentToken],
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let messages = store_payment_tokens(deps, &env, tokens)?;
     Ok(HandleResponse {
-        messages: vec![],
+        messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetRoyaltyInfo {
+        data: Some(to_binary(&HandleAnswer::AddPaymentTokens {
             status: Success,
         })?),
     })
 }
 
+/// Returns StdResult<Vec<CosmosMsg>>
+///
+/// saves SNIP-20 tokens accepted as payment, keeping any proceeds of tokens that were already
+/// accepted, and returns the messages registering with each of them
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `tokens` - SNIP-20 tokens and their prices
+fn store_payment_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    tokens: &[PaymentToken],
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut stored: Vec<StoredPaymentToken> =
+        may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    for token in tokens.iter() {
+        let address = deps.api.canonical_address(&token.address)?;
+        if let Some(existing) = stored.iter_mut().find(|t| t.address == address) {
+            existing.code_hash = token.code_hash.clone();
+            existing.price = token.price;
+        } else {
+            stored.push(StoredPaymentToken {
+                address,
+                code_hash: token.code_hash.clone(),
+                price: token.price,
+                proceeds: Uint128(0),
+            });
+        }
+        messages.push(register_receive_msg(env.contract_code_hash.clone(), token)?);
+    }
+    save(&mut deps.storage, PAYMENT_TOKENS_KEY, &stored)?;
+    Ok(messages)
+}
+
+/// Returns StdResult<Vec<String>>
+///
+/// enforces the public sale's purchase limit, and mints the purchased tokens.  Returns the
+/// minted ids
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `purchaser` - a reference to the purchaser's address
+/// * `quantity` - optional number of tokens to purchase
+/// * `entropy` - optional entropy mixed into the assignment of committed metadata
+/// * `memo` - optional memo for the mint txs
+/// * `payment` - how the purchase was paid
+#[allow(clippy::too_many_arguments)]
+fn purchase<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &mut Config,
+    purchaser: &CanonicalAddr,
+    quantity: Option<u32>,
+    entropy: Option<String>,
+    memo: Option<String>,
+    payment: SalePayment,
+) -> StdResult<Vec<String>> {
+    let sale: SaleConfig = may_load(&deps.storage, SALE_CONFIG_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no sale in progress"))?;
+    let quantity = quantity.unwrap_or(1);
+    // enforce the purchase limit
+    let purchase_store = ReadonlyPrefixedStorage::new(PREFIX_PURCHASES, &deps.storage);
+    let purchased: u32 = may_load(&purchase_store, purchaser.as_slice())?.unwrap_or(0);
+    let new_purchased = purchased.checked_add(quantity).ok_or_else(|| {
+        StdError::generic_err("Attempting to purchase more tokens than the implementation limit")
+    })?;
+    if let Some(limit) = sale.limit_per_address {
+        if new_purchased > limit {
+            return Err(StdError::generic_err(format!(
+                "Purchasing {} tokens would exceed the limit of {} tokens per address",
+                quantity, limit
+            )));
+        }
+    }
+    let minted = sale_mint(
+        deps, env, config, &sale, purchaser, quantity, entropy, memo, payment,
+    )?;
+    let mut purchase_store = PrefixedStorage::new(PREFIX_PURCHASES, &mut deps.storage);
+    save(&mut purchase_store, purchaser.as_slice(), &new_purchased)?;
+    Ok(minted)
+}
+
 /// Returns HandleResult
 ///
-/// makes the sealed private metadata public
+/// sets or removes the configuration of the allowlist presale
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - token id String slice of token whose metadata should be updated
-pub fn reveal<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `presale` - optional presale configuration
+pub fn set_presale_config<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    token_id: &str,
+    presale: Option<SaleConfig>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    if !config.sealed_metadata_is_enabled {
-        return Err(StdError::generic_err(
-            "Sealed metadata functionality is not enabled for this contract",
-        ));
-    }
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let custom_err = format!("You do not own token {}", token_id);
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they do not own that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (mut token, idx) = get_token(&deps.storage, token_id, opt_err)?;
-    if token.unwrapped {
+    if config.admin != sender_raw {
         return Err(StdError::generic_err(
-            "This token has already been unwrapped",
-        ));
-    }
-    if token.owner != sender_raw {
-        return Err(StdError::generic_err(custom_err));
+            "This is an admin command and can only be run from the admin address",
+        ));
     }
-    token.unwrapped = true;
-    let token_key = idx.to_le_bytes();
-    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
-    json_save(&mut info_store, &token_key, &token)?;
-    if !config.unwrap_to_private {
-        let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
-        let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
-        if let Some(metadata) = may_priv {
-            remove(&mut priv_store, &token_key);
-            let mut pub_store = PrefixedStorage::new(PREFIX_PUB_META, &mut deps.storage);
-            save(&mut pub_store, &token_key, &metadata)?;
-        }
+    if let Some(presale_config) = presale {
+        save(&mut deps.storage, PRESALE_CONFIG_KEY, &presale_config)?;
+    } else {
+        remove(&mut deps.storage, PRESALE_CONFIG_KEY);
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::Reveal { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::SetPresaleConfig {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// grants/revokes trasfer permission on a token
+/// replaces or adds to the presale allowlist
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `spender` - a reference to the address being granted permission
-/// * `token_id` - string slice of the token id to grant permission to
-/// * `expires` - optional Expiration for this approval
-/// * `is_approve` - true if this is an Approve call
-#[allow(clippy::too_many_arguments)]
-pub fn approve_revoke<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `entries` - allowlisted addresses and their allocations
+/// * `replace` - true if the existing allowlist should be discarded
+pub fn update_presale_list<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    spender: &HumanAddr,
-    token_id: &str,
-    expires: Option<Expiration>,
-    is_approve: bool,
+    entries: &[PresaleEntry],
+    replace: bool,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let address_raw = deps.api.canonical_address(spender)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let custom_err = format!(
-        "Not authorized to grant/revoke transfer permission for token {}",
-        token_id
-    );
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(&deps.storage, token_id, opt_err)?;
-    let mut all_perm: Option<Vec<Permission>> = None;
-    let mut from_oper = false;
-    let transfer_idx = PermissionType::Transfer.to_usize();
-    // if not called by the owner, check if message sender has operator status
-    if token.owner != sender_raw {
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
-        if let Some(list) = may_list.clone() {
-            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
-                if let Some(exp) = perm.expirations[transfer_idx] {
-                    if exp.is_expired(&env.block) {
-                        return Err(StdError::generic_err(format!(
-                            "Transfer authority for all tokens of {} has expired",
-                            &deps.api.human_address(&token.owner)?
-                        )));
-                    } else {
-                        from_oper = true;
-                    }
-                }
-            }
-        }
-        if !from_oper {
-            return Err(StdError::generic_err(custom_err));
-        }
-        all_perm = may_list;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    let response: HandleAnswer;
-    if is_approve {
-        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
-        response = HandleAnswer::Approve { status: Success };
-    } else {
-        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
-        response = HandleAnswer::Revoke { status: Success };
+    let mut epoch: u64 = may_load(&deps.storage, PRESALE_EPOCH_KEY)?.unwrap_or(0);
+    // rather than iterating over the old list to delete it, just start a new one
+    if replace {
+        epoch = epoch.checked_add(1).ok_or_else(|| {
+            StdError::generic_err("Presale lists have reached the implementation limit")
+        })?;
+        save(&mut deps.storage, PRESALE_EPOCH_KEY, &epoch)?;
     }
-    let owner = token.owner.clone();
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given: true,
-        accesses,
-        expires,
-        from_oper,
+    let epoch_key = epoch.to_le_bytes();
+    for entry in entries.iter() {
+        let address_raw = deps.api.canonical_address(&entry.address)?;
+        let mut presale_store =
+            PrefixedStorage::multilevel(&[PREFIX_PRESALE, &epoch_key], &mut deps.storage);
+        save(
+            &mut presale_store,
+            address_raw.as_slice(),
+            &entry.allocation,
+        )?;
+    }
+    let data = if replace {
+        HandleAnswer::SetPresaleList { status: Success }
+    } else {
+        HandleAnswer::AddToPresaleList { status: Success }
     };
-    process_accesses(
-        &mut deps.storage,
-        &env,
-        &address_raw,
-        &owner,
-        &mut proc_info,
-        all_perm,
-    )?;
-    let res = HandleResponse {
+    Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&response)?),
-    };
-    Ok(res)
+        data: Some(to_binary(&data)?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// makes an address' token ownership private
+/// mints tokens to an allowlisted message sender if they paid the presale price
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-pub fn make_owner_private<S: Storage, A: Api, Q: Querier>(
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `quantity` - optional number of tokens to purchase
+/// * `entropy` - optional entropy mixed into the assignment of committed metadata
+/// * `memo` - optional memo for the mint txs
+pub fn presale_mint<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
+    quantity: Option<u32>,
+    entropy: Option<String>,
+    memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
+    let presale: SaleConfig = may_load(&deps.storage, PRESALE_CONFIG_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no presale in progress"))?;
+    let quantity = quantity.unwrap_or(1);
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    // only need to do this if the contract has public ownership
-    if config.owner_is_public {
-        let mut priv_store = PrefixedStorage::new(PREFIX_OWNER_PRIV, &mut deps.storage);
-        save(&mut priv_store, sender_raw.as_slice(), &false)?
+    let epoch: u64 = may_load(&deps.storage, PRESALE_EPOCH_KEY)?.unwrap_or(0);
+    let epoch_key = epoch.to_le_bytes();
+    let presale_store =
+        ReadonlyPrefixedStorage::multilevel(&[PREFIX_PRESALE, &epoch_key], &deps.storage);
+    let allocation: u32 = may_load(&presale_store, sender_raw.as_slice())?.unwrap_or(0);
+    if quantity > allocation {
+        return Err(StdError::generic_err(format!(
+            "Purchasing {} tokens would exceed your presale allocation of {} tokens",
+            quantity, allocation
+        )));
     }
+    let minted = sale_mint(
+        deps,
+        &env,
+        config,
+        &presale,
+        &sender_raw,
+        quantity,
+        entropy,
+        memo,
+        SalePayment::Native,
+    )?;
+    let mut presale_store =
+        PrefixedStorage::multilevel(&[PREFIX_PRESALE, &epoch_key], &mut deps.storage);
+    save(
+        &mut presale_store,
+        sender_raw.as_slice(),
+        &(allocation - quantity),
+    )?;
     Ok(HandleResponse {
         messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&HandleAnswer::MakeOwnershipPrivate {
-            status: Success,
-        })?),
+        log: vec![log("minted", format!("{:?}", &minted))],
+        data: Some(to_binary(&HandleAnswer::PresaleMint { token_ids: minted })?),
     })
 }
 
+/// Returns StdResult<()>
+///
+/// mixes a purchase into the entropy used to assign committed metadata, if metadata has been
+/// committed and its assignment offset has not been generated yet
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `env` - a reference to the Env of contract's environment
+/// * `purchaser` - a reference to the purchaser's address
+/// * `entropy` - optional entropy supplied by the purchaser
+fn add_provenance_entropy<S: Storage>(
+    storage: &mut S,
+    env: &Env,
+    purchaser: &CanonicalAddr,
+    entropy: Option<&str>,
+) -> StdResult<()> {
+    if let Some(mut provenance) = may_load::<StoredProvenance, _>(storage, PROVENANCE_KEY)? {
+        if provenance.offset.is_none() {
+            let mut input = purchaser.as_slice().to_vec();
+            input.extend_from_slice(&env.block.height.to_be_bytes());
+            input.extend_from_slice(&env.block.time.to_be_bytes());
+            input.extend_from_slice(entropy.unwrap_or_default().as_bytes());
+            provenance.add_entropy(&input);
+            save(storage, PROVENANCE_KEY, &provenance)?;
+        }
+    }
+    Ok(())
+}
+
+// how a sale or listing purchase was paid
+enum SalePayment {
+    // native coins sent with the message
+    Native,
+    // SNIP-20 tokens sent from an accepted payment token contract
+    Snip20 {
+        token: CanonicalAddr,
+        amount: Uint128,
+    },
+}
+
+/// Returns StdResult<Vec<String>>
+///
+/// verifies the sale is open and the purchaser paid the sale price, then mints the purchased
+/// tokens to the purchaser, with the admin as their creator, and adds the payment to the
+/// proceeds.  Returns the minted ids
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `sale` - a reference to the configuration of the sale
+/// * `purchaser` - a reference to the purchaser's address
+/// * `quantity` - number of tokens to purchase
+/// * `entropy` - optional entropy mixed into the assignment of committed metadata
+/// * `memo` - optional memo for the mint txs
+/// * `payment` - how the purchase was paid
+#[allow(clippy::too_many_arguments)]
+fn sale_mint<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &mut Config,
+    sale: &SaleConfig,
+    purchaser: &CanonicalAddr,
+    quantity: u32,
+    entropy: Option<String>,
+    memo: Option<String>,
+    payment: SalePayment,
+) -> StdResult<Vec<String>> {
+    sale.check_open(&env.block)?;
+    if quantity == 0 {
+        return Err(StdError::generic_err("Must purchase at least one token"));
+    }
+    let cost_of = |price: Uint128| {
+        price
+            .u128()
+            .checked_mul(quantity as u128)
+            .ok_or_else(|| StdError::generic_err("Purchase price overflow"))
+    };
+    let mut tokens: Vec<StoredPaymentToken> = Vec::new();
+    let cost = match &payment {
+        SalePayment::Native => {
+            let cost = cost_of(sale.price)?;
+            let paid_exact = match env.message.sent_funds.as_slice() {
+                [] => cost == 0,
+                [coin] => coin.denom == sale.denom && coin.amount.u128() == cost,
+                _ => false,
+            };
+            if !paid_exact {
+                return Err(StdError::generic_err(format!(
+                    "The purchase price of {}{} must be sent with the purchase",
+                    cost, sale.denom
+                )));
+            }
+            cost
+        }
+        SalePayment::Snip20 { token, amount } => {
+            tokens = may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+            let price = tokens
+                .iter()
+                .find(|t| t.address == *token)
+                .map(|t| t.price)
+                .ok_or_else(|| {
+                    StdError::generic_err(
+                        "Payments from unregistered token contracts are not accepted",
+                    )
+                })?;
+            let cost = cost_of(price)?;
+            if amount.u128() != cost {
+                return Err(StdError::generic_err(format!(
+                    "The purchase price of {} tokens must be sent with the purchase",
+                    cost
+                )));
+            }
+            cost
+        }
+    };
+    let owner = deps.api.human_address(purchaser)?;
+    let mints = (0..quantity)
+        .map(|_| Mint {
+            token_id: None,
+            owner: Some(owner.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: Some(true),
+            origin: None,
+            memo: memo.clone(),
+        })
+        .collect();
+    // the admin is the creator of sold tokens, so purchasers can not rewrite their royalties
+    let creator = config.admin.clone();
+    let minted = mint_list(deps, env, config, &creator, mints)?;
+    add_provenance_entropy(&mut deps.storage, env, purchaser, entropy.as_deref())?;
+    // add the payment to the proceeds
+    if cost > 0 {
+        let overflow =
+            || StdError::generic_err("Sale proceeds have reached the implementation limit");
+        match payment {
+            SalePayment::Native => {
+                let mut proceeds: Vec<Coin> =
+                    may_load(&deps.storage, PROCEEDS_KEY)?.unwrap_or_default();
+                if let Some(coin) = proceeds.iter_mut().find(|c| c.denom == sale.denom) {
+                    coin.amount =
+                        Uint128(coin.amount.u128().checked_add(cost).ok_or_else(overflow)?);
+                } else {
+                    proceeds.push(Coin {
+                        denom: sale.denom.clone(),
+                        amount: Uint128(cost),
+                    });
+                }
+                save(&mut deps.storage, PROCEEDS_KEY, &proceeds)?;
+            }
+            SalePayment::Snip20 { token, .. } => {
+                if let Some(stored) = tokens.iter_mut().find(|t| t.address == token) {
+                    stored.proceeds = Uint128(
+                        stored
+                            .proceeds
+                            .u128()
+                            .checked_add(cost)
+                            .ok_or_else(overflow)?,
+                    );
+                }
+                save(&mut deps.storage, PAYMENT_TOKENS_KEY, &tokens)?;
+            }
+        }
+    }
+    Ok(minted)
+}
+
 /// Returns HandleResult
 ///
-/// adds/revokes access for everyone
+/// sends the accumulated sale proceeds
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id to apply approvals to
-/// * `view_owner` - optional access level for viewing token ownership
-/// * `view_private_metadata` - optional access level for viewing private metadata
-/// * `expires` - optional Expiration for this approval
-#[allow(clippy::too_many_arguments)]
-pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipient` - optional address to send the proceeds to
+pub fn withdraw_proceeds<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    token_id: Option<String>,
-    view_owner: Option<AccessLevel>,
-    view_private_metadata: Option<AccessLevel>,
-    expires: Option<Expiration>,
+    recipient: Option<HumanAddr>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let token_given: bool;
-    // use this "address" to represent global permission
-    let global_raw = CanonicalAddr(Binary::from(b"public"));
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let mut custom_err = String::new();
-    let (token, idx) = if let Some(id) = token_id {
-        token_given = true;
-        custom_err = format!("You do not own token {}", id);
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they do not own that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(&*custom_err)
-        };
-        get_token(&deps.storage, &id, opt_err)?
-    } else {
-        token_given = false;
-        (
-            Token {
-                owner: sender_raw.clone(),
-                permissions: Vec::new(),
-                unwrapped: false,
-                transferable: true,
-            },
-            0,
-        )
-    };
-    // if trying to set token permissions when you are not the owner
-    if token_given && token.owner != sender_raw {
-        return Err(StdError::generic_err(custom_err));
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
-    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given,
-        accesses,
-        expires,
-        from_oper: false,
+    let to_address = if let Some(r) = recipient {
+        r
+    } else {
+        deps.api.human_address(&config.admin)?
     };
-    process_accesses(
-        &mut deps.storage,
-        &env,
-        &global_raw,
-        &sender_raw,
-        &mut proc_info,
-        None,
-    )?;
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    let proceeds: Vec<Coin> = may_load(&deps.storage, PROCEEDS_KEY)?.unwrap_or_default();
+    if !proceeds.is_empty() {
+        remove(&mut deps.storage, PROCEEDS_KEY);
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            from_address: env.contract.address,
+            to_address: to_address.clone(),
+            amount: proceeds,
+        }));
+    }
+    let mut tokens: Vec<StoredPaymentToken> =
+        may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+    let mut snip20_withdrawn = false;
+    for token in tokens.iter_mut().filter(|t| t.proceeds.u128() > 0) {
+        messages.push(snip20_transfer_msg(
+            to_address.clone(),
+            token.proceeds,
+            token.code_hash.clone(),
+            deps.api.human_address(&token.address)?,
+        )?);
+        token.proceeds = Uint128(0);
+        snip20_withdrawn = true;
+    }
+    if snip20_withdrawn {
+        save(&mut deps.storage, PAYMENT_TOKENS_KEY, &tokens)?;
+    }
+    if messages.is_empty() {
+        return Err(StdError::generic_err("There are no proceeds to withdraw"));
+    }
     Ok(HandleResponse {
-        messages: vec![],
+        messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetGlobalApproval {
+        data: Some(to_binary(&HandleAnswer::WithdrawProceeds {
             status: Success,
         })?),
     })
@@ -1062,134 +4206,122 @@ pub fn set_global_approval<S: Storage, A: Api, Q: Querier>(
 
 /// Returns HandleResult
 ///
-/// sets specified permissions for an address
+/// lists a token for sale
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `address` - a reference to the address being granted permission
-/// * `token_id` - optional token id to apply approvals to
-/// * `view_owner` - optional access level for viewing token ownership
-/// * `view_private_metadata` - optional access level for viewing private metadata
-/// * `transfer` - optional access level for transferring tokens
-/// * `expires` - optional Expiration for this approval
-/// * `response_type` - which response to return for SetWhitelistedApproval, ApproveAll, or RevokeAll
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - id of the token being listed
+/// * `price` - sale price
+/// * `denom` - optional native coin denomination of the price
+/// * `payment_token` - optional accepted SNIP-20 payment token the price must be paid in
+/// * `expires` - optional Expiration of the listing
 #[allow(clippy::too_many_arguments)]
-pub fn set_whitelisted_approval<S: Storage, A: Api, Q: Querier>(
+pub fn list_for_sale<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    address: &HumanAddr,
-    token_id: Option<String>,
-    view_owner: Option<AccessLevel>,
-    view_private_metadata: Option<AccessLevel>,
-    transfer: Option<AccessLevel>,
+    token_id: &str,
+    price: Uint128,
+    denom: Option<String>,
+    payment_token: Option<HumanAddr>,
     expires: Option<Expiration>,
-    response_type: SetAppResp,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let token_given: bool;
-    let address_raw = deps.api.canonical_address(address)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let mut custom_err = String::new();
-    let (token, idx) = if let Some(id) = token_id {
-        token_given = true;
-        custom_err = format!("You do not own token {}", id);
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they do not own that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(&*custom_err)
-        };
-        get_token(&deps.storage, &id, opt_err)?
+    let (token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    check_not_auctioned(&deps.storage, idx, token_id)?;
+    check_not_attached(&deps.storage, idx, token_id)?;
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(
+            "The listing expiration has already passed",
+        ));
+    }
+    let payment_token = if let Some(address) = payment_token.as_ref() {
+        let token_raw = deps.api.canonical_address(address)?;
+        let tokens: Vec<StoredPaymentToken> =
+            may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+        if !tokens.iter().any(|t| t.address == token_raw) {
+            return Err(StdError::generic_err(format!(
+                "{} is not an accepted payment token",
+                address
+            )));
+        }
+        Some(token_raw)
     } else {
-        token_given = false;
-        (
-            Token {
-                owner: sender_raw.clone(),
-                permissions: Vec::new(),
-                unwrapped: false,
-                transferable: true,
-            },
-            0,
-        )
+        None
     };
-    // if trying to set token permissions when you are not the owner
-    if token_given && token.owner != sender_raw {
-        return Err(StdError::generic_err(custom_err));
+    if denom.is_some() == payment_token.is_some() {
+        return Err(StdError::generic_err(
+            "A listing must be priced in either a native denom or a payment token",
+        ));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
-    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
-    accesses[PermissionType::Transfer.to_usize()] = transfer;
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given,
-        accesses,
+    let listing = StoredListing {
+        seller: sender_raw,
+        price,
+        denom,
+        payment_token,
         expires,
-        from_oper: false,
-    };
-    process_accesses(
-        &mut deps.storage,
-        &env,
-        &address_raw,
-        &sender_raw,
-        &mut proc_info,
-        None,
-    )?;
-    let response = match response_type {
-        SetAppResp::SetWhitelistedApproval => {
-            HandleAnswer::SetWhitelistedApproval { status: Success }
-        }
-        SetAppResp::ApproveAll => HandleAnswer::ApproveAll { status: Success },
-        SetAppResp::RevokeAll => HandleAnswer::RevokeAll { status: Success },
     };
-    let res = HandleResponse {
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    save(&mut listing_store, &idx.to_le_bytes(), &listing)?;
+    Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&response)?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::ListForSale { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// burns many tokens
+/// removes a token's listing
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `burns` - the list of burns to perform
-pub fn batch_burn_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the listed token
+pub fn cancel_listing<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    burns: Vec<Burn>,
+    token_id: &str,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    burn_list(deps, &env.block, config, &sender_raw, burns)?;
-    let res = HandleResponse {
+    let (_token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    if may_load::<StoredListing, _>(&listing_store, &idx.to_le_bytes())?.is_none() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is not listed for sale",
+            token_id
+        )));
+    }
+    remove(&mut listing_store, &idx.to_le_bytes());
+    Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::BatchBurnNft { status: Success })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::CancelListing { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// burns a token
+/// buys a token listed for a price in native coins
 ///
 /// # Arguments
 ///
@@ -1197,9 +4329,9 @@ pub fn batch_burn_nft<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `token_id` - token id String of token to be burnt
-/// * `memo` - optional memo for the burn tx
-fn burn_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token being bought
+/// * `memo` - optional memo for the transfer tx
+pub fn buy<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &mut Config,
@@ -1208,55 +4340,156 @@ fn burn_nft<S: Storage, A: Api, Q: Querier>(
     memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let burns = vec![Burn {
-        token_ids: vec![token_id],
+    let buyer = env.message.sender.clone();
+    let messages = buy_listing(
+        deps,
+        &env,
+        config,
+        buyer,
+        token_id,
         memo,
-    }];
-    burn_list(deps, &env.block, config, &sender_raw, burns)?;
-    let res = HandleResponse {
-        messages: vec![],
+        SalePayment::Native,
+    )?;
+    Ok(HandleResponse {
+        messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::BurnNft { status: Success })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::Buy { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// transfer many tokens
+/// makes an offer on a token, escrowing the sent coin
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `transfers` - list of transfers to perform
-pub fn batch_transfer_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token the offer is for
+/// * `expires` - optional Expiration of the offer
+pub fn make_offer<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    transfers: Vec<Transfer>,
+    token_id: &str,
+    expires: Option<Expiration>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let _m = send_list(deps, &env, config, &sender_raw, Some(transfers), None)?;
+    let amount = match env.message.sent_funds.as_slice() {
+        [coin] if coin.amount.u128() > 0 => coin.clone(),
+        _ => {
+            return Err(StdError::generic_err(
+                "An offer must be made by sending a single native coin",
+            ))
+        }
+    };
+    let (token, idx) = get_token_for_offers(&deps.storage, config, token_id)?;
+    if token.owner == sender_raw {
+        return Err(StdError::generic_err(
+            "You can not make an offer on a token you own",
+        ));
+    }
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    if token.bridge_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked by the bridge",
+            token_id
+        )));
+    }
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(
+            "The offer expiration has already passed",
+        ));
+    }
+    let token_key = idx.to_le_bytes();
+    let offer_store = ReadonlyPrefixedStorage::new(PREFIX_OFFERS, &deps.storage);
+    let mut offers: Vec<StoredOffer> = may_load(&offer_store, &token_key)?.unwrap_or_default();
+    // refund any previous offer from the sender and any expired offers so they do not count
+    // against the cap
+    let (refunded, mut offers): (Vec<StoredOffer>, Vec<StoredOffer>) = offers
+        .drain(..)
+        .partition(|o| o.bidder == sender_raw || o.expires.is_expired(&env.block));
+    if offers.len() >= MAX_OFFERS_PER_TOKEN {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} already has the maximum of {} offers",
+            token_id, MAX_OFFERS_PER_TOKEN
+        )));
+    }
+    let messages = refund_offers(&deps.api, &env.contract.address, refunded)?;
+    offers.push(StoredOffer {
+        bidder: sender_raw,
+        amount,
+        expires,
+    });
+    let mut offer_store = PrefixedStorage::new(PREFIX_OFFERS, &mut deps.storage);
+    save(&mut offer_store, &token_key, &offers)?;
+    Ok(HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::MakeOffer { status: Success })?),
+    })
+}
 
-    let res = HandleResponse {
-        messages: vec![],
+/// Returns HandleResult
+///
+/// withdraws an offer on a token, refunding its escrowed coin
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - id of the token the offer is for
+pub fn withdraw_offer<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let (_token, idx) = get_token_for_offers(&deps.storage, config, token_id)?;
+    let token_key = idx.to_le_bytes();
+    let offer_store = ReadonlyPrefixedStorage::new(PREFIX_OFFERS, &deps.storage);
+    let mut offers: Vec<StoredOffer> = may_load(&offer_store, &token_key)?.unwrap_or_default();
+    let (withdrawn, offers): (Vec<StoredOffer>, Vec<StoredOffer>) =
+        offers.drain(..).partition(|o| o.bidder == sender_raw);
+    if withdrawn.is_empty() {
+        return Err(StdError::generic_err(format!(
+            "You do not have an offer on token {}",
+            token_id
+        )));
+    }
+    let messages = refund_offers(&deps.api, &env.contract.address, withdrawn)?;
+    let mut offer_store = PrefixedStorage::new(PREFIX_OFFERS, &mut deps.storage);
+    if offers.is_empty() {
+        remove(&mut offer_store, &token_key);
+    } else {
+        save(&mut offer_store, &token_key, &offers)?;
+    }
+    Ok(HandleResponse {
+        messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::BatchTransferNft {
-            status: Success,
-        })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::WithdrawOffer { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// transfer a token
+/// accepts an offer on a token, paying the token's royalty recipients and the owner from the
+/// escrowed coin, transferring the token to the bidder, and refunding all other offers
 ///
 /// # Arguments
 ///
@@ -1264,70 +4497,227 @@ pub fn batch_transfer_nft<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `recipient` - the address receiving the token
-/// * `token_id` - token id String of token to be transferred
-/// * `memo` - optional memo for the mint tx
-pub fn transfer_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token the offer is for
+/// * `bidder` - a reference to the address that made the offer being accepted
+/// * `memo` - optional memo for the transfer tx
+pub fn accept_offer<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    bidder: &HumanAddr,
+    memo: Option<String>,
+) -> HandleResult {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let bidder_raw = deps.api.canonical_address(bidder)?;
+    let (_token, idx) = get_owned_token(&deps.storage, config, &token_id, &sender_raw)?;
+    let token_key = idx.to_le_bytes();
+    let offer_store = ReadonlyPrefixedStorage::new(PREFIX_OFFERS, &deps.storage);
+    let mut offers: Vec<StoredOffer> = may_load(&offer_store, &token_key)?.unwrap_or_default();
+    let (mut accepted, others): (Vec<StoredOffer>, Vec<StoredOffer>) =
+        offers.drain(..).partition(|o| o.bidder == bidder_raw);
+    let offer = accepted.pop().ok_or_else(|| {
+        StdError::generic_err(format!(
+            "{} does not have an offer on token {}",
+            bidder, token_id
+        ))
+    })?;
+    if offer.expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(format!(
+            "The offer from {} has expired",
+            bidder
+        )));
+    }
+    // split the offer between the royalty recipients and the owner
+    let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+    let royalty_info: Option<StoredRoyaltyInfo> = may_load(&roy_store, &token_key)?;
+    let payouts = split_sale_price(
+        offer.amount.amount.u128(),
+        &sender_raw,
+        royalty_info.as_ref(),
+    )?;
+    let mut messages = payouts
+        .into_iter()
+        .map(|(recipient, amount)| {
+            Ok(CosmosMsg::Bank(BankMsg::Send {
+                from_address: env.contract.address.clone(),
+                to_address: deps.api.human_address(&recipient)?,
+                amount: vec![Coin {
+                    denom: offer.amount.denom.clone(),
+                    amount: Uint128(amount),
+                }],
+            }))
+        })
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+    messages.extend(refund_offers(&deps.api, &env.contract.address, others)?);
+    let transfers = Some(vec![Transfer {
+        recipient: bidder.clone(),
+        token_ids: vec![token_id],
+        memo,
+    }]);
+    send_list(deps, &env, config, &sender_raw, transfers, None)?;
+    let mut offer_store = PrefixedStorage::new(PREFIX_OFFERS, &mut deps.storage);
+    remove(&mut offer_store, &token_key);
+    Ok(HandleResponse {
+        messages,
+        log: vec![],
+        data: Some(to_binary(&HandleAnswer::AcceptOffer { status: Success })?),
+    })
+}
+
+/// Returns HandleResult
+///
+/// starts an auction of a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - Env of contract's environment
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - id of the token being auctioned
+/// * `denom` - native coin denomination of the bids
+/// * `reserve_price` - minimum bid that will be accepted
+/// * `end_time` - time the auction ends (in seconds since 01/01/1970)
+#[allow(clippy::too_many_arguments)]
+pub fn start_auction<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    recipient: HumanAddr,
-    token_id: String,
-    memo: Option<String>,
+    token_id: &str,
+    denom: String,
+    reserve_price: Uint128,
+    end_time: u64,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let transfers = Some(vec![Transfer {
-        recipient,
-        token_ids: vec![token_id],
-        memo,
-    }]);
-    let _m = send_list(deps, &env, config, &sender_raw, transfers, None)?;
-
-    let res = HandleResponse {
+    let (token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    check_not_attached(&deps.storage, idx, token_id)?;
+    if end_time <= env.block.time {
+        return Err(StdError::generic_err(
+            "The auction end time has already passed",
+        ));
+    }
+    let token_key = idx.to_le_bytes();
+    let mut auction_store = PrefixedStorage::new(PREFIX_AUCTIONS, &mut deps.storage);
+    if may_load::<StoredAuction, _>(&auction_store, &token_key)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is being auctioned",
+            token_id
+        )));
+    }
+    let auction = StoredAuction {
+        seller: sender_raw,
+        denom,
+        reserve_price,
+        end_time,
+        highest_bid: None,
+    };
+    save(&mut auction_store, &token_key, &auction)?;
+    // the token can not be bought while it is auctioned
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    remove(&mut listing_store, &token_key);
+    Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::TransferNft { status: Success })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::StartAuction { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// sends tokens to contracts, and calls those contracts' ReceiveNft.  Will error if any
-/// contract has not registered its ReceiveNft
+/// places a bid in an auction, refunding the previous highest bid
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `sends` - list of SendNfts to perform
-fn batch_send_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the auctioned token
+pub fn place_bid<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    sends: Vec<Send>,
+    token_id: &str,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let messages = send_list(deps, &env, config, &sender_raw, None, Some(sends))?;
-
-    let res = HandleResponse {
+    let (idx, mut auction) = load_auction(&deps.storage, token_id)?;
+    if auction.seller == sender_raw {
+        return Err(StdError::generic_err("You can not bid on your own auction"));
+    }
+    if env.block.time >= auction.end_time {
+        return Err(StdError::generic_err(format!(
+            "The auction of token ID: {} has ended",
+            token_id
+        )));
+    }
+    let amount = match env.message.sent_funds.as_slice() {
+        [coin] if coin.denom == auction.denom => coin.amount,
+        _ => {
+            return Err(StdError::generic_err(format!(
+                "A bid must be made by sending a single {} coin",
+                auction.denom
+            )))
+        }
+    };
+    if amount < auction.reserve_price {
+        return Err(StdError::generic_err(format!(
+            "The bid must be at least the reserve price of {}{}",
+            auction.reserve_price, auction.denom
+        )));
+    }
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    if let Some(previous) = auction.highest_bid.take() {
+        if amount <= previous.amount {
+            return Err(StdError::generic_err(format!(
+                "The bid must be higher than the current highest bid of {}{}",
+                previous.amount, auction.denom
+            )));
+        }
+        // refund the outbid bidder
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            from_address: env.contract.address.clone(),
+            to_address: deps.api.human_address(&previous.bidder)?,
+            amount: vec![Coin {
+                denom: auction.denom.clone(),
+                amount: previous.amount,
+            }],
+        }));
+    }
+    auction.highest_bid = Some(StoredBid {
+        bidder: sender_raw,
+        amount,
+    });
+    // late bids extend the auction so other bidders can respond
+    let extended_end = env.block.time.saturating_add(AUCTION_EXTENSION);
+    if extended_end > auction.end_time {
+        auction.end_time = extended_end;
+    }
+    let mut auction_store = PrefixedStorage::new(PREFIX_AUCTIONS, &mut deps.storage);
+    save(&mut auction_store, &idx.to_le_bytes(), &auction)?;
+    Ok(HandleResponse {
         messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::BatchSendNft { status: Success })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::PlaceBid { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// sends a token to a contract, and calls that contract's ReceiveNft.  Will error if the
-/// contract has not registered its ReceiveNft
+/// settles an auction that has ended, paying the token's royalty recipients and the seller
+/// from the highest bid and transferring the token to the highest bidder
 ///
 /// # Arguments
 ///
@@ -1335,46 +4725,61 @@ fn batch_send_nft<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `contract` - the address of the contract receiving the token
-/// * `receiver_info` - optional code hash and BatchReceiveNft implementation status of
-///                     the recipient contract
-/// * `token_id` - ID String of the token that was sent
-/// * `msg` - optional msg used to control ReceiveNft logic
-/// * `memo` - optional memo for the mint tx
-#[allow(clippy::too_many_arguments)]
-fn send_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the auctioned token
+/// * `memo` - optional memo for the transfer tx
+pub fn settle_auction<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &mut Config,
     priority: u8,
-    contract: HumanAddr,
-    receiver_info: Option<ReceiverInfo>,
     token_id: String,
-    msg: Option<Binary>,
     memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let sends = Some(vec![Send {
-        contract,
-        receiver_info,
-        token_ids: vec![token_id],
-        msg,
-        memo,
-    }]);
-    let messages = send_list(deps, &env, config, &sender_raw, None, sends)?;
-
-    let res = HandleResponse {
+    let (idx, auction) = load_auction(&deps.storage, &token_id)?;
+    if env.block.time < auction.end_time {
+        return Err(StdError::generic_err(format!(
+            "The auction of token ID: {} has not ended yet",
+            token_id
+        )));
+    }
+    let token_key = idx.to_le_bytes();
+    // unlock the token
+    let mut auction_store = PrefixedStorage::new(PREFIX_AUCTIONS, &mut deps.storage);
+    remove(&mut auction_store, &token_key);
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    if let Some(bid) = auction.highest_bid {
+        // split the winning bid between the royalty recipients and the seller
+        let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+        let royalty_info: Option<StoredRoyaltyInfo> = may_load(&roy_store, &token_key)?;
+        let payouts = split_sale_price(bid.amount.u128(), &auction.seller, royalty_info.as_ref())?;
+        for (recipient, amount) in payouts.into_iter() {
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                from_address: env.contract.address.clone(),
+                to_address: deps.api.human_address(&recipient)?,
+                amount: vec![Coin {
+                    denom: auction.denom.clone(),
+                    amount: Uint128(amount),
+                }],
+            }));
+        }
+        let transfers = Some(vec![Transfer {
+            recipient: deps.api.human_address(&bid.bidder)?,
+            token_ids: vec![token_id],
+            memo,
+        }]);
+        send_list(deps, &env, config, &auction.seller, transfers, None)?;
+    }
+    Ok(HandleResponse {
         messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SendNft { status: Success })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::SettleAuction { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// registers a contract's ReceiveNft
+/// cancels an auction that has not received any bids
 ///
 /// # Arguments
 ///
@@ -1382,37 +4787,40 @@ fn send_nft<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `code_hash` - code hash String of the registering contract
-/// * `impl_batch` - optionally true if the contract also implements BatchReceiveNft
-pub fn register_receive_nft<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the auctioned token
+pub fn cancel_auction<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    code_hash: String,
-    impl_batch: Option<bool>,
+    token_id: &str,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    let regrec = ReceiveRegistration {
-        code_hash,
-        impl_batch: impl_batch.unwrap_or(false),
-    };
-    let mut store = PrefixedStorage::new(PREFIX_RECEIVERS, &mut deps.storage);
-    save(&mut store, sender_raw.as_slice(), &regrec)?;
-    let res = HandleResponse {
+    let (idx, auction) = load_auction(&deps.storage, token_id)?;
+    if auction.seller != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "You are not authorized to perform this action on token {}",
+            token_id
+        )));
+    }
+    if auction.highest_bid.is_some() {
+        return Err(StdError::generic_err(
+            "An auction can not be cancelled after a bid has been placed",
+        ));
+    }
+    let mut auction_store = PrefixedStorage::new(PREFIX_AUCTIONS, &mut deps.storage);
+    remove(&mut auction_store, &idx.to_le_bytes());
+    Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::RegisterReceiveNft {
-            status: Success,
-        })?),
-    };
-    Ok(res)
+        data: Some(to_binary(&HandleAnswer::CancelAuction { status: Success })?),
+    })
 }
 
 /// Returns HandleResult
 ///
-/// creates a viewing key
+/// sets or removes the address renting a token
 ///
 /// # Arguments
 ///
@@ -1420,106 +4828,205 @@ pub fn register_receive_nft<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `entropy` - string slice of the input String to be used as entropy in randomization
-pub fn create_key<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token being rented
+/// * `user` - optional address renting the token
+/// * `expires` - optional Expiration of the rental
+pub fn set_user<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    entropy: &str,
+    token_id: &str,
+    user: Option<HumanAddr>,
+    expires: Option<Expiration>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY)?;
-    let key = ViewingKey::new(&env, &prng_seed, entropy.as_ref());
-    let message_sender = deps.api.canonical_address(&env.message.sender)?;
-    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
-    save(&mut key_store, message_sender.as_slice(), &key.to_hashed())?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let (mut token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    token.user = user
+        .map(|u| {
+            let expires = expires.unwrap_or_default();
+            if expires.is_expired(&env.block) {
+                return Err(StdError::generic_err(
+                    "The rental expiration has already passed",
+                ));
+            }
+            Ok(StoredUser {
+                address: deps.api.canonical_address(&u)?,
+                expires,
+            })
+        })
+        .transpose()?;
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &idx.to_le_bytes(), &token)?;
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::ViewingKey {
-            key: format!("{}", key),
-        })?),
+        data: Some(to_binary(&HandleAnswer::SetUser { status: Success })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// sets the viewing key to the input String
+/// burns a list of input tokens and mints a new token from them
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
+/// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `key` - String to be used as the viewing key
-pub fn set_key<S: Storage, A: Api, Q: Querier>(
+/// * `inputs` - ids of the tokens being consumed
+/// * `output` - the token being minted
+/// * `memo` - optional memo for the compose tx
+pub fn compose<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    key: String,
+    inputs: Vec<String>,
+    output: Mint,
+    memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let vk = ViewingKey(key.clone());
-    let message_sender = deps.api.canonical_address(&env.message.sender)?;
-    let mut key_store = PrefixedStorage::new(PREFIX_VIEW_KEY, &mut deps.storage);
-    save(&mut key_store, message_sender.as_slice(), &vk.to_hashed())?;
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to compose",
+        ));
+    }
+    if inputs.is_empty() {
+        return Err(StdError::generic_err(
+            "Compose requires at least one input token",
+        ));
+    }
+    let mints = vec![output];
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let recipient = mints[0]
+        .owner
+        .as_ref()
+        .map(|o| deps.api.canonical_address(o))
+        .transpose()?
+        .unwrap_or_else(|| sender_raw.clone());
+    // note the owners of the inputs before they are burned
+    let mut owners: Vec<CanonicalAddr> = Vec::new();
+    for id in inputs.iter() {
+        let custom_err = format!(
+            "You are not authorized to perform this action on token {}",
+            id
+        );
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        let (token, _idx) = get_token(&deps.storage, id, opt_err)?;
+        if !owners.contains(&token.owner) {
+            owners.push(token.owner);
+        }
+    }
+    let burns = vec![Burn {
+        token_ids: inputs.clone(),
+        memo: None,
+    }];
+    let messages = burn_list(deps, &env, config, &sender_raw, burns, &BurnType::Compose)?;
+    let minted = mint_list(deps, &env, config, &sender_raw, mints)?
+        .pop()
+        .unwrap_or_default();
+    store_compose(
+        &mut deps.storage,
+        config,
+        &env.block,
+        minted.clone(),
+        owners,
+        sender_raw,
+        recipient,
+        inputs,
+        memo,
+    )?;
+    save(&mut deps.storage, CONFIG_KEY, &config)?;
     Ok(HandleResponse {
-        messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&HandleAnswer::ViewingKey { key })?),
+        messages,
+        log: vec![log("minted", &minted)],
+        data: Some(to_binary(&HandleAnswer::Compose { token_id: minted })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// add a list of minters
+/// mints tokens derived from existing tokens and records their lineage
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
-/// * `config` - a reference to the Config
+/// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `new_minters` - list of minter addresses to add
-pub fn add_minters<S: Storage, A: Api, Q: Querier>(
+/// * `parent_token_ids` - ids of the tokens the new tokens are derived from
+/// * `mints` - the list of mints to perform
+pub fn derive<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    new_minters: &[HumanAddr],
+    parent_token_ids: Vec<String>,
+    mints: Vec<Mint>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if config.admin != sender_raw {
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    if !minters.contains(&sender_raw) {
         return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
+            "Only designated minters are allowed to mint",
         ));
     }
-    let mut minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    let old_len = minters.len();
-    for minter in new_minters {
-        let minter_raw = deps.api.canonical_address(minter)?;
-        if !minters.contains(&minter_raw) {
-            minters.push(minter_raw);
+    if parent_token_ids.is_empty() || mints.is_empty() {
+        return Err(StdError::generic_err(
+            "Derive requires at least one parent token and one token to mint",
+        ));
+    }
+    let mut parents: Vec<u32> = Vec::new();
+    for id in parent_token_ids.iter() {
+        let custom_err = format!(
+            "You are not authorized to perform this action on token {}",
+            id
+        );
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        let (_token, idx) = get_token(&deps.storage, id, opt_err)?;
+        if !parents.contains(&idx) {
+            parents.push(idx);
         }
     }
-    // only save if the list changed
-    if old_len != minters.len() {
-        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+    check_bridge_mints(&deps.storage, &sender_raw, &mints)?;
+    let first_idx = config.mint_cnt;
+    let minted = mint_list(deps, &env, config, &sender_raw, mints)?;
+    let derived: Vec<u32> = (first_idx..config.mint_cnt).collect();
+    let mut lineage_store = PrefixedStorage::new(PREFIX_LINEAGE, &mut deps.storage);
+    for idx in derived.iter() {
+        save(&mut lineage_store, &idx.to_le_bytes(), &parents)?;
+    }
+    let mut derived_store = PrefixedStorage::new(PREFIX_DERIVED, &mut deps.storage);
+    for parent in parents.iter() {
+        let parent_key = parent.to_le_bytes();
+        let mut descendants: Vec<u32> = may_load(&derived_store, &parent_key)?.unwrap_or_default();
+        descendants.extend(derived.iter());
+        save(&mut derived_store, &parent_key, &descendants)?;
     }
     Ok(HandleResponse {
         messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&HandleAnswer::AddMinters { status: Success })?),
+        log: vec![log("minted", format!("{:?}", &minted))],
+        data: Some(to_binary(&HandleAnswer::Derive { token_ids: minted })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// remove a list of minters
+/// attaches a token to a parent token with the same owner
 ///
 /// # Arguments
 ///
@@ -1527,48 +5034,69 @@ pub fn add_minters<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `no_minters` - list of minter addresses to remove
-pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token being attached
+/// * `parent_token_id` - id of the token it is being attached to
+pub fn attach_to_parent<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    no_minters: &[HumanAddr],
+    token_id: &str,
+    parent_token_id: &str,
 ) -> HandleResult {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
+    let sender_raw = deps.api.canonical_address(&env.message.sender)?;
+    let (token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    let (_parent, parent_idx) =
+        get_owned_token(&deps.storage, config, parent_token_id, &sender_raw)?;
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
     }
-    let may_minters: Option<Vec<CanonicalAddr>> = may_load(&deps.storage, MINTERS_KEY)?;
-    if let Some(mut minters) = may_minters {
-        let old_len = minters.len();
-        let no_raw: Vec<CanonicalAddr> = no_minters
-            .iter()
-            .map(|x| deps.api.canonical_address(x))
-            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-        minters.retain(|m| !no_raw.contains(m));
-        let new_len = minters.len();
-        if new_len > 0 {
-            if old_len != new_len {
-                save(&mut deps.storage, MINTERS_KEY, &minters)?;
-            }
-        } else {
-            remove(&mut deps.storage, MINTERS_KEY);
+    if token.bridge_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked by the bridge",
+            token_id
+        )));
+    }
+    check_not_auctioned(&deps.storage, idx, token_id)?;
+    check_not_auctioned(&deps.storage, parent_idx, parent_token_id)?;
+    check_not_attached(&deps.storage, idx, token_id)?;
+    // walk up from the parent to make sure the token is not one of its ancestors
+    let mut parent_store = PrefixedStorage::new(PREFIX_PARENT, &mut deps.storage);
+    let mut ancestor = Some(parent_idx);
+    while let Some(anc) = ancestor {
+        if anc == idx {
+            return Err(StdError::generic_err(format!(
+                "Attaching token {} to token {} would create a cycle",
+                token_id, parent_token_id
+            )));
         }
+        ancestor = may_load(&parent_store, &anc.to_le_bytes())?;
     }
+    save(&mut parent_store, &idx.to_le_bytes(), &parent_idx)?;
+    let mut child_store = PrefixedStorage::new(PREFIX_CHILDREN, &mut deps.storage);
+    let parent_key = parent_idx.to_le_bytes();
+    let mut children: Vec<u32> = may_load(&child_store, &parent_key)?.unwrap_or_default();
+    children.push(idx);
+    save(&mut child_store, &parent_key, &children)?;
+    // an attached token can not be sold on its own
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    remove(&mut listing_store, &idx.to_le_bytes());
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::RemoveMinters { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::AttachToParent {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// define the exact list of minters
+/// detaches a token from its parent token
 ///
 /// # Arguments
 ///
@@ -1576,48 +5104,65 @@ pub fn remove_minters<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `human_minters` - exact list of minter addresses
-pub fn set_minters<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token being detached
+pub fn detach_from_parent<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &Config,
     priority: u8,
-    human_minters: &[HumanAddr],
+    token_id: &str,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
+    let (_token, idx) = get_owned_token(&deps.storage, config, token_id, &sender_raw)?;
+    let token_key = idx.to_le_bytes();
+    let parent_store = ReadonlyPrefixedStorage::new(PREFIX_PARENT, &deps.storage);
+    let parent_idx: u32 = may_load(&parent_store, &token_key)?.ok_or_else(|| {
+        StdError::generic_err(format!(
+            "Token ID: {} is not attached to a parent token",
+            token_id
+        ))
+    })?;
+    // a listed or auctioned root is sold with everything attached to it, so nothing may be
+    // detached until the sale is cancelled or settled
+    let mut root = parent_idx;
+    while let Some(anc) = may_load::<u32, _>(&parent_store, &root.to_le_bytes())? {
+        root = anc;
     }
-    // remove duplicates from the minters list
-    let minters_raw: Vec<CanonicalAddr> = human_minters
-        .iter()
-        .map(|x| deps.api.canonical_address(x))
-        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
-    sortable.sort_unstable();
-    sortable.dedup();
-    let minters: Vec<CanonicalAddr> = sortable
-        .iter()
-        .map(|x| CanonicalAddr(Binary(x.to_vec())))
-        .collect();
-    if minters.is_empty() {
-        remove(&mut deps.storage, MINTERS_KEY);
+    let root_key = root.to_le_bytes();
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let root_id: String = load(&map2id, &root_key)?;
+    let listing_store = ReadonlyPrefixedStorage::new(PREFIX_LISTINGS, &deps.storage);
+    if may_load::<StoredListing, _>(&listing_store, &root_key)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} can not be detached while token {} is listed for sale",
+            token_id, root_id
+        )));
+    }
+    check_not_auctioned(&deps.storage, root, &root_id)?;
+    let mut parent_store = PrefixedStorage::new(PREFIX_PARENT, &mut deps.storage);
+    remove(&mut parent_store, &token_key);
+    let mut child_store = PrefixedStorage::new(PREFIX_CHILDREN, &mut deps.storage);
+    let parent_key = parent_idx.to_le_bytes();
+    let mut children: Vec<u32> = may_load(&child_store, &parent_key)?.unwrap_or_default();
+    children.retain(|c| *c != idx);
+    if children.is_empty() {
+        remove(&mut child_store, &parent_key);
     } else {
-        save(&mut deps.storage, MINTERS_KEY, &minters)?;
+        save(&mut child_store, &parent_key, &children)?;
     }
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetMinters { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::DetachFromParent {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// change the admin address
+/// moves a non-transferable token to a new owner on behalf of its issuer
 ///
 /// # Arguments
 ///
@@ -1625,90 +5170,378 @@ pub fn set_minters<S: Storage, A: Api, Q: Querier>(
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `address` - new admin address
-pub fn change_admin<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - id of the token being recovered
+/// * `new_owner` - a reference to the address of the new owner
+/// * `memo` - optional memo for the tx
+pub fn recover_non_transferable<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &mut Config,
     priority: u8,
-    address: &HumanAddr,
+    token_id: String,
+    new_owner: &HumanAddr,
+    memo: Option<String>,
 ) -> HandleResult {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
+    let recipient = deps.api.canonical_address(new_owner)?;
+    let (mut token, idx) = get_issued_token(&deps.storage, config, &token_id, &sender_raw)?;
+    if token.bridge_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked by the bridge",
+            token_id
+        )));
     }
-    let new_admin = deps.api.canonical_address(address)?;
-    if new_admin != config.admin {
-        config.admin = new_admin;
-        save(&mut deps.storage, CONFIG_KEY, &config)?;
+    check_not_auctioned(&deps.storage, idx, &token_id)?;
+    let old_owner = token.owner;
+    if old_owner == recipient {
+        return Err(StdError::generic_err(format!(
+            "Attempting to recover token ID: {} to the address that already owns it",
+            &token_id
+        )));
     }
+    token.owner = recipient.clone();
+    token.permissions.clear();
+    token.user = None;
+    let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
+    json_save(&mut info_store, &idx.to_le_bytes(), &token)?;
+    // a listing is cancelled when the token moves
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    remove(&mut listing_store, &idx.to_le_bytes());
+    let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
+    log_inventory_move(
+        &mut deps.storage,
+        &mut inv_updates,
+        idx,
+        &old_owner,
+        &recipient,
+    )?;
+    // attached tokens move with their parent
+    move_children(
+        &mut deps.storage,
+        &env.block,
+        config,
+        idx,
+        Some(&sender_raw),
+        &old_owner,
+        &recipient,
+        &mut inv_updates,
+        &memo,
+    )?;
+    store_recover(
+        &mut deps.storage,
+        config,
+        &env.block,
+        token_id,
+        old_owner,
+        sender_raw,
+        recipient,
+        memo,
+    )?;
+    save(&mut deps.storage, CONFIG_KEY, &config)?;
+    update_owner_inventory(
+        &mut deps.storage,
+        &inv_updates,
+        PermissionType::ViewOwner.num_types(),
+    )?;
     Ok(HandleResponse {
         messages: vec![],
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::ChangeAdmin { status: Success })?),
+        data: Some(to_binary(&HandleAnswer::RecoverNonTransferable {
+            status: Success,
+        })?),
     })
 }
 
 /// Returns HandleResult
 ///
-/// set the contract status level
+/// burns a non-transferable token on behalf of its issuer
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - Env of contract's environment
 /// * `config` - a mutable reference to the Config
-/// * `level` - new ContractStatus
-pub fn set_contract_status<S: Storage, A: Api, Q: Querier>(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - id of the token being revoked
+/// * `memo` - optional memo for the tx
+pub fn revoke_credential<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     config: &mut Config,
-    level: ContractStatus,
+    priority: u8,
+    token_id: String,
+    memo: Option<String>,
 ) -> HandleResult {
+    check_status(config.status, priority)?;
     let sender_raw = deps.api.canonical_address(&env.message.sender)?;
-    if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
-    }
-    let new_status = level.to_u8();
-    if config.status != new_status {
-        config.status = new_status;
-        save(&mut deps.storage, CONFIG_KEY, &config)?;
-    }
+    let burns = vec![Burn {
+        token_ids: vec![token_id],
+        memo,
+    }];
+    let messages = burn_list(deps, &env, config, &sender_raw, burns, &BurnType::Revoke)?;
     Ok(HandleResponse {
-        messages: vec![],
+        messages,
         log: vec![],
-        data: Some(to_binary(&HandleAnswer::SetContractStatus {
+        data: Some(to_binary(&HandleAnswer::RevokeCredential {
             status: Success,
         })?),
     })
 }
 
-/// Returns HandleResult
+/// Returns StdResult<(Token, u32)>
 ///
-/// revoke the ability to use a specified permit
+/// returns the specified non-transferable token and its identifier index if the sender is
+/// its issuer
 ///
 /// # Arguments
 ///
-/// * `storage` - mutable reference to the contract's storage
-/// * `sender` - a reference to the message sender
-/// * `permit_name` - string slice of the name of the permit to revoke
-fn revoke_permit<S: Storage>(
-    storage: &mut S,
-    sender: &HumanAddr,
-    permit_name: &str,
-) -> HandleResult {
-    RevokedPermits::revoke_permit(storage, PREFIX_REVOKED_PERMITS, sender, permit_name);
+/// * `storage` - a reference to contract's storage
+/// * `config` - a reference to the Config
+/// * `token_id` - token id string slice
+/// * `sender` - a reference to the message sender address
+fn get_issued_token<S: ReadonlyStorage>(
+    storage: &S,
+    config: &Config,
+    token_id: &str,
+    sender: &CanonicalAddr,
+) -> StdResult<(Token, u32)> {
+    let custom_err = format!("You are not the issuer of token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(storage, token_id, opt_err)?;
+    let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, storage);
+    let mint_run: StoredMintRunInfo = load(&run_store, &idx.to_le_bytes())?;
+    if token.transferable || mint_run.issuer.as_ref() != Some(sender) {
+        return Err(StdError::generic_err(custom_err));
+    }
+    Ok((token, idx))
+}
 
-    Ok(HandleResponse {
-        messages: vec![],
-        log: vec![],
-        data: Some(to_binary(&HandleAnswer::RevokePermit { status: Success })?),
-    })
+/// Returns StdResult<(u32, StoredAuction)>
+///
+/// returns the identifier index of an auctioned token and its auction
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `token_id` - token id string slice
+fn load_auction<S: ReadonlyStorage>(
+    storage: &S,
+    token_id: &str,
+) -> StdResult<(u32, StoredAuction)> {
+    let not_auctioned = format!("Token ID: {} is not being auctioned", token_id);
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
+    let idx: u32 = may_load(&map2idx, token_id.as_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_auctioned.clone()))?;
+    let auction_store = ReadonlyPrefixedStorage::new(PREFIX_AUCTIONS, storage);
+    let auction: StoredAuction = may_load(&auction_store, &idx.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_auctioned))?;
+    Ok((idx, auction))
+}
+
+/// Returns StdResult<Vec<CosmosMsg>>
+///
+/// returns the messages refunding the escrowed coins of a list of offers
+///
+/// # Arguments
+///
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+/// * `contract` - a reference to this contract's address
+/// * `offers` - the offers being refunded
+fn refund_offers<A: Api>(
+    api: &A,
+    contract: &HumanAddr,
+    offers: Vec<StoredOffer>,
+) -> StdResult<Vec<CosmosMsg>> {
+    offers
+        .into_iter()
+        .map(|o| {
+            Ok(CosmosMsg::Bank(BankMsg::Send {
+                from_address: contract.clone(),
+                to_address: api.human_address(&o.bidder)?,
+                amount: vec![o.amount],
+            }))
+        })
+        .collect()
+}
+
+/// Returns StdResult<(Token, u32)>
+///
+/// returns the specified token and its identifier index, without leaking that the token does
+/// not exist if the token supply is private
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `config` - a reference to the Config
+/// * `token_id` - token id string slice
+fn get_token_for_offers<S: ReadonlyStorage>(
+    storage: &S,
+    config: &Config,
+    token_id: &str,
+) -> StdResult<(Token, u32)> {
+    let custom_err = format!("Unable to make offers on token {}", token_id);
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    get_token(storage, token_id, opt_err)
+}
+
+/// Returns StdResult<(Token, u32)>
+///
+/// returns the specified token and its identifier index if it is owned by the sender
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `config` - a reference to the Config
+/// * `token_id` - token id string slice
+/// * `sender` - a reference to the message sender address
+fn get_owned_token<S: ReadonlyStorage>(
+    storage: &S,
+    config: &Config,
+    token_id: &str,
+    sender: &CanonicalAddr,
+) -> StdResult<(Token, u32)> {
+    let custom_err = format!(
+        "You are not authorized to perform this action on token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(storage, token_id, opt_err)?;
+    if token.owner != *sender {
+        return Err(StdError::generic_err(custom_err));
+    }
+    Ok((token, idx))
+}
+
+/// Returns StdResult<Vec<CosmosMsg>>
+///
+/// verifies the buyer paid a listed token's price, transfers the token to the buyer, and
+/// returns the messages paying the token's royalty recipients and the seller
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `buyer` - address receiving the token
+/// * `token_id` - id of the token being bought
+/// * `memo` - optional memo for the transfer tx
+/// * `payment` - how the purchase was paid
+fn buy_listing<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+    config: &mut Config,
+    buyer: HumanAddr,
+    token_id: String,
+    memo: Option<String>,
+    payment: SalePayment,
+) -> StdResult<Vec<CosmosMsg>> {
+    let not_listed = format!("Token ID: {} is not listed for sale", token_id);
+    let (token, idx) = get_token(&deps.storage, &token_id, Some(&not_listed))?;
+    let token_key = idx.to_le_bytes();
+    let listing_store = ReadonlyPrefixedStorage::new(PREFIX_LISTINGS, &deps.storage);
+    let listing: StoredListing = may_load(&listing_store, &token_key)?
+        .ok_or_else(|| StdError::generic_err(not_listed.clone()))?;
+    // a listing is no longer valid once its seller no longer owns the token
+    if listing.seller != token.owner {
+        return Err(StdError::generic_err(not_listed));
+    }
+    if listing.expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(format!(
+            "The listing of token ID: {} has expired",
+            token_id
+        )));
+    }
+    let price = listing.price.u128();
+    let mut snip20_hash: Option<String> = None;
+    match &payment {
+        SalePayment::Native => {
+            let denom = listing.denom.as_ref().ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Token ID: {} must be bought with a SNIP-20 token",
+                    token_id
+                ))
+            })?;
+            let paid_exact = match env.message.sent_funds.as_slice() {
+                [] => price == 0,
+                [coin] => coin.denom == *denom && coin.amount.u128() == price,
+                _ => false,
+            };
+            if !paid_exact {
+                return Err(StdError::generic_err(format!(
+                    "The listing price of {}{} must be sent with the purchase",
+                    price, denom
+                )));
+            }
+        }
+        SalePayment::Snip20 { token, amount } => {
+            if listing.payment_token.as_ref() != Some(token) {
+                return Err(StdError::generic_err(format!(
+                    "Token ID: {} is not priced in this SNIP-20 token",
+                    token_id
+                )));
+            }
+            if amount.u128() != price {
+                return Err(StdError::generic_err(format!(
+                    "The listing price of {} tokens must be sent with the purchase",
+                    price
+                )));
+            }
+            let tokens: Vec<StoredPaymentToken> =
+                may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+            snip20_hash = tokens
+                .into_iter()
+                .find(|t| t.address == *token)
+                .map(|t| t.code_hash);
+        }
+    }
+    // split the price between the royalty recipients and the seller
+    let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+    let royalty_info: Option<StoredRoyaltyInfo> = may_load(&roy_store, &token_key)?;
+    let payouts = split_sale_price(price, &listing.seller, royalty_info.as_ref())?;
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    for (recipient, amount) in payouts.into_iter() {
+        let to_address = deps.api.human_address(&recipient)?;
+        let msg = match &payment {
+            SalePayment::Native => CosmosMsg::Bank(BankMsg::Send {
+                from_address: env.contract.address.clone(),
+                to_address,
+                amount: vec![Coin {
+                    denom: listing.denom.clone().unwrap_or_default(),
+                    amount: Uint128(amount),
+                }],
+            }),
+            SalePayment::Snip20 { token, .. } => snip20_transfer_msg(
+                to_address,
+                Uint128(amount),
+                snip20_hash.clone().unwrap_or_default(),
+                deps.api.human_address(token)?,
+            )?,
+        };
+        messages.push(msg);
+    }
+    let transfers = Some(vec![Transfer {
+        recipient: buyer,
+        token_ids: vec![token_id],
+        memo,
+    }]);
+    // the transfer also removes the listing
+    send_list(deps, env, config, &listing.seller, transfers, None)?;
+    Ok(messages)
 }
 
 /////////////////////////////////////// Query /////////////////////////////////////
@@ -1720,14 +5553,29 @@ fn revoke_permit<S: Storage>(
 /// * `msg` - QueryMsg passed in with the query call
 pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryMsg) -> QueryResult {
     let response = match msg {
-        QueryMsg::ContractInfo {} => query_contract_info(&deps.storage),
+        QueryMsg::ContractInfo {} => query_contract_info(deps),
+        QueryMsg::CollectionInfo {} => query_collection_info(deps),
+        QueryMsg::TraitSchema {} => query_trait_schema(&deps.storage),
         QueryMsg::ContractCreator {} => query_contract_creator(deps),
+        QueryMsg::SaleInfo {} => query_sale_info(deps),
+        QueryMsg::PresaleAllocation {
+            address,
+            viewing_key,
+        } => {
+            let viewer = Some(ViewerInfo {
+                address,
+                viewing_key,
+            });
+            query_presale_allocation(deps, viewer, None)
+        }
         QueryMsg::RoyaltyInfo { token_id, viewer } => {
             query_royalty(deps, token_id.as_deref(), viewer, None)
         }
         QueryMsg::ContractConfig {} => query_config(&deps.storage),
+        QueryMsg::MetadataCommitment {} => query_metadata_commitment(&deps.storage),
         QueryMsg::Minters {} => query_minters(deps),
         QueryMsg::NumTokens { viewer } => query_num_tokens(deps, viewer, None),
+        QueryMsg::SupplyInfo { viewer } => query_supply_info(deps, viewer, None),
         QueryMsg::AllTokens {
             viewer,
             start_after,
@@ -1822,6 +5670,56 @@ pub fn query<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>, msg: QueryM
             query_transactions(deps, viewer, page, page_size, None)
         }
         QueryMsg::RegisteredCodeHash { contract } => query_code_hash(deps, &contract),
+        QueryMsg::TokenByOrigin {
+            chain_nonce,
+            contract_address,
+            token_id,
+            viewer,
+        } => query_token_by_origin(
+            deps,
+            chain_nonce,
+            &contract_address,
+            &token_id,
+            viewer,
+            None,
+        ),
+        QueryMsg::IsActionConsumed { kind, action_id } => {
+            query_action_consumed(&deps.storage, kind, action_id)
+        }
+        QueryMsg::Validators {} => query_validators(deps),
+        QueryMsg::MintProposals {
+            viewer,
+            start_after,
+            limit,
+        } => query_mint_proposals(deps, viewer, start_after, limit, None),
+        QueryMsg::BridgeBurns { start_after, limit } => {
+            query_bridge_burns(&deps.storage, start_after, limit)
+        }
+        QueryMsg::Listings {
+            viewer,
+            start_after,
+            limit,
+        } => query_listings(deps, viewer, start_after, limit, None),
+        QueryMsg::ListingOf { token_id, viewer } => query_listing_of(deps, &token_id, viewer, None),
+        QueryMsg::Offers { token_id, viewer } => query_offers(deps, &token_id, Some(viewer), None),
+        QueryMsg::Auctions {
+            viewer,
+            start_after,
+            limit,
+        } => query_auctions(deps, viewer, start_after, limit, None),
+        QueryMsg::AuctionOf { token_id, viewer } => query_auction_of(deps, &token_id, viewer, None),
+        QueryMsg::UserOf { token_id, viewer } => query_user_of(deps, &token_id, viewer, None),
+        QueryMsg::TokenLineage {
+            token_id,
+            depth,
+            viewer,
+        } => query_token_lineage(deps, &token_id, depth, viewer, None),
+        QueryMsg::MetadataHistory {
+            token_id,
+            viewer,
+            page,
+            page_size,
+        } => query_metadata_history(deps, &token_id, viewer, page, page_size, None),
         QueryMsg::WithPermit { permit, query } => permit_queries(deps, permit, query),
     };
     pad_query_result(response, BLOCK_SIZE)
@@ -1891,6 +5789,47 @@ pub fn permit_queries<S: Storage, A: Api, Q: Querier>(
             query_transactions(deps, None, page, page_size, Some(querier))
         }
         QueryWithPermit::NumTokens {} => query_num_tokens(deps, None, Some(querier)),
+        QueryWithPermit::SupplyInfo {} => query_supply_info(deps, None, Some(querier)),
+        QueryWithPermit::PresaleAllocation {} => {
+            query_presale_allocation(deps, None, Some(querier))
+        }
+        QueryWithPermit::Offers { token_id } => query_offers(deps, &token_id, None, Some(querier)),
+        QueryWithPermit::UserOf { token_id } => query_user_of(deps, &token_id, None, Some(querier)),
+        QueryWithPermit::TokenLineage { token_id, depth } => {
+            query_token_lineage(deps, &token_id, depth, None, Some(querier))
+        }
+        QueryWithPermit::MetadataHistory {
+            token_id,
+            page,
+            page_size,
+        } => query_metadata_history(deps, &token_id, None, page, page_size, Some(querier)),
+        QueryWithPermit::MintProposals { start_after, limit } => {
+            query_mint_proposals(deps, None, start_after, limit, Some(querier))
+        }
+        QueryWithPermit::TokenByOrigin {
+            chain_nonce,
+            contract_address,
+            token_id,
+        } => query_token_by_origin(
+            deps,
+            chain_nonce,
+            &contract_address,
+            &token_id,
+            None,
+            Some(querier),
+        ),
+        QueryWithPermit::Listings { start_after, limit } => {
+            query_listings(deps, None, start_after, limit, Some(querier))
+        }
+        QueryWithPermit::ListingOf { token_id } => {
+            query_listing_of(deps, &token_id, None, Some(querier))
+        }
+        QueryWithPermit::Auctions { start_after, limit } => {
+            query_auctions(deps, None, start_after, limit, Some(querier))
+        }
+        QueryWithPermit::AuctionOf { token_id } => {
+            query_auction_of(deps, &token_id, None, Some(querier))
+        }
         QueryWithPermit::AllTokens { start_after, limit } => {
             query_all_tokens(deps, None, start_after, limit, Some(querier))
         }
@@ -1912,1132 +5851,1922 @@ pub fn permit_queries<S: Storage, A: Api, Q: Querier>(
     }
 }
 
-/// Returns QueryResult displaying the contract's creator
+/// Returns QueryResult displaying the contract's creator
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_contract_creator<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> QueryResult {
+    let creator_raw: CanonicalAddr = load(&deps.storage, CREATOR_KEY)?;
+    to_binary(&QueryAnswer::ContractCreator {
+        creator: Some(deps.api.human_address(&creator_raw)?),
+    })
+}
+
+/// Returns QueryResult displaying the contract's name, symbol and collection-level metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_contract_info<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) -> QueryResult {
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+
+    to_binary(&QueryAnswer::ContractInfo {
+        name: config.name,
+        symbol: config.symbol,
+        collection_info: get_collection_info(deps)?,
+    })
+}
+
+/// Returns QueryResult displaying the collection-level metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_collection_info<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> QueryResult {
+    to_binary(&QueryAnswer::CollectionInfo {
+        collection_info: get_collection_info(deps)?,
+    })
+}
+
+/// Returns QueryResult displaying the schema that metadata attributes must follow
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_trait_schema<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+    to_binary(&QueryAnswer::TraitSchema {
+        trait_schema: may_load(storage, TRAIT_SCHEMA_KEY)?,
+    })
+}
+
+/// Returns StdResult<Option<CollectionInfo>> of the collection-level metadata, if set
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+fn get_collection_info<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> StdResult<Option<CollectionInfo>> {
+    may_load::<StoredCollectionInfo, _>(&deps.storage, COLLECTION_INFO_KEY)?
+        .map(|c| c.to_human(&deps.api))
+        .transpose()
+}
+
+/// Returns QueryResult displaying either a token's royalty information or the contract's
+/// default royalty information if no token_id is specified
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - optional token id whose RoyaltyInfo is being requested
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_royalty<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: Option<&str>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let viewer_raw = get_querier(deps, viewer, from_permit)?;
+    let (royalty, hide_addr) = if let Some(id) = token_id {
+        // TODO remove this when BlockInfo becomes available to queries
+        let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "not used".to_string(),
+        });
+        // if the token id was found
+        if let Ok((token, idx)) = get_token(&deps.storage, id, None) {
+            let hide_addr = check_perm_core(
+                deps,
+                &block,
+                &token,
+                id,
+                viewer_raw.as_ref(),
+                token.owner.as_slice(),
+                PermissionType::Transfer.to_usize(),
+                &mut Vec::new(),
+                "",
+            )
+            .is_err();
+            // get the royalty information if present
+            let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
+            (
+                may_load::<StoredRoyaltyInfo, _>(&roy_store, &idx.to_le_bytes())?,
+                hide_addr,
+            )
+        // token id not found
+        } else {
+            let config: Config = load(&deps.storage, CONFIG_KEY)?;
+            let minters: Vec<CanonicalAddr> =
+                may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+            let is_minter = viewer_raw.map(|v| minters.contains(&v)).unwrap_or(false);
+            // if minter querying or the token supply is public, let them know the token does not exist
+            if config.token_supply_is_public || is_minter {
+                return Err(StdError::generic_err(format!("Token ID: {} not found", id)));
+            }
+            // token supply is private and querier is not a minter so just show the default without addresses
+            (
+                may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY)?,
+                true,
+            )
+        }
+    // no id specified, so get the default
+    } else {
+        let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+        // only let minters view default royalty addresses
+        (
+            may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY)?,
+            viewer_raw.map(|v| !minters.contains(&v)).unwrap_or(true),
+        )
+    };
+    to_binary(&QueryAnswer::RoyaltyInfo {
+        royalty_info: royalty
+            .map(|s| s.to_human(&deps.api, hide_addr))
+            .transpose()?,
+    })
+}
+
+/// Returns QueryResult displaying the contract's configuration
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_config<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+
+    to_binary(&QueryAnswer::ContractConfig {
+        token_supply_is_public: config.token_supply_is_public,
+        owner_is_public: config.owner_is_public,
+        sealed_metadata_is_enabled: config.sealed_metadata_is_enabled,
+        unwrapped_metadata_is_private: config.unwrap_to_private,
+        minter_may_update_metadata: config.minter_may_update_metadata,
+        owner_may_update_metadata: config.owner_may_update_metadata,
+        burn_is_enabled: config.burn_is_enabled,
+        metadata_is_frozen: config.metadata_frozen,
+    })
+}
+
+/// Returns QueryResult displaying the commitment to blind mint metadata
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_metadata_commitment<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+    let may_prov: Option<StoredProvenance> = may_load(storage, PROVENANCE_KEY)?;
+    let answer = if let Some(provenance) = may_prov {
+        QueryAnswer::MetadataCommitment {
+            commitment: Some(Binary(provenance.commitment)),
+            count: provenance.count,
+            offset: provenance.offset,
+            revealed: provenance.revealed,
+        }
+    } else {
+        QueryAnswer::MetadataCommitment {
+            commitment: None,
+            count: 0,
+            offset: None,
+            revealed: 0,
+        }
+    };
+    to_binary(&answer)
+}
+
+/// Returns QueryResult displaying the list of authorized minters
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_minters<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) -> QueryResult {
+    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+
+    to_binary(&QueryAnswer::Minters {
+        minters: minters
+            .iter()
+            .map(|m| deps.api.human_address(m))
+            .collect::<StdResult<Vec<HumanAddr>>>()?,
+    })
+}
+
+/// Returns QueryResult displaying the number of tokens the contract controls
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_num_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    to_binary(&QueryAnswer::NumTokens {
+        count: config.token_cnt,
+    })
+}
+
+/// Returns QueryResult displaying the supply cap and the number of tokens minted and burned
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_supply_info<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let counted = if config.max_supply_includes_burned {
+        config.mint_cnt
+    } else {
+        config.token_cnt
+    };
+    to_binary(&QueryAnswer::SupplyInfo {
+        max_supply: config.max_supply,
+        max_supply_includes_burned: config.max_supply_includes_burned,
+        minted: config.mint_cnt,
+        burned: config.mint_cnt - config.token_cnt,
+        remaining: config.max_supply.map(|max| max.saturating_sub(counted)),
+    })
+}
+
+/// Returns QueryResult displaying the list of tokens that the contract controls
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display token ids that come after this one
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_all_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let mut i = start_after.map_or_else(
+        || Ok(0),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            let idx: u32 = may_load(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
+            idx.checked_add(1).ok_or_else(|| {
+                StdError::generic_err("This token was the last one the contract could mint")
+            })
+        },
+    )?;
+    let cut_off = limit.unwrap_or(300);
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let mut tokens = Vec::new();
+    let mut count = 0u32;
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    while count < cut_off && i < config.mint_cnt {
+        if let Some(id) = may_load::<String, _>(&map2id, &i.to_le_bytes())? {
+            tokens.push(id);
+            // will hit gas ceiling before the count overflows
+            count += 1;
+        }
+        // i can't overflow if it was less than a u32
+        i += 1;
+    }
+    to_binary(&QueryAnswer::TokenList { tokens })
+}
+
+/// Returns QueryResult displaying the owner of the input token if the requester is authorized
+/// to view it and the transfer approvals on this token if the owner is querying
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-pub fn query_contract_creator<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_owner_of<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    include_expired: Option<bool>,
+    from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let creator_raw: CanonicalAddr = load(&deps.storage, CREATOR_KEY)?;
-    to_binary(&QueryAnswer::ContractCreator {
-        creator: Some(deps.api.human_address(&creator_raw)?),
-    })
+    let (may_owner, approvals, _idx) =
+        process_cw721_owner_of(deps, token_id, viewer, include_expired, from_permit)?;
+    if let Some(owner) = may_owner {
+        return to_binary(&QueryAnswer::OwnerOf { owner, approvals });
+    }
+    Err(StdError::generic_err(format!(
+        "You are not authorized to view the owner of token {}",
+        token_id
+    )))
 }
 
-/// Returns QueryResult displaying the contract's name and symbol
+/// Returns QueryResult displaying the public metadata of a token
 ///
 /// # Arguments
 ///
 /// * `storage` - a reference to the contract's storage
-pub fn query_contract_info<S: ReadonlyStorage>(storage: &S) -> QueryResult {
+/// * `token_id` - string slice of the token id
+pub fn query_nft_info<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
+    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
+    // if token id was found
+    if let Some(idx) = may_idx {
+        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
+        let meta: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or(Metadata {
+            token_uri: None,
+            extension: None,
+        });
+        return to_binary(&QueryAnswer::NftInfo {
+            token_uri: meta.token_uri,
+            extension: meta.extension,
+        });
+    }
     let config: Config = load(storage, CONFIG_KEY)?;
+    // token id wasn't found
+    // if the token supply is public, let them know the token does not exist
+    if config.token_supply_is_public {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} not found",
+            token_id
+        )));
+    }
+    // otherwise, just return empty metadata
+    to_binary(&QueryAnswer::NftInfo {
+        token_uri: None,
+        extension: None,
+    })
+}
 
-    to_binary(&QueryAnswer::ContractInfo {
-        name: config.name,
-        symbol: config.symbol,
+/// Returns QueryResult displaying the private metadata of a token if permitted to
+/// view it
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_private_meta<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    check_perm_core(
+        deps,
+        &prep_info.block,
+        &prep_info.token,
+        token_id,
+        prep_info.viewer_raw.as_ref(),
+        prep_info.token.owner.as_slice(),
+        PermissionType::ViewMetadata.to_usize(),
+        &mut Vec::new(),
+        &prep_info.err_msg,
+    )?;
+    // don't display if private metadata is sealed
+    if !prep_info.token.unwrapped {
+        return Err(StdError::generic_err(
+            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
+        ));
+    }
+    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+    let meta: Metadata = may_load(&meta_store, &prep_info.idx.to_le_bytes())?.unwrap_or(Metadata {
+        token_uri: None,
+        extension: None,
+    });
+    to_binary(&QueryAnswer::PrivateMetadata {
+        token_uri: meta.token_uri,
+        extension: meta.extension,
     })
 }
 
-/// Returns QueryResult displaying either a token's royalty information or the contract's
-/// default royalty information if no token_id is specified
+/// Returns QueryResult displaying the prior metadata versions of a token.  Prior private
+/// metadata is only displayed if the querier may view the token's private metadata
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - optional token id whose RoyaltyInfo is being requested
+/// * `token_id` - string slice of the token id
 /// * `viewer` - optional address and key making an authenticated query request
+/// * `page` - optional page to display
+/// * `page_size` - optional number of versions per page
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_royalty<S: Storage, A: Api, Q: Querier>(
+pub fn query_metadata_history<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    token_id: Option<&str>,
+    token_id: &str,
     viewer: Option<ViewerInfo>,
+    page: Option<u32>,
+    page_size: Option<u32>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let viewer_raw = get_querier(deps, viewer, from_permit)?;
-    let (royalty, hide_addr) = if let Some(id) = token_id {
-        // TODO remove this when BlockInfo becomes available to queries
-        let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "not used".to_string(),
-        });
-        // if the token id was found
-        if let Ok((token, idx)) = get_token(&deps.storage, id, None) {
-            let hide_addr = check_perm_core(
-                deps,
-                &block,
-                &token,
-                id,
-                viewer_raw.as_ref(),
-                token.owner.as_slice(),
-                PermissionType::Transfer.to_usize(),
-                &mut Vec::new(),
-                "",
-            )
-            .is_err();
-            // get the royalty information if present
-            let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
-            (
-                may_load::<StoredRoyaltyInfo, _>(&roy_store, &idx.to_le_bytes())?,
-                hide_addr,
-            )
-        // token id not found
-        } else {
-            let config: Config = load(&deps.storage, CONFIG_KEY)?;
-            let minters: Vec<CanonicalAddr> =
-                may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-            let is_minter = viewer_raw.map(|v| minters.contains(&v)).unwrap_or(false);
-            // if minter querying or the token supply is public, let them know the token does not exist
-            if config.token_supply_is_public || is_minter {
-                return Err(StdError::generic_err(format!("Token ID: {} not found", id)));
-            }
-            // token supply is private and querier is not a minter so just show the default without addresses
-            (
-                may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY)?,
-                true,
-            )
-        }
-    // no id specified, so get the default
-    } else {
-        let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        // only let minters view default royalty addresses
-        (
-            may_load::<StoredRoyaltyInfo, _>(&deps.storage, DEFAULT_ROYALTY_KEY)?,
-            viewer_raw.map(|v| !minters.contains(&v)).unwrap_or(true),
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    // apply the same rules as the PrivateMetadata query
+    let may_view_private = prep_info.token.unwrapped
+        && check_perm_core(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            prep_info.viewer_raw.as_ref(),
+            prep_info.token.owner.as_slice(),
+            PermissionType::ViewMetadata.to_usize(),
+            &mut Vec::new(),
+            &prep_info.err_msg,
         )
-    };
-    to_binary(&QueryAnswer::RoyaltyInfo {
-        royalty_info: royalty
-            .map(|s| s.to_human(&deps.api, hide_addr))
-            .transpose()?,
-    })
+        .is_ok();
+    let hist_store = ReadonlyPrefixedStorage::new(PREFIX_META_HISTORY, &deps.storage);
+    let history: Vec<StoredMetadataVersion> =
+        may_load(&hist_store, &prep_info.idx.to_le_bytes())?.unwrap_or_default();
+    let visible: Vec<StoredMetadataVersion> = history
+        .into_iter()
+        .rev()
+        .filter_map(|mut v| {
+            if !may_view_private {
+                v.private_metadata = None;
+                v.public_metadata.as_ref()?;
+            }
+            Some(v)
+        })
+        .collect();
+    let page = page.unwrap_or(0);
+    let page_size = page_size.unwrap_or(30);
+    let total = visible.len() as u64;
+    let versions = visible
+        .into_iter()
+        .skip((page * page_size) as usize)
+        .take(page_size as usize)
+        .map(|v| v.into_human(&deps.api, !may_view_private))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&QueryAnswer::MetadataHistory { total, versions })
+}
+
+/// Returns QueryResult displaying response of both the OwnerOf and NftInfo queries
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_all_nft_info<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    include_expired: Option<bool>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let (owner, approvals, idx) =
+        process_cw721_owner_of(deps, token_id, viewer, include_expired, from_permit)?;
+    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+    let info: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
+    let access = Cw721OwnerOfResponse { owner, approvals };
+    to_binary(&QueryAnswer::AllNftInfo { access, info })
 }
 
-/// Returns QueryResult displaying the contract's configuration
+/// Returns QueryResult displaying all the token information the querier is permitted to
+/// view.  This may include the owner, the public metadata, the private metadata, royalty
+/// information, mint run information, whether the token is unwrapped, whether the token is
+/// transferable, and the token and inventory approvals
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-pub fn query_config<S: ReadonlyStorage>(storage: &S) -> QueryResult {
-    let config: Config = load(storage, CONFIG_KEY)?;
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: String,
+    viewer: Option<ViewerInfo>,
+    include_expired: Option<bool>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let dossier = dossier_list(deps, vec![token_id], viewer, include_expired, from_permit)?
+        .pop()
+        .ok_or_else(|| {
+            StdError::generic_err("NftDossier can never return an empty dossier list")
+        })?;
 
-    to_binary(&QueryAnswer::ContractConfig {
-        token_supply_is_public: config.token_supply_is_public,
-        owner_is_public: config.owner_is_public,
-        sealed_metadata_is_enabled: config.sealed_metadata_is_enabled,
-        unwrapped_metadata_is_private: config.unwrap_to_private,
-        minter_may_update_metadata: config.minter_may_update_metadata,
-        owner_may_update_metadata: config.owner_may_update_metadata,
-        burn_is_enabled: config.burn_is_enabled,
+    to_binary(&QueryAnswer::NftDossier {
+        owner: dossier.owner,
+        public_metadata: dossier.public_metadata,
+        private_metadata: dossier.private_metadata,
+        royalty_info: dossier.royalty_info,
+        mint_run_info: dossier.mint_run_info,
+        transferable: dossier.transferable,
+        unwrapped: dossier.unwrapped,
+        bridge_locked: dossier.bridge_locked,
+        metadata_frozen: dossier.metadata_frozen,
+        user: dossier.user,
+        parent: dossier.parent,
+        children: dossier.children,
+        display_private_metadata_error: dossier.display_private_metadata_error,
+        owner_is_public: dossier.owner_is_public,
+        public_ownership_expiration: dossier.public_ownership_expiration,
+        private_metadata_is_public: dossier.private_metadata_is_public,
+        private_metadata_is_public_expiration: dossier.private_metadata_is_public_expiration,
+        token_approvals: dossier.token_approvals,
+        inventory_approvals: dossier.inventory_approvals,
     })
 }
 
-/// Returns QueryResult displaying the list of authorized minters
+/// Returns QueryResult displaying all the token information the querier is permitted to
+/// view of multiple tokens.  This may include the owner, the public metadata, the private metadata,
+/// royalty information, mint run information, whether the token is unwrapped, whether the token is
+/// transferable, and the token and inventory approvals
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-pub fn query_minters<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) -> QueryResult {
-    let minters: Vec<CanonicalAddr> = may_load(&deps.storage, MINTERS_KEY)?.unwrap_or_default();
+/// * `token_ids` - list of token ids whose info should be retrieved
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_batch_nft_dossier<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_ids: Vec<String>,
+    viewer: Option<ViewerInfo>,
+    include_expired: Option<bool>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let nft_dossiers = dossier_list(deps, token_ids, viewer, include_expired, from_permit)?;
 
-    to_binary(&QueryAnswer::Minters {
-        minters: minters
-            .iter()
-            .map(|m| deps.api.human_address(m))
-            .collect::<StdResult<Vec<HumanAddr>>>()?,
-    })
+    to_binary(&QueryAnswer::BatchNftDossier { nft_dossiers })
 }
 
-/// Returns QueryResult displaying the number of tokens the contract controls
+/// Returns QueryResult displaying the approvals in place for a specified token
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `viewer` - optional address and key making an authenticated query request
+/// * `token_id` - string slice of the token id
+/// * `viewing_key` - the optional token owner's viewing key String
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_num_tokens<S: Storage, A: Api, Q: Querier>(
+pub fn query_token_approvals<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    viewer: Option<ViewerInfo>,
+    token_id: &str,
+    viewing_key: Option<String>,
+    include_expired: Option<bool>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    // authenticate permission to view token supply
-    check_view_supply(deps, viewer, from_permit)?;
     let config: Config = load(&deps.storage, CONFIG_KEY)?;
-    to_binary(&QueryAnswer::NumTokens {
-        count: config.token_cnt,
+    let custom_err = format!(
+        "You are not authorized to view approvals for token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (mut token, _idx) = get_token(&deps.storage, token_id, opt_err)?;
+    // verify that the querier is the token owner
+    if let Some(pmt) = from_permit {
+        if pmt != token.owner {
+            return Err(StdError::generic_err(custom_err));
+        }
+    } else {
+        let key = viewing_key.ok_or_else(|| {
+            StdError::generic_err("This is being called incorrectly if there is no viewing key")
+        })?;
+        check_key(&deps.storage, &token.owner, key)?;
+    }
+    let owner_slice = token.owner.as_slice();
+    let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+    let global_pass: bool =
+        may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let perm_type_info = PermissionTypeInfo {
+        view_owner_idx: PermissionType::ViewOwner.to_usize(),
+        view_meta_idx: PermissionType::ViewMetadata.to_usize(),
+        transfer_idx: PermissionType::Transfer.to_usize(),
+        num_types: PermissionType::Transfer.num_types(),
+    };
+    let incl_exp = include_expired.unwrap_or(false);
+    let (token_approvals, token_owner_exp, token_meta_exp) = gen_snip721_approvals(
+        &deps.api,
+        &block,
+        &mut token.permissions,
+        incl_exp,
+        &perm_type_info,
+    )?;
+    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let mut all_perm: Vec<Permission> = json_may_load(&all_store, owner_slice)?.unwrap_or_default();
+    let (_inventory_approv, all_owner_exp, all_meta_exp) =
+        gen_snip721_approvals(&deps.api, &block, &mut all_perm, incl_exp, &perm_type_info)?;
+    // determine if ownership is public
+    let (public_ownership_expiration, owner_is_public) = if global_pass {
+        (Some(Expiration::Never), true)
+    } else if token_owner_exp.is_some() {
+        (token_owner_exp, true)
+    } else {
+        (all_owner_exp, all_owner_exp.is_some())
+    };
+    // determine if private metadata is public
+    let (private_metadata_is_public_expiration, private_metadata_is_public) =
+        if token_meta_exp.is_some() {
+            (token_meta_exp, true)
+        } else {
+            (all_meta_exp, all_meta_exp.is_some())
+        };
+    to_binary(&QueryAnswer::TokenApprovals {
+        owner_is_public,
+        public_ownership_expiration,
+        private_metadata_is_public,
+        private_metadata_is_public_expiration,
+        token_approvals,
     })
 }
 
-/// Returns QueryResult displaying the list of tokens that the contract controls
+/// Returns QueryResult displaying the inventory-wide approvals for a specified address
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
 /// * `viewer` - optional address and key making an authenticated query request
-/// * `start_after` - optionally only display token ids that come after this one
-/// * `limit` - optional max number of tokens to display
+/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_all_tokens<S: Storage, A: Api, Q: Querier>(
+pub fn query_inventory_approvals<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     viewer: Option<ViewerInfo>,
-    start_after: Option<String>,
-    limit: Option<u32>,
+    include_expired: Option<bool>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    // authenticate permission to view token supply
-    check_view_supply(deps, viewer, from_permit)?;
-    let mut i = start_after.map_or_else(
-        || Ok(0),
-        |id| {
-            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-            let idx: u32 = may_load(&map2idx, id.as_bytes())?
-                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
-            idx.checked_add(1).ok_or_else(|| {
-                StdError::generic_err("This token was the last one the contract could mint")
-            })
-        },
-    )?;
-    let cut_off = limit.unwrap_or(300);
+    let owner_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let owner_slice = owner_raw.as_slice();
+    let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
     let config: Config = load(&deps.storage, CONFIG_KEY)?;
-    let mut tokens = Vec::new();
-    let mut count = 0u32;
-    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-    while count < cut_off && i < config.mint_cnt {
-        if let Some(id) = may_load::<String, _>(&map2id, &i.to_le_bytes())? {
-            tokens.push(id);
-            // will hit gas ceiling before the count overflows
-            count += 1;
-        }
-        // i can't overflow if it was less than a u32
-        i += 1;
-    }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    let global_pass: bool =
+        may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let mut all_perm: Vec<Permission> = json_may_load(&all_store, owner_slice)?.unwrap_or_default();
+    let perm_type_info = PermissionTypeInfo {
+        view_owner_idx: PermissionType::ViewOwner.to_usize(),
+        view_meta_idx: PermissionType::ViewMetadata.to_usize(),
+        transfer_idx: PermissionType::Transfer.to_usize(),
+        num_types: PermissionType::Transfer.num_types(),
+    };
+    let (
+        inventory_approvals,
+        mut public_ownership_expiration,
+        private_metadata_is_public_expiration,
+    ) = gen_snip721_approvals(
+        &deps.api,
+        &block,
+        &mut all_perm,
+        include_expired.unwrap_or(false),
+        &perm_type_info,
+    )?;
+    let owner_is_public = if global_pass {
+        public_ownership_expiration = Some(Expiration::Never);
+        true
+    } else {
+        public_ownership_expiration.is_some()
+    };
+    let private_metadata_is_public = private_metadata_is_public_expiration.is_some();
+    to_binary(&QueryAnswer::InventoryApprovals {
+        owner_is_public,
+        public_ownership_expiration,
+        private_metadata_is_public,
+        private_metadata_is_public_expiration,
+        inventory_approvals,
+    })
 }
 
-/// Returns QueryResult displaying the owner of the input token if the requester is authorized
-/// to view it and the transfer approvals on this token if the owner is querying
+/// Returns QueryResult displaying the list of all addresses that have approval to transfer
+/// all of the owner's tokens.  Only the owner's viewing key will be accepted for this query
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - string slice of the token id
-/// * `viewer` - optional address and key making an authenticated query request
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `owner` - an optional reference to the address whose transfer ALL list should be displayed
+/// * `viewing_key` - optional String of the owner's viewing key
+/// * `include_expired` - optionally true if the Approval list should include expired Approvals
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_owner_of<S: Storage, A: Api, Q: Querier>(
+pub fn query_approved_for_all<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    token_id: &str,
-    viewer: Option<ViewerInfo>,
+    owner: Option<&HumanAddr>,
+    viewing_key: Option<String>,
     include_expired: Option<bool>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let (may_owner, approvals, _idx) =
-        process_cw721_owner_of(deps, token_id, viewer, include_expired, from_permit)?;
-    if let Some(owner) = may_owner {
-        return to_binary(&QueryAnswer::OwnerOf { owner, approvals });
-    }
-    Err(StdError::generic_err(format!(
-        "You are not authorized to view the owner of token {}",
-        token_id
-    )))
+    // get the address whose approvals are being queried
+    let owner_raw = if let Some(pmt) = from_permit {
+        pmt
+    } else {
+        let raw = deps.api.canonical_address(owner.ok_or_else(|| {
+            StdError::generic_err("This is being called incorrectly if there is no owner address")
+        })?)?;
+        if let Some(key) = viewing_key {
+            check_key(&deps.storage, &raw, key)?;
+        // didn't supply a viewing key so just return an empty list of approvals
+        } else {
+            return to_binary(&QueryAnswer::ApprovedForAll {
+                operators: Vec::new(),
+            });
+        }
+        raw
+    };
+    // TODO remove this when BlockInfo becomes available to queries
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let mut operators: Vec<Cw721Approval> = Vec::new();
+    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let all_perm: Vec<Permission> =
+        json_may_load(&all_store, owner_raw.as_slice())?.unwrap_or_default();
+    gen_cw721_approvals(
+        &deps.api,
+        &block,
+        &all_perm,
+        &mut operators,
+        PermissionType::Transfer.to_usize(),
+        include_expired.unwrap_or(false),
+    )?;
+
+    to_binary(&QueryAnswer::ApprovedForAll { operators })
 }
 
-/// Returns QueryResult displaying the public metadata of a token
+/// Returns QueryResult displaying an optionally paginated list of all tokens belonging to
+/// the owner address.  It will only display the tokens that the querier has view_owner
+/// approval
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-/// * `token_id` - string slice of the token id
-pub fn query_nft_info<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
-    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, storage);
-    let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
-    // if token id was found
-    if let Some(idx) = may_idx {
-        let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, storage);
-        let meta: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or(Metadata {
-            token_uri: None,
-            extension: None,
-        });
-        return to_binary(&QueryAnswer::NftInfo {
-            token_uri: meta.token_uri,
-            extension: meta.extension,
-        });
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `owner` - a reference to the address whose tokens should be displayed
+/// * `viewer` - optional address of the querier if different from the owner
+/// * `viewing_key` - optional viewing key String
+/// * `start_after` - optionally only display token ids that come after this String in
+///   lexicographical order
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    owner: &HumanAddr,
+    viewer: Option<HumanAddr>,
+    viewing_key: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let owner_raw = deps.api.canonical_address(owner)?;
+    let cut_off = limit.unwrap_or(30);
+    // determine the querier
+    let (is_owner, may_querier) = if let Some(pmt) = from_permit.as_ref() {
+        // permit tells you who is querying, so also check if he is the owner
+        (owner_raw == *pmt, from_permit)
+    // no permit, so check if a key was provided and who it matches
+    } else if let Some(key) = viewing_key {
+        // if there is a viewer
+        viewer
+            // convert to canonical
+            .map(|v| deps.api.canonical_address(&v))
+            .transpose()?
+            // only keep the viewer address if the viewing key matches
+            .filter(|v| check_key(&deps.storage, v, key.clone()).is_ok())
+            .map_or_else(
+                // no viewer or key did not match
+                || {
+                    // check if the key matches the owner, and error if it fails this last chance
+                    check_key(&deps.storage, &owner_raw, key)?;
+                    Ok((true, Some(owner_raw.clone())))
+                },
+                // we know the querier is the viewer, so check if someone put the same address for both
+                |v| Ok((v == owner_raw, Some(v))),
+            )?
+    // no permit, no viewing key, so querier is unknown
+    } else {
+        (false, None)
+    };
+    // exit early if the limit is 0
+    if cut_off == 0 {
+        return to_binary(&QueryAnswer::TokenList { tokens: Vec::new() });
     }
-    let config: Config = load(storage, CONFIG_KEY)?;
-    // token id wasn't found
-    // if the token supply is public, let them know the token does not exist
-    if config.token_supply_is_public {
-        return Err(StdError::generic_err(format!(
-            "Token ID: {} not found",
-            token_id
-        )));
+    // get list of owner's tokens
+    let own_inv = Inventory::new(&deps.storage, owner_raw)?;
+    let owner_slice = own_inv.owner.as_slice();
+
+    let querier = may_querier.as_ref();
+    // if querier is different than the owner, check if ownership is public
+    let mut may_config: Option<Config> = None;
+    let mut known_pass = if !is_owner {
+        let config: Config = load(&deps.storage, CONFIG_KEY)?;
+        let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+        let pass: bool = may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
+        may_config = Some(config);
+        pass
+    } else {
+        true
+    };
+    // TODO remove this when BlockInfo becomes available to queries
+    let block = if !known_pass {
+        let b: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "not used".to_string(),
+        });
+        b
+    } else {
+        BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "not used".to_string(),
+        }
+    };
+    let exp_idx = PermissionType::ViewOwner.to_usize();
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let mut list_it: bool;
+    let mut oper_for: Vec<CanonicalAddr> = Vec::new();
+    let mut tokens: Vec<String> = Vec::new();
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let mut inv_iter = if let Some(after) = start_after.as_ref() {
+        // load the config if we haven't already
+        let config = may_config.map_or_else(|| load::<Config, _>(&deps.storage, CONFIG_KEY), Ok)?;
+        // if the querier is allowed to view all of the owner's tokens, let them know if the token
+        // does not belong to the owner
+        let inv_err = format!("Token ID: {} is not in the specified inventory", after);
+        // or tell any other viewer that they are not authorized
+        let unauth_err = format!(
+            "You are not authorized to perform this action on token {}",
+            after
+        );
+        let public_err = format!("Token ID: {} not found", after);
+        // if token supply is public let them know if the token id does not exist
+        let not_found_err = if config.token_supply_is_public {
+            &public_err
+        } else if known_pass {
+            &inv_err
+        } else {
+            &unauth_err
+        };
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: u32 = may_load(&map2idx, after.as_bytes())?
+            .ok_or_else(|| StdError::generic_err(not_found_err))?;
+        // make sure querier is allowed to know if the supplied token belongs to owner
+        if !known_pass {
+            let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?
+                .ok_or_else(|| StdError::generic_err("Token info storage is corrupt"))?;
+            // if the specified token belongs to the specified owner, save if the querier is an operator
+            let mut may_oper_vec = if own_inv.owner == token.owner {
+                None
+            } else {
+                Some(Vec::new())
+            };
+            check_perm_core(
+                deps,
+                &block,
+                &token,
+                after,
+                querier,
+                token.owner.as_slice(),
+                exp_idx,
+                may_oper_vec.as_mut().unwrap_or(&mut oper_for),
+                &unauth_err,
+            )?;
+            // if querier is found to have ALL permission for the specified owner, no need to check permission ever again
+            if !oper_for.is_empty() {
+                known_pass = true;
+            }
+        }
+        InventoryIter::start_after(&deps.storage, &own_inv, idx, &inv_err)?
+    } else {
+        InventoryIter::new(&own_inv)
+    };
+    let mut count = 0u32;
+    while let Some(idx) = inv_iter.next(&deps.storage)? {
+        if let Some(id) = may_load::<String, _>(&map2id, &idx.to_le_bytes())? {
+            list_it = known_pass;
+            // only check permissions if not public or owner
+            if !known_pass {
+                if let Some(token) = json_may_load::<Token, _>(&info_store, &idx.to_le_bytes())? {
+                    list_it = check_perm_core(
+                        deps,
+                        &block,
+                        &token,
+                        &id,
+                        querier,
+                        owner_slice,
+                        exp_idx,
+                        &mut oper_for,
+                        "",
+                    )
+                    .is_ok();
+                    // if querier is found to have ALL permission, no need to check permission ever again
+                    if !oper_for.is_empty() {
+                        known_pass = true;
+                    }
+                }
+            }
+            if list_it {
+                tokens.push(id);
+                // it'll hit the gas ceiling before overflowing the count
+                count += 1;
+                // exit if we hit the limit
+                if count >= cut_off {
+                    break;
+                }
+            }
+        }
     }
-    // otherwise, just return empty metadata
-    to_binary(&QueryAnswer::NftInfo {
-        token_uri: None,
-        extension: None,
-    })
+    to_binary(&QueryAnswer::TokenList { tokens })
 }
 
-/// Returns QueryResult displaying the private metadata of a token if permitted to
-/// view it
+/// Returns QueryResult displaying the number of tokens that the querier has permission to
+/// view ownership and that belong to the specified address
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - string slice of the token id
-/// * `viewer` - optional address and key making an authenticated query request
+/// * `owner` - a reference to the address whose tokens should be displayed
+/// * `viewer` - optional address of the querier if different from the owner
+/// * `viewing_key` - optional viewing key String
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_private_meta<S: Storage, A: Api, Q: Querier>(
+pub fn query_num_owner_tokens<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    token_id: &str,
-    viewer: Option<ViewerInfo>,
+    owner: &HumanAddr,
+    viewer: Option<HumanAddr>,
+    viewing_key: Option<String>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
-    check_perm_core(
-        deps,
-        &prep_info.block,
-        &prep_info.token,
-        token_id,
-        prep_info.viewer_raw.as_ref(),
-        prep_info.token.owner.as_slice(),
-        PermissionType::ViewMetadata.to_usize(),
-        &mut Vec::new(),
-        &prep_info.err_msg,
-    )?;
-    // don't display if private metadata is sealed
-    if !prep_info.token.unwrapped {
-        return Err(StdError::generic_err(
-            "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
-        ));
-    }
-    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
-    let meta: Metadata = may_load(&meta_store, &prep_info.idx.to_le_bytes())?.unwrap_or(Metadata {
-        token_uri: None,
-        extension: None,
-    });
-    to_binary(&QueryAnswer::PrivateMetadata {
-        token_uri: meta.token_uri,
-        extension: meta.extension,
-    })
-}
+    let owner_raw = deps.api.canonical_address(owner)?;
+    // determine the querier
+    let (is_owner, may_querier) = if let Some(pmt) = from_permit.as_ref() {
+        // permit tells you who is querying, so also check if he is the owner
+        (owner_raw == *pmt, from_permit)
+    // no permit, so check if a key was provided and who it matches
+    } else if let Some(key) = viewing_key {
+        // if there is a viewer
+        viewer
+            // convert to canonical
+            .map(|v| deps.api.canonical_address(&v))
+            .transpose()?
+            // only keep the viewer address if the viewing key matches
+            .filter(|v| check_key(&deps.storage, v, key.clone()).is_ok())
+            .map_or_else(
+                // no viewer or key did not match
+                || {
+                    // check if the key matches the owner, and error if it fails this last chance
+                    check_key(&deps.storage, &owner_raw, key)?;
+                    Ok((true, Some(owner_raw.clone())))
+                },
+                // we know the querier is the viewer, so check if someone put the same address for both
+                |v| Ok((v == owner_raw, Some(v))),
+            )?
+    // no permit, no viewing key, so querier is unknown
+    } else {
+        (false, None)
+    };
 
-/// Returns QueryResult displaying response of both the OwnerOf and NftInfo queries
-///
-/// # Arguments
-///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - string slice of the token id
-/// * `viewer` - optional address and key making an authenticated query request
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
-/// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_all_nft_info<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-    token_id: &str,
-    viewer: Option<ViewerInfo>,
-    include_expired: Option<bool>,
-    from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let (owner, approvals, idx) =
-        process_cw721_owner_of(deps, token_id, viewer, include_expired, from_permit)?;
-    let meta_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
-    let info: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
-    let access = Cw721OwnerOfResponse { owner, approvals };
-    to_binary(&QueryAnswer::AllNftInfo { access, info })
-}
+    // get list of owner's tokens
+    let own_inv = Inventory::new(&deps.storage, owner_raw)?;
+    let owner_slice = own_inv.owner.as_slice();
 
-/// Returns QueryResult displaying all the token information the querier is permitted to
-/// view.  This may include the owner, the public metadata, the private metadata, royalty
-/// information, mint run information, whether the token is unwrapped, whether the token is
-/// transferable, and the token and inventory approvals
-///
-/// # Arguments
-///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - the token id
-/// * `viewer` - optional address and key making an authenticated query request
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
-/// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_nft_dossier<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-    token_id: String,
-    viewer: Option<ViewerInfo>,
-    include_expired: Option<bool>,
-    from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let dossier = dossier_list(deps, vec![token_id], viewer, include_expired, from_permit)?
-        .pop()
-        .ok_or_else(|| {
-            StdError::generic_err("NftDossier can never return an empty dossier list")
-        })?;
+    // if querier is different than the owner, check if ownership is public
+    let mut known_pass = if !is_owner {
+        let config: Config = load(&deps.storage, CONFIG_KEY)?;
+        let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
+        let pass: bool = may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
+        pass
+    } else {
+        true
+    };
+    // TODO remove this when BlockInfo becomes available to queries
+    let block = if !known_pass {
+        let b: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "not used".to_string(),
+        });
+        b
+    } else {
+        BlockInfo {
+            height: 1,
+            time: 1,
+            chain_id: "not used".to_string(),
+        }
+    };
+    let exp_idx = PermissionType::ViewOwner.to_usize();
+    let global_raw = CanonicalAddr(Binary::from(b"public"));
+    let (sender, only_public) = if let Some(sdr) = may_querier.as_ref() {
+        (sdr, false)
+    } else {
+        (&global_raw, true)
+    };
+    let mut found_one = only_public;
+    if !known_pass {
+        // check if the ownership has been made public or the sender has ALL permission.
+        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, owner_slice)?;
+        if let Some(list) = may_list {
+            for perm in &list {
+                if perm.address == *sender || perm.address == global_raw {
+                    if let Some(exp) = perm.expirations[exp_idx] {
+                        if !exp.is_expired(&block) {
+                            known_pass = true;
+                            break;
+                        }
+                    }
+                    // we can quit if we found both the sender and the global (or if only searching for public)
+                    if found_one {
+                        break;
+                    } else {
+                        found_one = true;
+                    }
+                }
+            }
+        }
+    }
+    // if it is either the owner, ownership is public, or the querier has inventory-wide view owner permission,
+    // let them see the full count
+    if known_pass {
+        return to_binary(&QueryAnswer::NumTokens {
+            count: own_inv.info.count,
+        });
+    }
 
-    to_binary(&QueryAnswer::NftDossier {
-        owner: dossier.owner,
-        public_metadata: dossier.public_metadata,
-        private_metadata: dossier.private_metadata,
-        royalty_info: dossier.royalty_info,
-        mint_run_info: dossier.mint_run_info,
-        transferable: dossier.transferable,
-        unwrapped: dossier.unwrapped,
-        display_private_metadata_error: dossier.display_private_metadata_error,
-        owner_is_public: dossier.owner_is_public,
-        public_ownership_expiration: dossier.public_ownership_expiration,
-        private_metadata_is_public: dossier.private_metadata_is_public,
-        private_metadata_is_public_expiration: dossier.private_metadata_is_public_expiration,
-        token_approvals: dossier.token_approvals,
-        inventory_approvals: dossier.inventory_approvals,
-    })
+    // get the list of tokens that might have viewable ownership for this querier
+    let mut token_idxs: HashSet<u32> = HashSet::new();
+    found_one = only_public;
+    let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
+    let auth_list: Vec<AuthList> = may_load(&auth_store, owner_slice)?.unwrap_or_default();
+    for auth in auth_list.iter() {
+        if auth.address == *sender || auth.address == global_raw {
+            token_idxs.extend(auth.tokens[exp_idx].iter());
+            if found_one {
+                break;
+            } else {
+                found_one = true;
+            }
+        }
+    }
+    // check if the the token permissions have expired, and if not include it in the count
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let mut count = 0u32;
+    for idx in token_idxs.into_iter() {
+        if let Some(token) = json_may_load::<Token, _>(&info_store, &idx.to_le_bytes())? {
+            found_one = only_public;
+            for perm in token.permissions.iter() {
+                if perm.address == *sender || perm.address == global_raw {
+                    if let Some(exp) = perm.expirations[exp_idx] {
+                        if !exp.is_expired(&block) {
+                            count += 1;
+                            break;
+                        }
+                    }
+                    // we can quit if we found both the sender and the global (or if only searching for public)
+                    if found_one {
+                        break;
+                    } else {
+                        found_one = true;
+                    }
+                }
+            }
+        }
+    }
+
+    to_binary(&QueryAnswer::NumTokens { count })
 }
 
-/// Returns QueryResult displaying all the token information the querier is permitted to
-/// view of multiple tokens.  This may include the owner, the public metadata, the private metadata,
-/// royalty information, mint run information, whether the token is unwrapped, whether the token is
-/// transferable, and the token and inventory approvals
+/// Returns QueryResult displaying true if the token has been unwrapped.  If sealed metadata
+/// is not enabled, all tokens are considered unwrapped
 ///
 /// # Arguments
 ///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_ids` - list of token ids whose info should be retrieved
-/// * `viewer` - optional address and key making an authenticated query request
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
-/// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_batch_nft_dossier<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-    token_ids: Vec<String>,
-    viewer: Option<ViewerInfo>,
-    include_expired: Option<bool>,
-    from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let nft_dossiers = dossier_list(deps, token_ids, viewer, include_expired, from_permit)?;
-
-    to_binary(&QueryAnswer::BatchNftDossier { nft_dossiers })
+/// * `storage` - a reference to the contract's storage
+pub fn query_is_unwrapped<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let get_token_res = get_token(storage, token_id, None);
+    match get_token_res {
+        Err(err) => match err {
+            // if the token id is not found, but token supply is private, just say
+            // the token's wrapped state is the same as a newly minted token
+            StdError::GenericErr { msg, .. }
+                if !config.token_supply_is_public && msg.contains("Token ID") =>
+            {
+                to_binary(&QueryAnswer::IsUnwrapped {
+                    token_is_unwrapped: !config.sealed_metadata_is_enabled,
+                })
+            }
+            _ => Err(err),
+        },
+        Ok((token, _idx)) => to_binary(&QueryAnswer::IsUnwrapped {
+            token_is_unwrapped: token.unwrapped,
+        }),
+    }
 }
 
-/// Returns QueryResult displaying the approvals in place for a specified token
+/// Returns QueryResult displaying true if the token is transferable
 ///
 /// # Arguments
 ///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_id` - string slice of the token id
-/// * `viewing_key` - the optional token owner's viewing key String
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
-/// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_token_approvals<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-    token_id: &str,
-    viewing_key: Option<String>,
-    include_expired: Option<bool>,
-    from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let config: Config = load(&deps.storage, CONFIG_KEY)?;
-    let custom_err = format!(
-        "You are not authorized to view approvals for token {}",
-        token_id
-    );
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (mut token, _idx) = get_token(&deps.storage, token_id, opt_err)?;
-    // verify that the querier is the token owner
-    if let Some(pmt) = from_permit {
-        if pmt != token.owner {
-            return Err(StdError::generic_err(custom_err));
-        }
-    } else {
-        let key = viewing_key.ok_or_else(|| {
-            StdError::generic_err("This is being called incorrectly if there is no viewing key")
-        })?;
-        check_key(&deps.storage, &token.owner, key)?;
+/// * `storage` - a reference to the contract's storage
+pub fn query_is_transferable<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let get_token_res = get_token(storage, token_id, None);
+    match get_token_res {
+        Err(err) => match err {
+            // if the token id is not found, but token supply is private, just say
+            // the token is transferable
+            StdError::GenericErr { msg, .. }
+                if !config.token_supply_is_public && msg.contains("Token ID") =>
+            {
+                to_binary(&QueryAnswer::IsTransferable {
+                    token_is_transferable: true,
+                })
+            }
+            _ => Err(err),
+        },
+        Ok((token, _idx)) => to_binary(&QueryAnswer::IsTransferable {
+            token_is_transferable: token.transferable,
+        }),
     }
-    let owner_slice = token.owner.as_slice();
-    let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-    let global_pass: bool =
-        may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
-    // TODO remove this when BlockInfo becomes available to queries
-    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-        height: 1,
-        time: 1,
-        chain_id: "not used".to_string(),
-    });
-    let perm_type_info = PermissionTypeInfo {
-        view_owner_idx: PermissionType::ViewOwner.to_usize(),
-        view_meta_idx: PermissionType::ViewMetadata.to_usize(),
-        transfer_idx: PermissionType::Transfer.to_usize(),
-        num_types: PermissionType::Transfer.num_types(),
-    };
-    let incl_exp = include_expired.unwrap_or(false);
-    let (token_approvals, token_owner_exp, token_meta_exp) = gen_snip721_approvals(
-        &deps.api,
-        &block,
-        &mut token.permissions,
-        incl_exp,
-        &perm_type_info,
-    )?;
-    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-    let mut all_perm: Vec<Permission> = json_may_load(&all_store, owner_slice)?.unwrap_or_default();
-    let (_inventory_approv, all_owner_exp, all_meta_exp) =
-        gen_snip721_approvals(&deps.api, &block, &mut all_perm, incl_exp, &perm_type_info)?;
-    // determine if ownership is public
-    let (public_ownership_expiration, owner_is_public) = if global_pass {
-        (Some(Expiration::Never), true)
-    } else if token_owner_exp.is_some() {
-        (token_owner_exp, true)
-    } else {
-        (all_owner_exp, all_owner_exp.is_some())
-    };
-    // determine if private metadata is public
-    let (private_metadata_is_public_expiration, private_metadata_is_public) =
-        if token_meta_exp.is_some() {
-            (token_meta_exp, true)
-        } else {
-            (all_meta_exp, all_meta_exp.is_some())
-        };
-    to_binary(&QueryAnswer::TokenApprovals {
-        owner_is_public,
-        public_ownership_expiration,
-        private_metadata_is_public,
-        private_metadata_is_public_expiration,
-        token_approvals,
-    })
 }
 
-/// Returns QueryResult displaying the inventory-wide approvals for a specified address
+/// Returns QueryResult displaying an optionally paginated list of all transactions
+/// involving a specified address, displayed in reverse chronological order
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
 /// * `viewer` - optional address and key making an authenticated query request
-/// * `include_expired` - optionally true if the Approval lists should include expired Approvals
+/// * `page` - an optional page number.  If given, the most recent `page` times `page_size`
+///   transactions will be skipped
+/// * `page_size` - optional max number of transactions to display
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_inventory_approvals<S: Storage, A: Api, Q: Querier>(
+pub fn query_transactions<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     viewer: Option<ViewerInfo>,
-    include_expired: Option<bool>,
+    page: Option<u32>,
+    page_size: Option<u32>,
     from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let owner_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+) -> StdResult<Binary> {
+    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
         StdError::generic_err("This is being called incorrectly if there is no querier address")
     })?;
-    let owner_slice = owner_raw.as_slice();
-    let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-    let config: Config = load(&deps.storage, CONFIG_KEY)?;
-    let global_pass: bool =
-        may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
-    // TODO remove this when BlockInfo becomes available to queries
-    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-        height: 1,
-        time: 1,
-        chain_id: "not used".to_string(),
-    });
-    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-    let mut all_perm: Vec<Permission> = json_may_load(&all_store, owner_slice)?.unwrap_or_default();
-    let perm_type_info = PermissionTypeInfo {
-        view_owner_idx: PermissionType::ViewOwner.to_usize(),
-        view_meta_idx: PermissionType::ViewMetadata.to_usize(),
-        transfer_idx: PermissionType::Transfer.to_usize(),
-        num_types: PermissionType::Transfer.num_types(),
-    };
-    let (
-        inventory_approvals,
-        mut public_ownership_expiration,
-        private_metadata_is_public_expiration,
-    ) = gen_snip721_approvals(
-        &deps.api,
-        &block,
-        &mut all_perm,
-        include_expired.unwrap_or(false),
-        &perm_type_info,
-    )?;
-    let owner_is_public = if global_pass {
-        public_ownership_expiration = Some(Expiration::Never);
-        true
-    } else {
-        public_ownership_expiration.is_some()
-    };
-    let private_metadata_is_public = private_metadata_is_public_expiration.is_some();
-    to_binary(&QueryAnswer::InventoryApprovals {
-        owner_is_public,
-        public_ownership_expiration,
-        private_metadata_is_public,
-        private_metadata_is_public_expiration,
-        inventory_approvals,
-    })
-}
-
-/// Returns QueryResult displaying the list of all addresses that have approval to transfer
-/// all of the owner's tokens.  Only the owner's viewing key will be accepted for this query
-///
-/// # Arguments
-///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `owner` - an optional reference to the address whose transfer ALL list should be displayed
-/// * `viewing_key` - optional String of the owner's viewing key
-/// * `include_expired` - optionally true if the Approval list should include expired Approvals
-/// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_approved_for_all<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-    owner: Option<&HumanAddr>,
-    viewing_key: Option<String>,
-    include_expired: Option<bool>,
-    from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    // get the address whose approvals are being queried
-    let owner_raw = if let Some(pmt) = from_permit {
-        pmt
-    } else {
-        let raw = deps.api.canonical_address(owner.ok_or_else(|| {
-            StdError::generic_err("This is being called incorrectly if there is no owner address")
-        })?)?;
-        if let Some(key) = viewing_key {
-            check_key(&deps.storage, &raw, key)?;
-        // didn't supply a viewing key so just return an empty list of approvals
-        } else {
-            return to_binary(&QueryAnswer::ApprovedForAll {
-                operators: Vec::new(),
-            });
-        }
-        raw
-    };
-    // TODO remove this when BlockInfo becomes available to queries
-    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-        height: 1,
-        time: 1,
-        chain_id: "not used".to_string(),
-    });
-    let mut operators: Vec<Cw721Approval> = Vec::new();
-    let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-    let all_perm: Vec<Permission> =
-        json_may_load(&all_store, owner_raw.as_slice())?.unwrap_or_default();
-    gen_cw721_approvals(
+    let (txs, total) = get_txs(
         &deps.api,
-        &block,
-        &all_perm,
-        &mut operators,
-        PermissionType::Transfer.to_usize(),
-        include_expired.unwrap_or(false),
+        &deps.storage,
+        &address_raw,
+        page.unwrap_or(0),
+        page_size.unwrap_or(30),
     )?;
-
-    to_binary(&QueryAnswer::ApprovedForAll { operators })
+    to_binary(&QueryAnswer::TransactionHistory { total, txs })
 }
 
-/// Returns QueryResult displaying an optionally paginated list of all tokens belonging to
-/// the owner address.  It will only display the tokens that the querier has view_owner
-/// approval
+/// Returns QueryResult after verifying that the specified address has transfer approval
+/// for all the listed tokens.  A token will count as unapproved if it is non-transferable
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `owner` - a reference to the address whose tokens should be displayed
-/// * `viewer` - optional address of the querier if different from the owner
-/// * `viewing_key` - optional viewing key String
-/// * `start_after` - optionally only display token ids that come after this String in
-///                   lexicographical order
-/// * `limit` - optional max number of tokens to display
+/// * `token_ids` - a list of token ids to check if the address has transfer approval
+/// * `viewer` - optional address and key making an authenticated query request
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_tokens<S: Storage, A: Api, Q: Querier>(
+pub fn query_verify_approval<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    owner: &HumanAddr,
-    viewer: Option<HumanAddr>,
-    viewing_key: Option<String>,
-    start_after: Option<String>,
-    limit: Option<u32>,
+    token_ids: Vec<String>,
+    viewer: Option<ViewerInfo>,
     from_permit: Option<CanonicalAddr>,
-) -> QueryResult {
-    let owner_raw = deps.api.canonical_address(owner)?;
-    let cut_off = limit.unwrap_or(30);
-    // determine the querier
-    let (is_owner, may_querier) = if let Some(pmt) = from_permit.as_ref() {
-        // permit tells you who is querying, so also check if he is the owner
-        (owner_raw == *pmt, from_permit)
-    // no permit, so check if a key was provided and who it matches
-    } else if let Some(key) = viewing_key {
-        // if there is a viewer
-        viewer
-            // convert to canonical
-            .map(|v| deps.api.canonical_address(&v))
-            .transpose()?
-            // only keep the viewer address if the viewing key matches
-            .filter(|v| check_key(&deps.storage, v, key.clone()).is_ok())
-            .map_or_else(
-                // no viewer or key did not match
-                || {
-                    // check if the key matches the owner, and error if it fails this last chance
-                    check_key(&deps.storage, &owner_raw, key)?;
-                    Ok((true, Some(owner_raw.clone())))
-                },
-                // we know the querier is the viewer, so check if someone put the same address for both
-                |v| Ok((v == owner_raw, Some(v))),
-            )?
-    // no permit, no viewing key, so querier is unknown
-    } else {
-        (false, None)
-    };
-    // exit early if the limit is 0
-    if cut_off == 0 {
-        return to_binary(&QueryAnswer::TokenList { tokens: Vec::new() });
-    }
-    // get list of owner's tokens
-    let own_inv = Inventory::new(&deps.storage, owner_raw)?;
-    let owner_slice = own_inv.owner.as_slice();
-
-    let querier = may_querier.as_ref();
-    // if querier is different than the owner, check if ownership is public
-    let mut may_config: Option<Config> = None;
-    let mut known_pass = if !is_owner {
-        let config: Config = load(&deps.storage, CONFIG_KEY)?;
-        let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-        let pass: bool = may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
-        may_config = Some(config);
-        pass
-    } else {
-        true
-    };
+) -> StdResult<Binary> {
+    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
     // TODO remove this when BlockInfo becomes available to queries
-    let block = if !known_pass {
-        let b: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "not used".to_string(),
-        });
-        b
-    } else {
-        BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "not used".to_string(),
-        }
-    };
-    let exp_idx = PermissionType::ViewOwner.to_usize();
-    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-    let mut list_it: bool;
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
-    let mut tokens: Vec<String> = Vec::new();
-    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
-    let mut inv_iter = if let Some(after) = start_after.as_ref() {
-        // load the config if we haven't already
-        let config = may_config.map_or_else(|| load::<Config, _>(&deps.storage, CONFIG_KEY), Ok)?;
-        // if the querier is allowed to view all of the owner's tokens, let them know if the token
-        // does not belong to the owner
-        let inv_err = format!("Token ID: {} is not in the specified inventory", after);
-        // or tell any other viewer that they are not authorized
-        let unauth_err = format!(
-            "You are not authorized to perform this action on token {}",
-            after
-        );
-        let public_err = format!("Token ID: {} not found", after);
-        // if token supply is public let them know if the token id does not exist
-        let not_found_err = if config.token_supply_is_public {
-            &public_err
-        } else if known_pass {
-            &inv_err
-        } else {
-            &unauth_err
-        };
-        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
-        let idx: u32 = may_load(&map2idx, after.as_bytes())?
-            .ok_or_else(|| StdError::generic_err(not_found_err))?;
-        // make sure querier is allowed to know if the supplied token belongs to owner
-        if !known_pass {
-            let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?
-                .ok_or_else(|| StdError::generic_err("Token info storage is corrupt"))?;
-            // if the specified token belongs to the specified owner, save if the querier is an operator
-            let mut may_oper_vec = if own_inv.owner == token.owner {
-                None
+    for id in token_ids.into_iter() {
+        let approved = get_token_if_permitted(
+            deps,
+            &block,
+            &id,
+            Some(&address_raw),
+            PermissionType::Transfer,
+            &mut oper_for,
+            &config,
+            // the and_then forces an error if the token is not transferable
+        )
+        .and_then(|(t, _)| {
+            if t.transferable {
+                Ok(())
             } else {
-                Some(Vec::new())
-            };
-            check_perm_core(
-                deps,
-                &block,
-                &token,
-                after,
-                querier,
-                token.owner.as_slice(),
-                exp_idx,
-                may_oper_vec.as_mut().unwrap_or(&mut oper_for),
-                &unauth_err,
-            )?;
-            // if querier is found to have ALL permission for the specified owner, no need to check permission ever again
-            if !oper_for.is_empty() {
-                known_pass = true;
-            }
-        }
-        InventoryIter::start_after(&deps.storage, &own_inv, idx, &inv_err)?
-    } else {
-        InventoryIter::new(&own_inv)
-    };
-    let mut count = 0u32;
-    while let Some(idx) = inv_iter.next(&deps.storage)? {
-        if let Some(id) = may_load::<String, _>(&map2id, &idx.to_le_bytes())? {
-            list_it = known_pass;
-            // only check permissions if not public or owner
-            if !known_pass {
-                if let Some(token) = json_may_load::<Token, _>(&info_store, &idx.to_le_bytes())? {
-                    list_it = check_perm_core(
-                        deps,
-                        &block,
-                        &token,
-                        &id,
-                        querier,
-                        owner_slice,
-                        exp_idx,
-                        &mut oper_for,
-                        "",
-                    )
-                    .is_ok();
-                    // if querier is found to have ALL permission, no need to check permission ever again
-                    if !oper_for.is_empty() {
-                        known_pass = true;
-                    }
-                }
-            }
-            if list_it {
-                tokens.push(id);
-                // it'll hit the gas ceiling before overflowing the count
-                count += 1;
-                // exit if we hit the limit
-                if count >= cut_off {
-                    break;
-                }
+                Err(StdError::unauthorized())
             }
+        })
+        .is_ok();
+        if !approved {
+            return to_binary(&QueryAnswer::VerifyTransferApproval {
+                approved_for_all: false,
+                first_unapproved_token: Some(id),
+            });
         }
     }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    to_binary(&QueryAnswer::VerifyTransferApproval {
+        approved_for_all: true,
+        first_unapproved_token: None,
+    })
+}
+
+/// Returns QueryResult displaying the registered code hash of the specified contract if
+/// it has registered and whether the contract implements BatchReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `contract` - a reference to the contract's address whose code hash is being requested
+pub fn query_code_hash<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    contract: &HumanAddr,
+) -> QueryResult {
+    let contract_raw = deps.api.canonical_address(contract)?;
+    let store = ReadonlyPrefixedStorage::new(PREFIX_RECEIVERS, &deps.storage);
+    let may_reg_rec: Option<ReceiveRegistration> = may_load(&store, contract_raw.as_slice())?;
+    if let Some(reg_rec) = may_reg_rec {
+        return to_binary(&QueryAnswer::RegisteredCodeHash {
+            code_hash: Some(reg_rec.code_hash),
+            also_implements_batch_receive_nft: reg_rec.impl_batch,
+        });
+    }
+    to_binary(&QueryAnswer::RegisteredCodeHash {
+        code_hash: None,
+        also_implements_batch_receive_nft: false,
+    })
+}
+
+/// Returns QueryResult displaying the configuration of the paid public mint
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_sale_info<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) -> QueryResult {
+    let sale: Option<SaleConfig> = may_load(&deps.storage, SALE_CONFIG_KEY)?;
+    let presale: Option<SaleConfig> = may_load(&deps.storage, PRESALE_CONFIG_KEY)?;
+    let tokens: Vec<StoredPaymentToken> =
+        may_load(&deps.storage, PAYMENT_TOKENS_KEY)?.unwrap_or_default();
+    let payment_tokens = tokens
+        .into_iter()
+        .map(|t| {
+            Ok(PaymentToken {
+                address: deps.api.human_address(&t.address)?,
+                code_hash: t.code_hash,
+                price: t.price,
+            })
+        })
+        .collect::<StdResult<Vec<PaymentToken>>>()?;
+    to_binary(&QueryAnswer::SaleInfo {
+        sale,
+        presale,
+        payment_tokens,
+    })
 }
 
-/// Returns QueryResult displaying the number of tokens that the querier has permission to
-/// view ownership and that belong to the specified address
+/// Returns QueryResult displaying the number of tokens the querier may still purchase during
+/// the presale
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `owner` - a reference to the address whose tokens should be displayed
-/// * `viewer` - optional address of the querier if different from the owner
-/// * `viewing_key` - optional viewing key String
+/// * `viewer` - optional address and key making an authenticated query request
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_num_owner_tokens<S: Storage, A: Api, Q: Querier>(
+pub fn query_presale_allocation<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    owner: &HumanAddr,
-    viewer: Option<HumanAddr>,
-    viewing_key: Option<String>,
+    viewer: Option<ViewerInfo>,
     from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let owner_raw = deps.api.canonical_address(owner)?;
-    // determine the querier
-    let (is_owner, may_querier) = if let Some(pmt) = from_permit.as_ref() {
-        // permit tells you who is querying, so also check if he is the owner
-        (owner_raw == *pmt, from_permit)
-    // no permit, so check if a key was provided and who it matches
-    } else if let Some(key) = viewing_key {
-        // if there is a viewer
-        viewer
-            // convert to canonical
-            .map(|v| deps.api.canonical_address(&v))
-            .transpose()?
-            // only keep the viewer address if the viewing key matches
-            .filter(|v| check_key(&deps.storage, v, key.clone()).is_ok())
-            .map_or_else(
-                // no viewer or key did not match
-                || {
-                    // check if the key matches the owner, and error if it fails this last chance
-                    check_key(&deps.storage, &owner_raw, key)?;
-                    Ok((true, Some(owner_raw.clone())))
-                },
-                // we know the querier is the viewer, so check if someone put the same address for both
-                |v| Ok((v == owner_raw, Some(v))),
-            )?
-    // no permit, no viewing key, so querier is unknown
-    } else {
-        (false, None)
-    };
+    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let epoch: u64 = may_load(&deps.storage, PRESALE_EPOCH_KEY)?.unwrap_or(0);
+    let presale_store =
+        ReadonlyPrefixedStorage::multilevel(&[PREFIX_PRESALE, &epoch.to_le_bytes()], &deps.storage);
+    let allocation: u32 = may_load(&presale_store, address_raw.as_slice())?.unwrap_or(0);
+    to_binary(&QueryAnswer::PresaleAllocation { allocation })
+}
 
-    // get list of owner's tokens
-    let own_inv = Inventory::new(&deps.storage, owner_raw)?;
-    let owner_slice = own_inv.owner.as_slice();
+/// Returns QueryResult displaying the id of the wrapped token minted from an origin token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `chain_nonce` - XP.network nonce of the origin chain
+/// * `contract_address` - address of the token's contract on the origin chain
+/// * `token_id` - id of the token on the origin chain
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_token_by_origin<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    chain_nonce: u64,
+    contract_address: &str,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let idx_store = ReadonlyPrefixedStorage::new(PREFIX_ORIGIN_INDEX, &deps.storage);
+    let may_idx: Option<u32> = may_load(
+        &idx_store,
+        &origin_index_key(chain_nonce, contract_address, token_id),
+    )?;
+    let token_id = may_idx
+        .map(|idx| {
+            let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+            load::<String, _>(&map2id, &idx.to_le_bytes())
+        })
+        .transpose()?;
+    to_binary(&QueryAnswer::TokenByOrigin { token_id })
+}
 
-    // if querier is different than the owner, check if ownership is public
-    let mut known_pass = if !is_owner {
-        let config: Config = load(&deps.storage, CONFIG_KEY)?;
-        let own_priv_store = ReadonlyPrefixedStorage::new(PREFIX_OWNER_PRIV, &deps.storage);
-        let pass: bool = may_load(&own_priv_store, owner_slice)?.unwrap_or(config.owner_is_public);
-        pass
-    } else {
-        true
-    };
-    // TODO remove this when BlockInfo becomes available to queries
-    let block = if !known_pass {
-        let b: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "not used".to_string(),
-        });
-        b
-    } else {
-        BlockInfo {
-            height: 1,
-            time: 1,
-            chain_id: "not used".to_string(),
-        }
-    };
-    let exp_idx = PermissionType::ViewOwner.to_usize();
-    let global_raw = CanonicalAddr(Binary::from(b"public"));
-    let (sender, only_public) = if let Some(sdr) = may_querier.as_ref() {
-        (sdr, false)
+/// Returns QueryResult displaying whether a bridge action id has been processed
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `kind` - optional kind of bridge action.  If not provided, every kind is checked
+/// * `action_id` - the bridge action id
+pub fn query_action_consumed<S: ReadonlyStorage>(
+    storage: &S,
+    kind: Option<BridgeActionKind>,
+    action_id: Uint128,
+) -> QueryResult {
+    let kinds = if let Some(knd) = kind {
+        vec![knd]
     } else {
-        (&global_raw, true)
+        vec![
+            BridgeActionKind::Mint,
+            BridgeActionKind::Unlock,
+            BridgeActionKind::Burn,
+        ]
     };
-    let mut found_one = only_public;
-    if !known_pass {
-        // check if the ownership has been made public or the sender has ALL permission.
-        let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
-        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, owner_slice)?;
-        if let Some(list) = may_list {
-            for perm in &list {
-                if perm.address == *sender || perm.address == global_raw {
-                    if let Some(exp) = perm.expirations[exp_idx] {
-                        if !exp.is_expired(&block) {
-                            known_pass = true;
-                            break;
-                        }
-                    }
-                    // we can quit if we found both the sender and the global (or if only searching for public)
-                    if found_one {
-                        break;
-                    } else {
-                        found_one = true;
-                    }
-                }
-            }
+    let key = action_id.u128().to_le_bytes();
+    let mut consumed = false;
+    for knd in kinds.into_iter() {
+        let consumed_store = ReadonlyPrefixedStorage::new(consumed_prefix(knd), storage);
+        let found: Option<bool> = may_load(&consumed_store, &key)?;
+        if found.is_some() {
+            consumed = true;
+            break;
         }
     }
-    // if it is either the owner, ownership is public, or the querier has inventory-wide view owner permission,
-    // let them see the full count
-    if known_pass {
-        return to_binary(&QueryAnswer::NumTokens {
-            count: own_inv.info.count,
-        });
-    }
+    to_binary(&QueryAnswer::IsActionConsumed { consumed })
+}
 
-    // get the list of tokens that might have viewable ownership for this querier
-    let mut token_idxs: HashSet<u32> = HashSet::new();
-    found_one = only_public;
-    let auth_store = ReadonlyPrefixedStorage::new(PREFIX_AUTHLIST, &deps.storage);
-    let auth_list: Vec<AuthList> = may_load(&auth_store, owner_slice)?.unwrap_or_default();
-    for auth in auth_list.iter() {
-        if auth.address == *sender || auth.address == global_raw {
-            token_idxs.extend(auth.tokens[exp_idx].iter());
-            if found_one {
-                break;
-            } else {
-                found_one = true;
-            }
-        }
-    }
-    // check if the the token permissions have expired, and if not include it in the count
-    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
-    let mut count = 0u32;
-    for idx in token_idxs.into_iter() {
-        if let Some(token) = json_may_load::<Token, _>(&info_store, &idx.to_le_bytes())? {
-            found_one = only_public;
-            for perm in token.permissions.iter() {
-                if perm.address == *sender || perm.address == global_raw {
-                    if let Some(exp) = perm.expirations[exp_idx] {
-                        if !exp.is_expired(&block) {
-                            count += 1;
-                            break;
-                        }
-                    }
-                    // we can quit if we found both the sender and the global (or if only searching for public)
-                    if found_one {
-                        break;
-                    } else {
-                        found_one = true;
-                    }
-                }
-            }
-        }
-    }
+/// Returns QueryResult displaying the validators that approve bridge mints
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_validators<S: Storage, A: Api, Q: Querier>(deps: &Extern<S, A, Q>) -> QueryResult {
+    let validator_set: ValidatorSet = may_load(&deps.storage, VALIDATORS_KEY)?.unwrap_or_default();
+    to_binary(&QueryAnswer::Validators {
+        validators: validator_set
+            .validators
+            .iter()
+            .map(|v| deps.api.human_address(v))
+            .collect::<StdResult<Vec<HumanAddr>>>()?,
+        threshold: validator_set.threshold,
+    })
+}
 
-    to_binary(&QueryAnswer::NumTokens { count })
+/// Returns QueryResult displaying the mint proposals that have not been executed
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display proposals with ids after this one
+/// * `limit` - optional max number of proposals to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_mint_proposals<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let validator_set: ValidatorSet = may_load(&deps.storage, VALIDATORS_KEY)?.unwrap_or_default();
+    let pending: Vec<u64> = may_load(&deps.storage, PENDING_PROPOSALS_KEY)?.unwrap_or_default();
+    let cut_off = limit.unwrap_or(30) as usize;
+    let prop_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_PROPOSALS, &deps.storage);
+    let proposals = pending
+        .iter()
+        .filter(|id| start_after.map_or(true, |s| **id > s))
+        .take(cut_off)
+        .map(|id| {
+            let proposal: StoredMintProposal = json_load(&prop_store, &id.to_le_bytes())?;
+            Ok(MintProposal {
+                proposal_id: *id,
+                action_id: proposal.action_id,
+                token_id: proposal.mint.token_id,
+                owner: proposal.mint.owner,
+                origin: proposal.mint.origin,
+                voters: proposal
+                    .votes
+                    .iter()
+                    .map(|v| deps.api.human_address(v))
+                    .collect::<StdResult<Vec<HumanAddr>>>()?,
+                vote_count: proposal
+                    .votes
+                    .iter()
+                    .filter(|v| validator_set.validators.contains(v))
+                    .count() as u32,
+            })
+        })
+        .collect::<StdResult<Vec<MintProposal>>>()?;
+    to_binary(&QueryAnswer::MintProposals { proposals })
 }
 
-/// Returns QueryResult displaying true if the token has been unwrapped.  If sealed metadata
-/// is not enabled, all tokens are considered unwrapped
+/// Returns QueryResult displaying the bridge burns in the order they happened
 ///
 /// # Arguments
 ///
 /// * `storage` - a reference to the contract's storage
-pub fn query_is_unwrapped<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
+/// * `start_after` - optionally only display bridge burns with action ids after this one
+/// * `limit` - optional max number of bridge burns to display
+pub fn query_bridge_burns<S: ReadonlyStorage>(
+    storage: &S,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> QueryResult {
     let config: Config = load(storage, CONFIG_KEY)?;
-    let get_token_res = get_token(storage, token_id, None);
-    match get_token_res {
-        Err(err) => match err {
-            // if the token id is not found, but token supply is private, just say
-            // the token's wrapped state is the same as a newly minted token
-            StdError::GenericErr { msg, .. }
-                if !config.token_supply_is_public && msg.contains("Token ID") =>
-            {
-                to_binary(&QueryAnswer::IsUnwrapped {
-                    token_is_unwrapped: !config.sealed_metadata_is_enabled,
-                })
-            }
-            _ => Err(err),
+    let start = start_after.map_or(0, |a| a.saturating_add(1));
+    let cut_off = limit.unwrap_or(30) as u64;
+    let end = start.saturating_add(cut_off).min(config.bridge_burn_cnt);
+    let burn_store = ReadonlyPrefixedStorage::new(PREFIX_BRIDGE_BURNS, storage);
+    let burns = (start..end)
+        .map(|i| load(&burn_store, &i.to_le_bytes()))
+        .collect::<StdResult<Vec<BridgeBurn>>>()?;
+    to_binary(&QueryAnswer::BridgeBurns {
+        burn_count: config.bridge_burn_cnt,
+        burns,
+    })
+}
+
+/// Returns QueryResult displaying the tokens listed for sale
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display listings of tokens that come after this one
+/// * `limit` - optional max number of listings to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_listings<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer.clone(), from_permit.clone())?;
+    let viewer_raw = get_querier(deps, viewer, from_permit)?;
+    let mut i = start_after.map_or_else(
+        || Ok(0),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            let idx: u32 = may_load(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
+            idx.checked_add(1).ok_or_else(|| {
+                StdError::generic_err("This token was the last one the contract could mint")
+            })
         },
-        Ok((token, _idx)) => to_binary(&QueryAnswer::IsUnwrapped {
-            token_is_unwrapped: token.unwrapped,
-        }),
+    )?;
+    let cut_off = limit.unwrap_or(30);
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let listing_store = ReadonlyPrefixedStorage::new(PREFIX_LISTINGS, &deps.storage);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let mut listings = Vec::new();
+    let mut count = 0u32;
+    while count < cut_off && i < config.mint_cnt {
+        let key = i.to_le_bytes();
+        if let Some(listing) = may_load::<StoredListing, _>(&listing_store, &key)? {
+            if !listing.expires.is_expired(&block) {
+                let id: String = load(&map2id, &key)?;
+                let hide_seller = hide_owner(
+                    deps,
+                    &block,
+                    i,
+                    &id,
+                    viewer_raw.as_ref(),
+                    config.owner_is_public,
+                )?;
+                listings.push(listing.to_human(&deps.api, id, hide_seller)?);
+                // will hit gas ceiling before the count overflows
+                count += 1;
+            }
+        }
+        // i can't overflow if it was less than a u32
+        i += 1;
     }
+    to_binary(&QueryAnswer::Listings { listings })
 }
 
-/// Returns QueryResult displaying true if the token is transferable
+/// Returns QueryResult displaying a token's listing
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-pub fn query_is_transferable<S: ReadonlyStorage>(storage: &S, token_id: &str) -> QueryResult {
-    let config: Config = load(storage, CONFIG_KEY)?;
-    let get_token_res = get_token(storage, token_id, None);
-    match get_token_res {
-        Err(err) => match err {
-            // if the token id is not found, but token supply is private, just say
-            // the token is transferable
-            StdError::GenericErr { msg, .. }
-                if !config.token_supply_is_public && msg.contains("Token ID") =>
-            {
-                to_binary(&QueryAnswer::IsTransferable {
-                    token_is_transferable: true,
-                })
-            }
-            _ => Err(err),
-        },
-        Ok((token, _idx)) => to_binary(&QueryAnswer::IsTransferable {
-            token_is_transferable: token.transferable,
-        }),
-    }
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_listing_of<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let viewer_raw = get_querier(deps, viewer, from_permit)?;
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+    // a token that does not exist is displayed the same as an unlisted one, so a private
+    // token supply is not leaked
+    let listing = if let Some(idx) = may_load::<u32, _>(&map2idx, token_id.as_bytes())? {
+        let listing_store = ReadonlyPrefixedStorage::new(PREFIX_LISTINGS, &deps.storage);
+        may_load::<StoredListing, _>(&listing_store, &idx.to_le_bytes())?
+            .filter(|l| !l.expires.is_expired(&block))
+            .map(|l| {
+                let hide_seller = hide_owner(
+                    deps,
+                    &block,
+                    idx,
+                    token_id,
+                    viewer_raw.as_ref(),
+                    config.owner_is_public,
+                )?;
+                l.to_human(&deps.api, token_id.to_string(), hide_seller)
+            })
+            .transpose()?
+    } else {
+        None
+    };
+    to_binary(&QueryAnswer::ListingOf { listing })
 }
 
-/// Returns QueryResult displaying an optionally paginated list of all transactions
-/// involving a specified address, displayed in reverse chronological order
+/// Returns QueryResult displaying the auctions that have not been settled or cancelled
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
 /// * `viewer` - optional address and key making an authenticated query request
-/// * `page` - an optional page number.  If given, the most recent `page` times `page_size`
-///            transactions will be skipped
-/// * `page_size` - optional max number of transactions to display
+/// * `start_after` - optionally only display auctions of tokens that come after this one
+/// * `limit` - optional max number of auctions to display
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_transactions<S: Storage, A: Api, Q: Querier>(
+pub fn query_auctions<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
     viewer: Option<ViewerInfo>,
-    page: Option<u32>,
-    page_size: Option<u32>,
+    start_after: Option<String>,
+    limit: Option<u32>,
     from_permit: Option<CanonicalAddr>,
-) -> StdResult<Binary> {
-    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
-        StdError::generic_err("This is being called incorrectly if there is no querier address")
-    })?;
-    let (txs, total) = get_txs(
-        &deps.api,
-        &deps.storage,
-        &address_raw,
-        page.unwrap_or(0),
-        page_size.unwrap_or(30),
+) -> QueryResult {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer.clone(), from_permit.clone())?;
+    let viewer_raw = get_querier(deps, viewer, from_permit)?;
+    let mut i = start_after.map_or_else(
+        || Ok(0),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+            let idx: u32 = may_load(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
+            idx.checked_add(1).ok_or_else(|| {
+                StdError::generic_err("This token was the last one the contract could mint")
+            })
+        },
     )?;
-    to_binary(&QueryAnswer::TransactionHistory { total, txs })
+    let cut_off = limit.unwrap_or(30);
+    let config: Config = load(&deps.storage, CONFIG_KEY)?;
+    let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
+        height: 1,
+        time: 1,
+        chain_id: "not used".to_string(),
+    });
+    let auction_store = ReadonlyPrefixedStorage::new(PREFIX_AUCTIONS, &deps.storage);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let mut auctions = Vec::new();
+    let mut count = 0u32;
+    while count < cut_off && i < config.mint_cnt {
+        let key = i.to_le_bytes();
+        if let Some(auction) = may_load::<StoredAuction, _>(&auction_store, &key)? {
+            let id: String = load(&map2id, &key)?;
+            let hide_addr = hide_owner(
+                deps,
+                &block,
+                i,
+                &id,
+                viewer_raw.as_ref(),
+                config.owner_is_public,
+            )?;
+            auctions.push(auction.to_human(&deps.api, id, hide_addr)?);
+            // will hit gas ceiling before the count overflows
+            count += 1;
+        }
+        // i can't overflow if it was less than a u32
+        i += 1;
+    }
+    to_binary(&QueryAnswer::Auctions { auctions })
 }
 
-/// Returns QueryResult after verifying that the specified address has transfer approval
-/// for all the listed tokens.  A token will count as unapproved if it is non-transferable
+/// Returns QueryResult displaying a token's auction
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `token_ids` - a list of token ids to check if the address has transfer approval
+/// * `token_id` - string slice of the token id
 /// * `viewer` - optional address and key making an authenticated query request
 /// * `from_permit` - address derived from an Owner permit, if applicable
-pub fn query_verify_approval<S: Storage, A: Api, Q: Querier>(
+pub fn query_auction_of<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    token_ids: Vec<String>,
+    token_id: &str,
     viewer: Option<ViewerInfo>,
     from_permit: Option<CanonicalAddr>,
-) -> StdResult<Binary> {
-    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
-        StdError::generic_err("This is being called incorrectly if there is no querier address")
-    })?;
+) -> QueryResult {
+    let viewer_raw = get_querier(deps, viewer, from_permit)?;
     let config: Config = load(&deps.storage, CONFIG_KEY)?;
-    // TODO remove this when BlockInfo becomes available to queries
     let block: BlockInfo = may_load(&deps.storage, BLOCK_KEY)?.unwrap_or_else(|| BlockInfo {
         height: 1,
         time: 1,
         chain_id: "not used".to_string(),
     });
+    // a token that does not exist is displayed the same as one that is not auctioned, so a
+    // private token supply is not leaked
+    let auction = load_auction(&deps.storage, token_id)
+        .ok()
+        .map(|(idx, a)| {
+            let hide_addr = hide_owner(
+                deps,
+                &block,
+                idx,
+                token_id,
+                viewer_raw.as_ref(),
+                config.owner_is_public,
+            )?;
+            a.to_human(&deps.api, token_id.to_string(), hide_addr)
+        })
+        .transpose()?;
+    to_binary(&QueryAnswer::AuctionOf { auction })
+}
+
+/// Returns QueryResult displaying the address renting a token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_user_of<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let opt_viewer = prep_info.viewer_raw.as_ref();
+    let may_user = prep_info.token.active_user(&prep_info.block);
+    // the user may always view their own rental
+    if may_user.map_or(true, |u| opt_viewer != Some(&u.address)) {
+        check_permission(
+            deps,
+            &prep_info.block,
+            &prep_info.token,
+            token_id,
+            opt_viewer,
+            PermissionType::ViewOwner,
+            &mut Vec::new(),
+            &format!(
+                "You are not authorized to view the user of token {}",
+                token_id
+            ),
+            prep_info.owner_is_public,
+        )?;
+    }
+    let user = may_user
+        .map(|u| {
+            Ok(TokenUser {
+                address: deps.api.human_address(&u.address)?,
+                expires: u.expires,
+            })
+        })
+        .transpose()?;
+    to_binary(&QueryAnswer::UserOf { user })
+}
+
+/// Returns QueryResult displaying the tokens a token was derived from and the tokens derived
+/// from it, if the querier may view their metadata
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `depth` - optional number of generations to display in each direction
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_token_lineage<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    token_id: &str,
+    depth: Option<u32>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> QueryResult {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    let opt_viewer = prep_info.viewer_raw.as_ref();
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
-    for id in token_ids.into_iter() {
-        // cargo fmt creates the and_then block, but clippy doesn't like it
-        #[allow(clippy::blocks_in_if_conditions)]
-        if get_token_if_permitted(
+    check_permission(
+        deps,
+        &prep_info.block,
+        &prep_info.token,
+        token_id,
+        opt_viewer,
+        PermissionType::ViewMetadata,
+        &mut oper_for,
+        &format!(
+            "You are not authorized to view the lineage of token {}",
+            token_id
+        ),
+        prep_info.owner_is_public,
+    )?;
+    let depth = depth.unwrap_or(1);
+    let mut walk = |prefix: &[u8]| {
+        walk_lineage(
             deps,
-            &block,
-            &id,
-            Some(&address_raw),
-            PermissionType::Transfer,
+            &prep_info.block,
+            prefix,
+            prep_info.idx,
+            depth,
+            opt_viewer,
             &mut oper_for,
-            &config,
-            // the and_then forces an error if the token is not transferable
+            prep_info.owner_is_public,
         )
-        .and_then(|(t, _)| {
-            if t.transferable {
-                Ok(())
-            } else {
-                Err(StdError::unauthorized())
+    };
+    let ancestors = walk(PREFIX_LINEAGE)?;
+    let descendants = walk(PREFIX_DERIVED)?;
+    to_binary(&QueryAnswer::TokenLineage {
+        ancestors,
+        descendants,
+    })
+}
+
+/// Returns StdResult<Vec<LineageToken>>
+///
+/// walks the derivation links of a token one generation at a time, stopping at tokens that
+/// have been burned or whose metadata the querier may not view
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the BlockInfo
+/// * `prefix` - storage prefix of the links to follow
+/// * `start` - identifier index of the token whose lineage is being walked
+/// * `depth` - number of generations to walk
+/// * `viewer` - optional reference to the querier's address
+/// * `oper_for` - a mutable reference to a list of owners that gave the querier "all" permission
+/// * `owner_is_public` - true if token ownership is public for this contract
+#[allow(clippy::too_many_arguments)]
+fn walk_lineage<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    block: &BlockInfo,
+    prefix: &[u8],
+    start: u32,
+    depth: u32,
+    viewer: Option<&CanonicalAddr>,
+    oper_for: &mut Vec<CanonicalAddr>,
+    owner_is_public: bool,
+) -> StdResult<Vec<LineageToken>> {
+    let link_store = ReadonlyPrefixedStorage::new(prefix, &deps.storage);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let mut found: Vec<LineageToken> = Vec::new();
+    let mut visited: Vec<u32> = vec![start];
+    let mut frontier: Vec<u32> = vec![start];
+    for generation in 1..=depth {
+        let mut next: Vec<u32> = Vec::new();
+        for idx in frontier.iter() {
+            let links: Vec<u32> = may_load(&link_store, &idx.to_le_bytes())?.unwrap_or_default();
+            for link in links.into_iter() {
+                if visited.contains(&link) {
+                    continue;
+                }
+                visited.push(link);
+                let link_key = link.to_le_bytes();
+                // skip tokens that have been burned
+                if let Some(id) = may_load::<String, _>(&map2id, &link_key)? {
+                    let token: Token = json_load(&info_store, &link_key)?;
+                    if check_permission(
+                        deps,
+                        block,
+                        &token,
+                        &id,
+                        viewer,
+                        PermissionType::ViewMetadata,
+                        oper_for,
+                        "",
+                        owner_is_public,
+                    )
+                    .is_ok()
+                    {
+                        found.push(LineageToken {
+                            token_id: id,
+                            generation,
+                        });
+                        next.push(link);
+                    }
+                }
             }
-        })
-        .is_err()
-        {
-            return to_binary(&QueryAnswer::VerifyTransferApproval {
-                approved_for_all: false,
-                first_unapproved_token: Some(id),
-            });
         }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
     }
-    to_binary(&QueryAnswer::VerifyTransferApproval {
-        approved_for_all: true,
-        first_unapproved_token: None,
-    })
+    Ok(found)
 }
 
-/// Returns QueryResult displaying the registered code hash of the specified contract if
-/// it has registered and whether the contract implements BatchReceiveNft
+/// Returns QueryResult displaying the offers on a token the querier may view
 ///
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `contract` - a reference to the contract's address whose code hash is being requested
-pub fn query_code_hash<S: Storage, A: Api, Q: Querier>(
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_offers<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    contract: &HumanAddr,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
 ) -> QueryResult {
-    let contract_raw = deps.api.canonical_address(contract)?;
-    let store = ReadonlyPrefixedStorage::new(PREFIX_RECEIVERS, &deps.storage);
-    let may_reg_rec: Option<ReceiveRegistration> = may_load(&store, contract_raw.as_slice())?;
-    if let Some(reg_rec) = may_reg_rec {
-        return to_binary(&QueryAnswer::RegisteredCodeHash {
-            code_hash: Some(reg_rec.code_hash),
-            also_implements_batch_receive_nft: reg_rec.impl_batch,
-        });
-    }
-    to_binary(&QueryAnswer::RegisteredCodeHash {
-        code_hash: None,
-        also_implements_batch_receive_nft: false,
-    })
+    let querier = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    // a token that does not exist is displayed the same as one without offers, so a private
+    // token supply is not leaked
+    let offers = if let Ok((token, idx)) = get_token(&deps.storage, token_id, None) {
+        let offer_store = ReadonlyPrefixedStorage::new(PREFIX_OFFERS, &deps.storage);
+        let offers: Vec<StoredOffer> =
+            may_load(&offer_store, &idx.to_le_bytes())?.unwrap_or_default();
+        offers
+            .into_iter()
+            .filter(|o| token.owner == querier || o.bidder == querier)
+            .map(|o| o.to_human(&deps.api))
+            .collect::<StdResult<Vec<Offer>>>()?
+    } else {
+        Vec::new()
+    };
+    to_binary(&QueryAnswer::Offers { offers })
 }
 
 // bundled info when prepping an authenticated token query
@@ -3202,7 +7931,7 @@ fn get_owner_of_resp<S: Storage, A: Api, Q: Querier>(
 /// * `block` - a reference to the current BlockInfo
 /// * `perm_list` - slice of Permissions to search through looking for transfer approvals
 /// * `approvals` - a mutable reference to the list of approvals that should be appended
-///                 with any found in the permission list
+///   with any found in the permission list
 /// * `transfer_idx` - index into the Permission expirations that represents transfers
 /// * `include_expired` - true if the Approval list should include expired Approvals
 fn gen_cw721_approvals<A: Api>(
@@ -3331,6 +8060,41 @@ fn check_view_supply<S: Storage, A: Api, Q: Querier>(
     Ok(())
 }
 
+/// Returns StdResult<bool> which is true if the querier is not permitted to view the owner
+/// of a listed or auctioned token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the current BlockInfo
+/// * `idx` - the token's identifier index
+/// * `token_id` - token ID String slice
+/// * `viewer_raw` - optional reference to the querier's address
+/// * `owner_is_public` - true if token ownership is public for this contract
+fn hide_owner<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    block: &BlockInfo,
+    idx: u32,
+    token_id: &str,
+    viewer_raw: Option<&CanonicalAddr>,
+    owner_is_public: bool,
+) -> StdResult<bool> {
+    let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+    let token: Token = json_load(&info_store, &idx.to_le_bytes())?;
+    Ok(check_permission(
+        deps,
+        block,
+        &token,
+        token_id,
+        viewer_raw,
+        PermissionType::ViewOwner,
+        &mut Vec::new(),
+        "",
+        owner_is_public,
+    )
+    .is_err())
+}
+
 /// Returns StdResult<bool> result of validating an address' viewing key
 ///
 /// # Arguments
@@ -3449,6 +8213,14 @@ fn check_perm_core<S: Storage, A: Api, Q: Querier>(
         if token.owner == *sender {
             return Ok(());
         }
+        // a token's user may view its private metadata until the rental expires
+        if exp_idx == PermissionType::ViewMetadata.to_usize()
+            && token
+                .active_user(block)
+                .map_or(false, |u| u.address == *sender)
+        {
+            return Ok(());
+        }
         // check if the token is public or the sender has token permission.
         // Can't use find because even if the global or sender permission expired, you
         // still want to see if the other is still valid, but if we are only checking for public
@@ -3581,7 +8353,7 @@ fn get_token_if_permitted<S: Storage, A: Api, Q: Querier>(
 /// * `storage` - a reference to contract's storage
 /// * `token_id` - token id string slice
 /// * `custom_err` - optional custom error message to use if don't want to reveal that a token
-///                  does not exist
+///   does not exist
 fn get_token<S: ReadonlyStorage>(
     storage: &S,
     token_id: &str,
@@ -3622,7 +8394,53 @@ fn check_status(contract_status: u8, priority: u8) -> StdResult<()> {
 
 /// Returns StdResult<()>
 ///
-/// sets new metadata
+/// verifies that the sender may update the metadata of a token
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `token` - a reference to the token
+/// * `sender` - a reference to the address of the message sender
+/// * `custom_err` - the error message to return if the sender is not permitted
+fn check_metadata_updater<S: ReadonlyStorage>(
+    storage: &S,
+    config: &Config,
+    token: &Token,
+    sender: &CanonicalAddr,
+    custom_err: String,
+) -> StdResult<()> {
+    if !(token.owner == *sender && config.owner_may_update_metadata) {
+        let minters: Vec<CanonicalAddr> = may_load(storage, MINTERS_KEY)?.unwrap_or_default();
+        if !(minters.contains(sender) && config.minter_may_update_metadata) {
+            return Err(StdError::generic_err(custom_err));
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// returns an error if the token's metadata has been frozen
+///
+/// # Arguments
+///
+/// * `config` - a reference to the Config
+/// * `token` - a reference to the token
+/// * `token_id` - token id String slice
+fn check_not_frozen(config: &Config, token: &Token, token_id: &str) -> StdResult<()> {
+    if config.metadata_frozen || token.metadata_frozen {
+        return Err(StdError::generic_err(format!(
+            "The metadata of token {} is frozen",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<Option<Metadata>>
+///
+/// sets new metadata and returns the metadata it replaced, if there was any
 ///
 /// # Arguments
 ///
@@ -3638,7 +8456,7 @@ fn set_metadata_impl<S: Storage>(
     idx: u32,
     prefix: &[u8],
     metadata: &Metadata,
-) -> StdResult<()> {
+) -> StdResult<Option<Metadata>> {
     // do not allow the altering of sealed metadata
     if !token.unwrapped && prefix == PREFIX_PRIV_META {
         return Err(StdError::generic_err(
@@ -3646,9 +8464,45 @@ fn set_metadata_impl<S: Storage>(
         ));
     }
     enforce_metadata_field_exclusion(metadata)?;
+    if let Some(schema) = may_load::<TraitSchema, _>(storage, TRAIT_SCHEMA_KEY)? {
+        schema.validate(metadata)?;
+    }
     let mut meta_store = PrefixedStorage::new(prefix, storage);
+    let prior: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
     save(&mut meta_store, &idx.to_le_bytes(), metadata)?;
-    Ok(())
+    Ok(prior)
+}
+
+/// Returns StdResult<()>
+///
+/// appends a prior metadata version to a token's history, dropping the oldest versions if
+/// the history limit is exceeded
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `idx` - the token identifier index
+/// * `version` - the prior metadata version
+fn append_metadata_version<S: Storage>(
+    storage: &mut S,
+    config: &Config,
+    idx: u32,
+    version: StoredMetadataVersion,
+) -> StdResult<()> {
+    if config.metadata_history_limit == 0 {
+        return Ok(());
+    }
+    let mut hist_store = PrefixedStorage::new(PREFIX_META_HISTORY, storage);
+    let token_key = idx.to_le_bytes();
+    let mut history: Vec<StoredMetadataVersion> =
+        may_load(&hist_store, &token_key)?.unwrap_or_default();
+    history.push(version);
+    let excess = history
+        .len()
+        .saturating_sub(config.metadata_history_limit as usize);
+    history.drain(..excess);
+    save(&mut hist_store, &token_key, &history)
 }
 
 // enum used to return correct response from SetWhitelistedApproval
@@ -4119,7 +8973,7 @@ pub struct CacheReceiverInfo {
 /// * `msg` - a reference to the optional msg used to control ReceiveNft logic
 /// * `sender` - a reference to the address that is sending the tokens
 /// * `receivers` - a mutable reference the list of receiver contracts and their registration
-///                 info
+///   info
 #[allow(clippy::too_many_arguments)]
 fn receiver_callback_msgs<S: ReadonlyStorage>(
     storage: &S,
@@ -4237,6 +9091,51 @@ fn update_owner_inventory<S: Storage>(
     Ok(())
 }
 
+/// Returns StdResult<()>
+///
+/// logs the inventory changes caused by moving a token from one owner to another
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `inv_updates` - a mutable reference to the list of token inventories to update
+/// * `idx` - the token identifier index
+/// * `old_owner` - a reference to the previous owner's address
+/// * `recipient` - a reference to the new owner's address
+fn log_inventory_move<S: Storage>(
+    storage: &mut S,
+    inv_updates: &mut Vec<InventoryUpdate>,
+    idx: u32,
+    old_owner: &CanonicalAddr,
+    recipient: &CanonicalAddr,
+) -> StdResult<()> {
+    for addr in [recipient, old_owner].iter() {
+        let inv_upd =
+            if let Some(inv) = inv_updates.iter_mut().find(|i| i.inventory.owner == **addr) {
+                inv
+            } else {
+                let inventory = Inventory::new(storage, (*addr).clone())?;
+                let new_inv = InventoryUpdate {
+                    inventory,
+                    remove: HashSet::new(),
+                };
+                inv_updates.push(new_inv);
+                inv_updates.last_mut().ok_or_else(|| {
+                    StdError::generic_err("Just pushed an InventoryUpdate so this can not happen")
+                })?
+            };
+        // if updating the recipient's inventory
+        if inv_upd.inventory.owner == *recipient {
+            inv_upd.inventory.insert(storage, idx, false)?;
+        // else updating the old owner's inventory
+        } else {
+            inv_upd.inventory.remove(storage, idx, false)?;
+            inv_upd.remove.insert(idx);
+        }
+    }
+    Ok(())
+}
+
 /// Returns StdResult<CanonicalAddr>
 ///
 /// transfers a token, clears the token's permissions, and returns the previous owner's address
@@ -4279,6 +9178,14 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
             token_id
         )));
     }
+    if token.bridge_locked {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked by the bridge",
+            token_id
+        )));
+    }
+    check_not_auctioned(&deps.storage, idx, &token_id)?;
+    check_not_attached(&deps.storage, idx, &token_id)?;
     let old_owner = token.owner;
     // throw error if ownership would not change
     if old_owner == recipient {
@@ -4289,42 +9196,35 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
     }
     token.owner = recipient.clone();
     token.permissions.clear();
+    // a rental ends when the token moves
+    token.user = None;
 
-    let update_addrs = vec![recipient.clone(), old_owner.clone()];
     // save updated token info
     let mut info_store = PrefixedStorage::new(PREFIX_INFOS, &mut deps.storage);
     json_save(&mut info_store, &idx.to_le_bytes(), &token)?;
+    // a listing is cancelled when the token moves
+    let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+    remove(&mut listing_store, &idx.to_le_bytes());
     // log the inventory changes
-    for addr in update_addrs.into_iter() {
-        let inv_upd = if let Some(inv) = inv_updates.iter_mut().find(|i| i.inventory.owner == addr)
-        {
-            inv
-        } else {
-            let inventory = Inventory::new(&deps.storage, addr)?;
-            let new_inv = InventoryUpdate {
-                inventory,
-                remove: HashSet::new(),
-            };
-            inv_updates.push(new_inv);
-            inv_updates.last_mut().ok_or_else(|| {
-                StdError::generic_err("Just pushed an InventoryUpdate so this can not happen")
-            })?
-        };
-        // if updating the recipient's inventory
-        if inv_upd.inventory.owner == recipient {
-            inv_upd.inventory.insert(&mut deps.storage, idx, false)?;
-        // else updating the old owner's inventory
-        } else {
-            inv_upd.inventory.remove(&mut deps.storage, idx, false)?;
-            inv_upd.remove.insert(idx);
-        }
-    }
+    log_inventory_move(&mut deps.storage, inv_updates, idx, &old_owner, &recipient)?;
 
     let sndr = if old_owner == *sender {
         None
     } else {
         Some(sender.clone())
     };
+    // attached tokens move with their parent
+    move_children(
+        &mut deps.storage,
+        block,
+        config,
+        idx,
+        sndr.as_ref(),
+        &old_owner,
+        &recipient,
+        inv_updates,
+        &memo,
+    )?;
     // store the tx
     store_transfer(
         &mut deps.storage,
@@ -4339,6 +9239,110 @@ fn transfer_impl<S: Storage, A: Api, Q: Querier>(
     Ok(old_owner)
 }
 
+/// Returns StdResult<()> which is an error if the token is being auctioned
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `idx` - the token's identifier index
+/// * `token_id` - token id string slice
+fn check_not_auctioned<S: ReadonlyStorage>(storage: &S, idx: u32, token_id: &str) -> StdResult<()> {
+    let auction_store = ReadonlyPrefixedStorage::new(PREFIX_AUCTIONS, storage);
+    if may_load::<StoredAuction, _>(&auction_store, &idx.to_le_bytes())?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is being auctioned",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()> which is an error if the token is attached to a parent token
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `idx` - the token's identifier index
+/// * `token_id` - token id string slice
+fn check_not_attached<S: ReadonlyStorage>(storage: &S, idx: u32, token_id: &str) -> StdResult<()> {
+    let parent_store = ReadonlyPrefixedStorage::new(PREFIX_PARENT, storage);
+    if may_load::<u32, _>(&parent_store, &idx.to_le_bytes())?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is attached to a parent token",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// moves all the tokens attached to a parent token (and their own attached tokens) to the
+/// parent's new owner
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `config` - a mutable reference to the Config
+/// * `parent_idx` - the parent token's identifier index
+/// * `sender` - optional reference to the address that moved the parent if not the owner
+/// * `old_owner` - a reference to the previous owner's address
+/// * `recipient` - a reference to the new owner's address
+/// * `inv_updates` - a mutable reference to the list of token inventories to update
+/// * `memo` - a reference to the optional memo for the transfer txs
+#[allow(clippy::too_many_arguments)]
+fn move_children<S: Storage>(
+    storage: &mut S,
+    block: &BlockInfo,
+    config: &mut Config,
+    parent_idx: u32,
+    sender: Option<&CanonicalAddr>,
+    old_owner: &CanonicalAddr,
+    recipient: &CanonicalAddr,
+    inv_updates: &mut Vec<InventoryUpdate>,
+    memo: &Option<String>,
+) -> StdResult<()> {
+    let child_store = ReadonlyPrefixedStorage::new(PREFIX_CHILDREN, storage);
+    let children: Vec<u32> = may_load(&child_store, &parent_idx.to_le_bytes())?.unwrap_or_default();
+    for child in children.into_iter() {
+        let child_key = child.to_le_bytes();
+        let mut info_store = PrefixedStorage::new(PREFIX_INFOS, storage);
+        let mut token: Token = json_load(&info_store, &child_key)?;
+        token.owner = recipient.clone();
+        token.permissions.clear();
+        token.user = None;
+        json_save(&mut info_store, &child_key, &token)?;
+        let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, storage);
+        remove(&mut listing_store, &child_key);
+        log_inventory_move(storage, inv_updates, child, old_owner, recipient)?;
+        move_children(
+            storage,
+            block,
+            config,
+            child,
+            sender,
+            old_owner,
+            recipient,
+            inv_updates,
+            memo,
+        )?;
+        let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, storage);
+        let token_id: String = load(&map2id, &child_key)?;
+        store_transfer(
+            storage,
+            config,
+            block,
+            token_id,
+            old_owner.clone(),
+            sender.cloned(),
+            recipient.clone(),
+            memo.clone(),
+        )?;
+    }
+    Ok(())
+}
+
 // list of tokens sent from one previous owner
 pub struct SendFrom {
     // the owner's address
@@ -4435,42 +9439,96 @@ fn send_list<S: Storage, A: Api, Q: Querier>(
     Ok(messages)
 }
 
-/// Returns StdResult<()>
+// the type of burn being performed by burn_list
+pub enum BurnType<'a> {
+    // burn requested with BurnNft or BatchBurnNft
+    Standard,
+    // burn that sends the tokens to another chain
+    Bridge(&'a BridgeBurn),
+    // issuer burn of non-transferable tokens requested with RevokeCredential
+    Revoke,
+    // burn of the input tokens consumed by Compose
+    Compose,
+}
+
+/// Returns StdResult<Vec<CosmosMsg>>
 ///
-/// burns a list of tokens
+/// burns a list of tokens, and returns the messages refunding any offers on them
 ///
 /// # Arguments
 ///
 /// * `deps` - a mutable reference to Extern containing all the contract's external dependencies
-/// * `block` - a reference to the current BlockInfo
+/// * `env` - a reference to the Env of contract's environment
 /// * `config` - a mutable reference to the Config
 /// * `sender` - a reference to the message sender address
 /// * `burns` - list of burns to perform
+/// * `burn_type` - a reference to the type of burn being performed
 fn burn_list<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
-    block: &BlockInfo,
+    env: &Env,
     config: &mut Config,
     sender: &CanonicalAddr,
     burns: Vec<Burn>,
-) -> StdResult<()> {
+    burn_type: &BurnType,
+) -> StdResult<Vec<CosmosMsg>> {
+    let block = &env.block;
+    let mut messages: Vec<CosmosMsg> = Vec::new();
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
     let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
     let num_perm_types = PermissionType::ViewOwner.num_types();
     for burn in burns.into_iter() {
         for token_id in burn.token_ids.into_iter() {
-            let (token, idx) = get_token_if_permitted(
-                deps,
-                block,
-                &token_id,
-                Some(sender),
-                PermissionType::Transfer,
-                &mut oper_for,
-                config,
-            )?;
-            if !config.burn_is_enabled && token.transferable {
-                return Err(StdError::generic_err(
-                    "Burn functionality is not enabled for this token",
-                ));
+            let (token, idx) = if let BurnType::Revoke = burn_type {
+                get_issued_token(&deps.storage, config, &token_id, sender)?
+            } else {
+                get_token_if_permitted(
+                    deps,
+                    block,
+                    &token_id,
+                    Some(sender),
+                    PermissionType::Transfer,
+                    &mut oper_for,
+                    config,
+                )?
+            };
+            match burn_type {
+                BurnType::Standard => {
+                    if !config.burn_is_enabled && token.transferable {
+                        return Err(StdError::generic_err(
+                            "Burn functionality is not enabled for this token",
+                        ));
+                    }
+                }
+                // bridge burns are allowed even when burn is disabled, but only for tokens
+                // that could have been transferred
+                BurnType::Bridge(_) => {
+                    if !token.transferable {
+                        return Err(StdError::generic_err(format!(
+                            "Token ID: {} is non-transferable",
+                            token_id
+                        )));
+                    }
+                }
+                // the issuer may revoke even when burn is disabled, and get_issued_token
+                // already verified the token is non-transferable
+                BurnType::Revoke => {}
+                // composition consumes its inputs even when burn is disabled
+                BurnType::Compose => {}
+            }
+            if token.bridge_locked {
+                return Err(StdError::generic_err(format!(
+                    "Token ID: {} is locked by the bridge",
+                    token_id
+                )));
+            }
+            check_not_auctioned(&deps.storage, idx, &token_id)?;
+            check_not_attached(&deps.storage, idx, &token_id)?;
+            let child_store = ReadonlyPrefixedStorage::new(PREFIX_CHILDREN, &deps.storage);
+            if may_load::<Vec<u32>, _>(&child_store, &idx.to_le_bytes())?.is_some() {
+                return Err(StdError::generic_err(format!(
+                    "Token ID: {} has attached tokens that must be detached first",
+                    token_id
+                )));
             }
             // log the inventory change
             let inv_upd = if let Some(inv) = inv_updates
@@ -4507,12 +9565,32 @@ fn burn_list<S: Storage, A: Api, Q: Querier>(
             remove(&mut pub_store, &token_key);
             let mut priv_store = PrefixedStorage::new(PREFIX_PRIV_META, &mut deps.storage);
             remove(&mut priv_store, &token_key);
+            let mut hist_store = PrefixedStorage::new(PREFIX_META_HISTORY, &mut deps.storage);
+            remove(&mut hist_store, &token_key);
             // remove mint run info if existent
             let mut run_store = PrefixedStorage::new(PREFIX_MINT_RUN, &mut deps.storage);
             remove(&mut run_store, &token_key);
             // remove royalty info if existent
             let mut roy_store = PrefixedStorage::new(PREFIX_ROYALTY_INFO, &mut deps.storage);
             remove(&mut roy_store, &token_key);
+            // remove listing if existent
+            let mut listing_store = PrefixedStorage::new(PREFIX_LISTINGS, &mut deps.storage);
+            remove(&mut listing_store, &token_key);
+            // refund any offers
+            let mut offer_store = PrefixedStorage::new(PREFIX_OFFERS, &mut deps.storage);
+            let may_offers: Option<Vec<StoredOffer>> = may_load(&offer_store, &token_key)?;
+            if let Some(offers) = may_offers {
+                remove(&mut offer_store, &token_key);
+                messages.extend(refund_offers(&deps.api, &env.contract.address, offers)?);
+            }
+            // remove origin info if existent, so the origin token may be wrapped again
+            let mut origin_store = PrefixedStorage::new(PREFIX_ORIGIN, &mut deps.storage);
+            let may_origin: Option<OriginInfo> = may_load(&origin_store, &token_key)?;
+            if let Some(origin) = may_origin {
+                remove(&mut origin_store, &token_key);
+                let mut idx_store = PrefixedStorage::new(PREFIX_ORIGIN_INDEX, &mut deps.storage);
+                remove(&mut idx_store, &origin.index_key());
+            }
 
             let brnr = if token.owner == *sender {
                 None
@@ -4520,20 +9598,49 @@ fn burn_list<S: Storage, A: Api, Q: Querier>(
                 Some(sender.clone())
             };
             // store the tx
-            store_burn(
-                &mut deps.storage,
-                config,
-                block,
-                token_id,
-                token.owner,
-                brnr,
-                burn.memo.clone(),
-            )?;
+            match burn_type {
+                BurnType::Bridge(bridge_burn) => {
+                    store_bridge_burn(
+                        &mut deps.storage,
+                        config,
+                        block,
+                        token_id,
+                        token.owner,
+                        brnr,
+                        bridge_burn,
+                        burn.memo.clone(),
+                    )?;
+                }
+                BurnType::Revoke => {
+                    store_revoke(
+                        &mut deps.storage,
+                        config,
+                        block,
+                        token_id,
+                        token.owner,
+                        sender.clone(),
+                        burn.memo.clone(),
+                    )?;
+                }
+                // the Compose tx records the consumed inputs
+                BurnType::Compose => {}
+                BurnType::Standard => {
+                    store_burn(
+                        &mut deps.storage,
+                        config,
+                        block,
+                        token_id,
+                        token.owner,
+                        brnr,
+                        burn.memo.clone(),
+                    )?;
+                }
+            }
         }
     }
     save(&mut deps.storage, CONFIG_KEY, &config)?;
     update_owner_inventory(&mut deps.storage, &inv_updates, num_perm_types)?;
-    Ok(())
+    Ok(messages)
 }
 
 /// Returns <Vec<String>>
@@ -4557,6 +9664,44 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
     let mut inventories: Vec<Inventory> = Vec::new();
     let mut minted: Vec<String> = Vec::new();
     let default_roy: Option<StoredRoyaltyInfo> = may_load(&deps.storage, DEFAULT_ROYALTY_KEY)?;
+    // validate all the metadata against the trait schema before anything is saved
+    if let Some(schema) = may_load::<TraitSchema, _>(&deps.storage, TRAIT_SCHEMA_KEY)? {
+        for mint in mints.iter() {
+            for metadata in mint
+                .public_metadata
+                .iter()
+                .chain(mint.private_metadata.iter())
+            {
+                schema.validate(metadata)?;
+            }
+        }
+    }
+    if let Some(max) = config.max_supply {
+        let counted = if config.max_supply_includes_burned {
+            config.mint_cnt
+        } else {
+            config.token_cnt
+        };
+        if counted as u64 + mints.len() as u64 > max as u64 {
+            return Err(StdError::generic_err(format!(
+                "Minting would exceed the maximum supply of {} tokens",
+                max
+            )));
+        }
+    }
+    let mut quotas: Vec<MinterQuota> =
+        may_load(&deps.storage, MINTER_QUOTAS_KEY)?.unwrap_or_default();
+    if let Some(quota) = quotas.iter_mut().find(|q| q.minter == *sender_raw) {
+        let new_minted = quota.minted as u64 + mints.len() as u64;
+        if new_minted > quota.quota as u64 {
+            return Err(StdError::generic_err(format!(
+                "Minting would exceed the minter's quota of {} tokens",
+                quota.quota
+            )));
+        }
+        quota.minted = new_minted as u32;
+        save(&mut deps.storage, MINTER_QUOTAS_KEY, &quotas)?;
+    }
     for mint in mints.into_iter() {
         let id = mint.token_id.unwrap_or(format!("{}", config.mint_cnt));
         // check if id already exists
@@ -4585,6 +9730,9 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
             permissions: Vec::new(),
             unwrapped: !config.sealed_metadata_is_enabled,
             transferable,
+            bridge_locked: false,
+            user: None,
+            metadata_frozen: false,
         };
 
         // save new token info
@@ -4639,6 +9787,12 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
             mint_run,
             serial_number,
             quantity_minted_this_run,
+            // the minter issues non-transferable tokens and may recover or revoke them
+            issuer: if token.transferable {
+                None
+            } else {
+                Some(sender_raw.clone())
+            },
         };
         let mut run_store = PrefixedStorage::new(PREFIX_MINT_RUN, &mut deps.storage);
         save(&mut run_store, &token_key, &mint_info)?;
@@ -4653,6 +9807,20 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
                 &token_key,
             )?;
         }
+        // save the origin of a wrapped token, making sure there is only one wrapped copy
+        if let Some(origin) = mint.origin {
+            let origin_key = origin.index_key();
+            let mut idx_store = PrefixedStorage::new(PREFIX_ORIGIN_INDEX, &mut deps.storage);
+            if may_load::<u32, _>(&idx_store, &origin_key)?.is_some() {
+                return Err(StdError::generic_err(format!(
+                    "Token ID {} from contract {} on chain {} has already been minted",
+                    origin.token_id, origin.contract_address, origin.chain_nonce
+                )));
+            }
+            save(&mut idx_store, &origin_key, &config.mint_cnt)?;
+            let mut origin_store = PrefixedStorage::new(PREFIX_ORIGIN, &mut deps.storage);
+            save(&mut origin_store, &token_key, &origin)?;
+        }
         //
         //
 
@@ -4691,7 +9859,7 @@ fn mint_list<S: Storage, A: Api, Q: Querier>(
 /// * `api` - a reference to the Api used to convert human and canonical addresses
 /// * `royalty_info` - an optional reference to the RoyaltyInfo to store
 /// * `default` - an optional reference to the default StoredRoyaltyInfo to use if royalty_info is
-///               not provided
+///   not provided
 /// * `key` - the storage key (either token key or default key)
 fn store_royalties<S: Storage, A: Api>(
     storage: &mut S,
@@ -4834,6 +10002,9 @@ pub fn dossier_list<S: Storage, A: Api, Q: Querier>(
     let roy_store = ReadonlyPrefixedStorage::new(PREFIX_ROYALTY_INFO, &deps.storage);
     let run_store = ReadonlyPrefixedStorage::new(PREFIX_MINT_RUN, &deps.storage);
     let all_store = ReadonlyPrefixedStorage::new(PREFIX_ALL_PERMISSIONS, &deps.storage);
+    let parent_store = ReadonlyPrefixedStorage::new(PREFIX_PARENT, &deps.storage);
+    let child_store = ReadonlyPrefixedStorage::new(PREFIX_CHILDREN, &deps.storage);
+    let map2id = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_ID, &deps.storage);
 
     for id in token_ids.into_iter() {
         let err_msg = format!(
@@ -4969,6 +10140,31 @@ pub fn dossier_list<S: Storage, A: Api, Q: Querier>(
                     owner_inf.view_meta_exp.is_some(),
                 )
             };
+        // display the user if the viewer may see the owner or is the user
+        let user = token
+            .active_user(&block)
+            .filter(|u| owner.is_some() || opt_viewer == Some(&u.address))
+            .map(|u| {
+                Ok(TokenUser {
+                    address: deps.api.human_address(&u.address)?,
+                    expires: u.expires,
+                })
+            })
+            .transpose()?;
+        // display the attached tokens if the viewer may see the owner
+        let (parent, children) = if owner.is_some() {
+            let parent = may_load::<u32, _>(&parent_store, &token_key)?
+                .map(|p| load::<String, _>(&map2id, &p.to_le_bytes()))
+                .transpose()?;
+            let children = may_load::<Vec<u32>, _>(&child_store, &token_key)?
+                .unwrap_or_default()
+                .iter()
+                .map(|c| load::<String, _>(&map2id, &c.to_le_bytes()))
+                .collect::<StdResult<Vec<String>>>()?;
+            (parent, children)
+        } else {
+            (None, Vec::new())
+        };
         // if the viewer is the owner, display the approvals
         let (token_approvals, inventory_approvals) = opt_viewer.map_or((None, None), |v| {
             if token.owner == *v {
@@ -4989,6 +10185,11 @@ pub fn dossier_list<S: Storage, A: Api, Q: Querier>(
             mint_run_info: Some(mint_run.to_human(&deps.api, contract_creator.clone())?),
             transferable: token.transferable,
             unwrapped: token.unwrapped,
+            bridge_locked: token.bridge_locked,
+            metadata_frozen: config.metadata_frozen || token.metadata_frozen,
+            user,
+            parent,
+            children,
             display_private_metadata_error,
             owner_is_public,
             public_ownership_expiration,
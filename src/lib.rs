@@ -1,20 +1,59 @@
 //#![allow(clippy::field_reassign_with_default)]
+pub mod bridge;
+pub mod collection;
 pub mod contract;
 pub mod expiration;
 mod inventory;
+pub mod market;
 pub mod mint_run;
 pub mod msg;
+pub mod provenance;
 mod rand;
 pub mod receiver;
 pub mod royalties;
+pub mod sale;
 pub mod state;
 pub mod token;
+pub mod trait_schema;
+mod unittest_bridge;
+mod unittest_compose;
+#[allow(
+    unknown_lints,
+    clippy::bool_assert_comparison,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::search_is_some,
+    clippy::unnecessary_literal_unwrap,
+    clippy::zero_prefixed_literal
+)]
 mod unittest_handles;
+#[allow(clippy::redundant_clone)]
 mod unittest_inventory;
+mod unittest_lineage;
+mod unittest_market;
+mod unittest_metadata_history;
+#[allow(clippy::redundant_clone)]
 mod unittest_mint_run;
+mod unittest_nested;
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::redundant_clone,
+    clippy::too_many_arguments
+)]
 mod unittest_non_transferable;
+mod unittest_provenance;
+#[allow(clippy::bool_assert_comparison, clippy::redundant_clone)]
 mod unittest_queries;
+mod unittest_randomized;
+mod unittest_rental;
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::redundant_clone,
+    clippy::too_many_arguments
+)]
 mod unittest_royalties;
+mod unittest_sales;
+mod unittest_trait_schema;
 mod utils;
 mod viewing_key;
 
@@ -0,0 +1,229 @@
+use cosmwasm_std::{Api, CanonicalAddr, Coin, HumanAddr, StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::expiration::Expiration;
+use crate::royalties::StoredRoyaltyInfo;
+
+/// display of a token listed for sale
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct Listing {
+    /// id of the listed token
+    pub token_id: String,
+    /// owner that listed the token.  Can be None to keep ownership private
+    pub seller: Option<HumanAddr>,
+    /// sale price
+    pub price: Uint128,
+    /// native coin denomination of the price if it is paid in native coins
+    pub denom: Option<String>,
+    /// SNIP-20 contract the price is paid in if it is not paid in native coins
+    pub payment_token: Option<HumanAddr>,
+    /// expiration of the listing
+    pub expires: Expiration,
+}
+
+/// stored listing of a token for sale by its owner
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredListing {
+    /// owner that listed the token
+    pub seller: CanonicalAddr,
+    /// sale price
+    pub price: Uint128,
+    /// native coin denomination of the price if it is paid in native coins
+    pub denom: Option<String>,
+    /// SNIP-20 contract the price is paid in if it is not paid in native coins
+    pub payment_token: Option<CanonicalAddr>,
+    /// expiration of the listing
+    pub expires: Expiration,
+}
+
+impl StoredListing {
+    /// Returns StdResult<Listing> from creating a Listing from a StoredListing
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `token_id` - id of the listed token
+    /// * `hide_seller` - true if the seller's address should be kept hidden
+    pub fn to_human<A: Api>(
+        self,
+        api: &A,
+        token_id: String,
+        hide_seller: bool,
+    ) -> StdResult<Listing> {
+        let seller = if hide_seller {
+            None
+        } else {
+            Some(api.human_address(&self.seller)?)
+        };
+        Ok(Listing {
+            token_id,
+            seller,
+            price: self.price,
+            denom: self.denom,
+            payment_token: self
+                .payment_token
+                .map(|t| api.human_address(&t))
+                .transpose()?,
+            expires: self.expires,
+        })
+    }
+}
+
+/// display of an offer on a token
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct Offer {
+    /// address that made the offer
+    pub bidder: HumanAddr,
+    /// escrowed coins offered for the token
+    pub amount: Coin,
+    /// expiration of the offer
+    pub expires: Expiration,
+}
+
+/// stored offer on a token, with its coins held in escrow
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredOffer {
+    /// address that made the offer
+    pub bidder: CanonicalAddr,
+    /// escrowed coins offered for the token
+    pub amount: Coin,
+    /// expiration of the offer
+    pub expires: Expiration,
+}
+
+impl StoredOffer {
+    /// Returns StdResult<Offer> from creating an Offer from a StoredOffer
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn to_human<A: Api>(self, api: &A) -> StdResult<Offer> {
+        Ok(Offer {
+            bidder: api.human_address(&self.bidder)?,
+            amount: self.amount,
+            expires: self.expires,
+        })
+    }
+}
+
+/// number of seconds an auction is extended to when a bid is placed close to its end
+pub const AUCTION_EXTENSION: u64 = 600;
+
+/// display of a token's auction
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct Auction {
+    /// id of the auctioned token
+    pub token_id: String,
+    /// owner that started the auction.  Can be None to keep ownership private
+    pub seller: Option<HumanAddr>,
+    /// native coin denomination of the bids
+    pub denom: String,
+    /// minimum bid that will be accepted
+    pub reserve_price: Uint128,
+    /// time the auction ends (in seconds since 01/01/1970)
+    pub end_time: u64,
+    /// current highest bid if any
+    pub highest_bid: Option<Uint128>,
+    /// address that placed the highest bid.  Can be None to keep ownership private
+    pub highest_bidder: Option<HumanAddr>,
+}
+
+/// stored bid in an auction, with its coins held in escrow
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredBid {
+    /// address that placed the bid
+    pub bidder: CanonicalAddr,
+    /// amount of the bid
+    pub amount: Uint128,
+}
+
+/// stored auction of a token.  The token can not be transferred while it is being auctioned
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredAuction {
+    /// owner that started the auction
+    pub seller: CanonicalAddr,
+    /// native coin denomination of the bids
+    pub denom: String,
+    /// minimum bid that will be accepted
+    pub reserve_price: Uint128,
+    /// time the auction ends (in seconds since 01/01/1970)
+    pub end_time: u64,
+    /// current highest bid if any
+    pub highest_bid: Option<StoredBid>,
+}
+
+impl StoredAuction {
+    /// Returns StdResult<Auction> from creating an Auction from a StoredAuction
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `token_id` - id of the auctioned token
+    /// * `hide_addr` - true if the seller's and highest bidder's addresses should be kept hidden
+    pub fn to_human<A: Api>(
+        self,
+        api: &A,
+        token_id: String,
+        hide_addr: bool,
+    ) -> StdResult<Auction> {
+        let (seller, highest_bidder) = if hide_addr {
+            (None, None)
+        } else {
+            (
+                Some(api.human_address(&self.seller)?),
+                self.highest_bid
+                    .as_ref()
+                    .map(|b| api.human_address(&b.bidder))
+                    .transpose()?,
+            )
+        };
+        Ok(Auction {
+            token_id,
+            seller,
+            denom: self.denom,
+            reserve_price: self.reserve_price,
+            end_time: self.end_time,
+            highest_bid: self.highest_bid.map(|b| b.amount),
+            highest_bidder,
+        })
+    }
+}
+
+/// Returns StdResult<Vec<(CanonicalAddr, u128)>> listing the amounts each royalty recipient
+/// and the seller are paid from a sale
+///
+/// # Arguments
+///
+/// * `price` - sale price
+/// * `seller` - a reference to the seller's address
+/// * `royalty_info` - optional reference to the sold token's StoredRoyaltyInfo
+pub fn split_sale_price(
+    price: u128,
+    seller: &CanonicalAddr,
+    royalty_info: Option<&StoredRoyaltyInfo>,
+) -> StdResult<Vec<(CanonicalAddr, u128)>> {
+    let mut payouts: Vec<(CanonicalAddr, u128)> = Vec::new();
+    let mut remainder = price;
+    if let Some(info) = royalty_info {
+        let denominator = 10u128
+            .checked_pow(info.decimal_places_in_rates as u32)
+            .ok_or_else(|| StdError::generic_err("Royalty rate decimal places are too large"))?;
+        for royalty in info.royalties.iter() {
+            let amount = price
+                .checked_mul(royalty.rate as u128)
+                .ok_or_else(|| StdError::generic_err("Royalty calculation overflow"))?
+                / denominator;
+            remainder = remainder
+                .checked_sub(amount)
+                .ok_or_else(|| StdError::generic_err("Royalties exceed the sale price"))?;
+            if amount > 0 {
+                payouts.push((royalty.recipient.clone(), amount));
+            }
+        }
+    }
+    if remainder > 0 {
+        payouts.push((seller.clone(), remainder));
+    }
+    Ok(payouts)
+}
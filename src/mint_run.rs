@@ -44,6 +44,8 @@ pub struct StoredMintRunInfo {
     /// optional total number of NFTs minted on this run.  This is used to
     /// represent that this token is number m of n
     pub quantity_minted_this_run: Option<u32>,
+    /// address that may recover or revoke this token if it is non-transferable
+    pub issuer: Option<CanonicalAddr>,
 }
 
 impl StoredMintRunInfo {
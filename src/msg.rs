@@ -2,13 +2,18 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Binary, Coin, HumanAddr};
+use cosmwasm_std::{Binary, Coin, HumanAddr, Uint128};
 use secret_toolkit::permit::Permit;
 
+use crate::bridge::{BridgeActionKind, BridgeBurn, MintProposal, OriginInfo};
+use crate::collection::CollectionInfo;
 use crate::expiration::Expiration;
+use crate::market::{Auction, Listing, Offer};
 use crate::mint_run::{MintRunInfo, SerialNumber};
 use crate::royalties::{DisplayRoyaltyInfo, RoyaltyInfo};
-use crate::token::{Extension, Metadata};
+use crate::sale::{PaymentToken, PresaleEntry, SaleConfig};
+use crate::token::{Extension, Metadata, MetadataVersion, TokenUser};
+use crate::trait_schema::TraitSchema;
 
 /// Instantiation message
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -24,6 +29,8 @@ pub struct InitMsg {
     /// optional royalty information to use as default when RoyaltyInfo is not provided to a
     /// minting function
     pub royalty_info: Option<RoyaltyInfo>,
+    /// optional collection-level metadata
+    pub collection_info: Option<CollectionInfo>,
     /// optional privacy configuration for the contract
     pub config: Option<InitConfig>,
     /// optional callback message to execute after instantiation.  This will
@@ -70,6 +77,27 @@ pub struct InitConfig {
     /// Indicates whether burn functionality should be enabled
     /// default: False
     pub enable_burn: Option<bool>,
+    /// optional maximum number of tokens.  Burned tokens do not count against it unless
+    /// max_supply_includes_burned is true
+    /// default: None (no cap)
+    pub max_supply: Option<u32>,
+    /// indicates whether max_supply caps the number of tokens ever minted, including those
+    /// that have been burned, instead of the number of tokens that exist at one time
+    /// default: False
+    pub max_supply_includes_burned: Option<bool>,
+    /// optional SNIP-20 tokens accepted as payment for the public sale.  The contract will
+    /// register with each of them during instantiation
+    /// default: None
+    pub payment_tokens: Option<Vec<PaymentToken>>,
+    /// optional maximum number of prior metadata versions kept for each token.  The oldest
+    /// versions are dropped once the limit is reached, and 0 disables the history.  The
+    /// limit can not be more than 100
+    /// default: 10
+    pub metadata_history_limit: Option<u32>,
+    /// optional block height or time before which sealed metadata may not be revealed.  This
+    /// config value is ignored if sealed metadata is not enabled
+    /// default: None (may be revealed at any time)
+    pub reveal_not_before: Option<Expiration>,
 }
 
 impl Default for InitConfig {
@@ -82,6 +110,11 @@ impl Default for InitConfig {
             minter_may_update_metadata: Some(true),
             owner_may_update_metadata: Some(false),
             enable_burn: Some(false),
+            max_supply: None,
+            max_supply_includes_burned: Some(false),
+            payment_tokens: None,
+            metadata_history_limit: None,
+            reveal_not_before: None,
         }
     }
 }
@@ -119,6 +152,8 @@ pub enum HandleMsg {
         royalty_info: Option<RoyaltyInfo>,
         /// optionally true if the token is transferable.  Defaults to true if omitted
         transferable: Option<bool>,
+        /// optional origin of a wrapped token minted by the bridge
+        origin: Option<OriginInfo>,
         /// optional memo for the tx
         memo: Option<String>,
         /// optional message length padding
@@ -158,6 +193,27 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// mint a new token with traits randomly chosen from weighted trait tables.  Each chosen
+    /// trait is added to the attributes of either the public or private metadata.  Only
+    /// designated minters may call this
+    MintRandomized {
+        /// optional token id. if omitted, use current token index
+        token_id: Option<String>,
+        /// optional owner address. if omitted, owned by the message sender
+        owner: Option<HumanAddr>,
+        /// optional public metadata that can be seen by everyone
+        public_metadata: Option<Metadata>,
+        /// optional private metadata that can only be seen by the owner and whitelist
+        private_metadata: Option<Metadata>,
+        /// the weighted tables to choose the token's traits from
+        trait_tables: Vec<TraitTable>,
+        /// entropy used in choosing the traits
+        entropy: String,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// set the public and/or private metadata.  This can be called by either the token owner or
     /// a valid minter if they have been given this power by the appropriate config values
     SetMetadata {
@@ -185,6 +241,23 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// set the schema that the attributes of newly minted or updated metadata must follow.
+    /// Existing metadata is not revalidated.  This can only be called by the admin
+    SetTraitSchema {
+        /// the new trait schema.  If None, the existing schema will be deleted and metadata
+        /// attributes will no longer be validated
+        trait_schema: Option<TraitSchema>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the collection-level metadata.  This can only be called by the admin
+    SetCollectionInfo {
+        /// the new collection-level metadata.  If None, existing collection-level metadata
+        /// will be deleted
+        collection_info: Option<CollectionInfo>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// Reveal the private metadata of a sealed token and mark the token as having been unwrapped
     Reveal {
         /// id of the token to unwrap
@@ -192,6 +265,61 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// reveal the sealed metadata of a range of tokens, ordered by the index in which they were
+    /// minted.  Tokens that have already been unwrapped, burned, or whose metadata is frozen
+    /// are skipped.  This can only be called by the admin
+    RevealAll {
+        /// index of the first token to reveal
+        start_index: u32,
+        /// optional number of indexes to process.  Defaults to 100
+        limit: Option<u32>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// commit to the shuffled metadata of a blind mint before it is revealed.  The commitment
+    /// is the first link of a hash chain over the ordered metadata list, where each link is
+    /// sha256(sha256(JSON serialization of the entry) || next link), and the link following
+    /// the last entry is 32 zero bytes.  This can only be called by the admin, only once, and
+    /// only before any tokens are minted
+    CommitMetadata {
+        /// the 32-byte commitment
+        commitment: Binary,
+        /// number of metadata entries committed to.  These are assigned to the tokens with
+        /// mint indexes 0 through count - 1
+        count: u32,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// reveal the next chunk of committed metadata.  The chunk is verified against the
+    /// commitment and each entry is set as the public metadata of the token whose mint index
+    /// is (position in the list + offset) % count.  The offset is generated when the first
+    /// chunk is revealed, from the contract's private prng seed, the height of the commit, the
+    /// entropy of every purchase made after it, and the block of the first reveal.  Only the
+    /// prng seed is not public.  Burned tokens and tokens with frozen metadata are
+    /// skipped.  This can only be called by the admin, after all the committed tokens
+    /// have been minted
+    RevealCommittedMetadata {
+        /// the next entries of the committed metadata list
+        metadata: Vec<Metadata>,
+        /// the chain link of the entry following this chunk (32 zero bytes for the last chunk)
+        next_link: Binary,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// permanently freeze a token's metadata so that it can no longer be updated or revealed.
+    /// This can be called by either the token owner or a valid minter if they have been given
+    /// the power to update metadata by the appropriate config values
+    FreezeMetadata {
+        /// id of the token whose metadata should be frozen
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// permanently freeze the metadata of every token.  This can only be called by the admin
+    FreezeAllMetadata {
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// if a contract was instantiated to make ownership public by default, this will allow
     /// an address to make the ownership of their tokens private.  The address can still use
     /// SetGlobalApproval to make ownership public either inventory-wide or for a specific token
@@ -382,6 +510,16 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// set the maximum number of tokens a minter may mint.  Tokens minted before the quota
+    /// was first set do not count towards the quota.  Setting the quota to None removes it
+    SetMinterQuota {
+        /// address of the minter
+        minter: HumanAddr,
+        /// optional maximum number of tokens the minter may mint
+        quota: Option<u32>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// change address with administrative power
     ChangeAdmin {
         /// address with admin authority
@@ -404,6 +542,397 @@ pub enum HandleMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// lock a token in the contract's escrow while it lives on another chain.  Only the owner or
+    /// an address with transfer approval may lock a token.  A locked token can not be
+    /// transferred, sent, or burned until the bridge validator unlocks it
+    BridgeLock {
+        /// id of the token being bridged
+        token_id: String,
+        /// XP.network nonce of the destination chain
+        chain_nonce: u64,
+        /// address on the destination chain that will receive the token
+        destination_address: String,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// release a bridge-locked token back to a Secret owner.  Only the bridge validator may
+    /// unlock a token
+    BridgeUnlock {
+        /// id of the token being returned
+        token_id: String,
+        /// address that will own the token once it is unlocked
+        recipient: HumanAddr,
+        /// unique id of this bridge action.  An action id can only be processed once
+        action_id: Uint128,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// burn tokens to send them back to another chain.  Bridge burns are permitted even if
+    /// burn is not enabled for the contract.  Exactly the fee must be sent (in uscrt) with this
+    /// message, and no other coins, and it is forwarded to the bridge validator
+    BurnForBridge {
+        /// tokens being sent
+        token_ids: Vec<String>,
+        /// XP.network nonce of the destination chain
+        chain_nonce: u64,
+        /// address on the destination chain that will receive the tokens
+        destination_address: String,
+        /// bridge fee (in uscrt)
+        fee: Uint128,
+        /// unique id supplied by the relayer so a retried burn can not be processed twice.
+        /// This is unrelated to the burn_seq assigned to the burn
+        action_id: Uint128,
+        /// optional memo for the txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the address that is allowed to unlock bridge-locked tokens
+    SetBridgeValidator {
+        /// address of the bridge validator
+        address: HumanAddr,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the validators that must approve bridge mints.  Once validators are set, mints
+    /// that specify an origin, and any mint by a validator or the bridge validator, can only
+    /// be performed through ProposeMint/VoteMint.  Other minters may still mint tokens that
+    /// do not specify an origin.  Setting an empty list removes the validator requirement
+    SetValidators {
+        /// list of validator addresses
+        validators: Vec<HumanAddr>,
+        /// number of validator votes needed to execute a mint
+        threshold: u32,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// propose a bridge mint.  The proposer's vote is counted, and the mint is performed as
+    /// soon as the threshold is reached.  Only validators may propose mints, and at most 100
+    /// proposals may be pending at once
+    ProposeMint {
+        /// the mint to perform
+        mint: Mint,
+        /// unique id of this bridge action.  It is consumed when the mint is performed, and
+        /// can not be proposed again while its proposal is pending
+        action_id: Uint128,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// vote for a pending mint proposal.  The mint is performed as soon as the threshold is
+    /// reached.  Only validators may vote
+    VoteMint {
+        /// id of the proposal
+        proposal_id: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// cancel a pending mint proposal.  Only the admin may cancel proposals
+    CancelMintProposal {
+        /// id of the proposal
+        proposal_id: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the configuration of the paid public mint.  Setting it to None ends the sale
+    SetSaleConfig {
+        /// optional sale configuration
+        sale: Option<SaleConfig>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// purchase tokens from the public sale.  The price of all the tokens must be sent with
+    /// this message in the sale's denomination
+    PurchaseMint {
+        /// optional number of tokens to purchase.  Defaults to 1 if omitted
+        quantity: Option<u32>,
+        /// optional entropy mixed into the assignment of committed blind mint metadata
+        entropy: Option<String>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set the configuration of the allowlist presale.  The presale's limit_per_address is
+    /// ignored because each allowlisted address has its own allocation.  Setting it to None
+    /// ends the presale
+    SetPresaleConfig {
+        /// optional presale configuration
+        presale: Option<SaleConfig>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// replace the presale allowlist.  Large lists can be sent in chunks by following this
+    /// with AddToPresaleList
+    SetPresaleList {
+        /// allowlisted addresses and their allocations
+        entries: Vec<PresaleEntry>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// add to the presale allowlist, replacing the allocation of addresses already listed
+    AddToPresaleList {
+        /// allowlisted addresses and their allocations
+        entries: Vec<PresaleEntry>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// purchase tokens from the presale.  The price of all the tokens must be sent with this
+    /// message in the presale's denomination
+    PresaleMint {
+        /// optional number of tokens to purchase.  Defaults to 1 if omitted
+        quantity: Option<u32>,
+        /// optional entropy mixed into the assignment of committed blind mint metadata
+        entropy: Option<String>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// add SNIP-20 tokens accepted as payment for the public sale, or change the price of
+    /// tokens that are already accepted.  The contract will register with each of them
+    AddPaymentTokens {
+        /// SNIP-20 tokens and their prices
+        tokens: Vec<PaymentToken>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// list a token for sale, replacing any existing listing.  The sale price is split between
+    /// the token's royalty recipients and the seller when it is bought.  Only the token's owner
+    /// may list it, and the listing is cancelled if the token is transferred or burned.  Also
+    /// accepted as `list_token`
+    #[serde(alias = "list_token")]
+    ListForSale {
+        /// id of the token being listed
+        token_id: String,
+        /// sale price
+        price: Uint128,
+        /// native coin denomination of the price.  Required if no payment_token is given
+        denom: Option<String>,
+        /// accepted SNIP-20 payment token the price must be paid in.  Required if no denom is
+        /// given
+        payment_token: Option<HumanAddr>,
+        /// optional expiration of the listing.  Defaults to never
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// remove a token's listing
+    CancelListing {
+        /// id of the listed token
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// buy a token that is listed for a price in native coins, paying royalties from the
+    /// sent coins.  Also accepted as `buy_listed_token`
+    #[serde(alias = "buy_listed_token")]
+    Buy {
+        /// id of the token being bought
+        token_id: String,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// make an offer on a token, escrowing the sent native coin.  Any previous offer the sender
+    /// made on the token and any expired offers are refunded.  A token can have at most 30
+    /// unexpired offers, and tokens locked by the bridge can not receive offers
+    MakeOffer {
+        /// id of the token the offer is for
+        token_id: String,
+        /// optional expiration of the offer.  Defaults to never
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// withdraw an offer and refund its escrowed coin
+    WithdrawOffer {
+        /// id of the token the offer is for
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// accept an offer on a token you own.  The offered coin is split between the token's
+    /// royalty recipients and the owner, and all other offers on the token are refunded
+    AcceptOffer {
+        /// id of the token the offer is for
+        token_id: String,
+        /// address that made the offer being accepted
+        bidder: HumanAddr,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// start an auction of a token you own.  The token can not be transferred until the auction
+    /// is settled or cancelled
+    StartAuction {
+        /// id of the token being auctioned
+        token_id: String,
+        /// native coin denomination of the bids
+        denom: String,
+        /// minimum bid that will be accepted
+        reserve_price: Uint128,
+        /// time the auction ends (in seconds since 01/01/1970).  Bids placed within 10 minutes
+        /// of the end extend the auction to 10 minutes after the bid
+        end_time: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// bid on an auction by sending the bid in the auction's denomination.  The previous
+    /// highest bid is refunded
+    PlaceBid {
+        /// id of the auctioned token
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// settle an auction that has ended.  The highest bid is split between the token's royalty
+    /// recipients and the seller, and the token is transferred to the highest bidder.  Anyone
+    /// may settle an auction
+    SettleAuction {
+        /// id of the auctioned token
+        token_id: String,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// cancel an auction that has not received any bids
+    CancelAuction {
+        /// id of the auctioned token
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// rent a token you own to a user, who may view its private metadata until the rental
+    /// expires.  The user may not transfer the token, and the rental ends if the token is
+    /// transferred
+    SetUser {
+        /// id of the token being rented
+        token_id: String,
+        /// address renting the token.  Removes the current user if omitted
+        user: Option<HumanAddr>,
+        /// optional expiration of the rental.  Defaults to never
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// burn a list of input tokens and mint a new token from them.  Only designated minters
+    /// may compose, and they must own the inputs or have transfer approval for them.  The
+    /// inputs are burned even if burn has not been enabled on the contract
+    Compose {
+        /// ids of the tokens being consumed
+        inputs: Vec<String>,
+        /// the token being minted
+        output: Mint,
+        /// optional memo for the compose tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// mint tokens derived from existing tokens, such as the pieces a token is split into.
+    /// Only designated minters may derive tokens, and the lineage can be viewed with the
+    /// TokenLineage query
+    Derive {
+        /// ids of the tokens the new tokens are derived from
+        parent_token_ids: Vec<String>,
+        /// the tokens being minted
+        mints: Vec<Mint>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// attach a token you own to another token you own.  An attached token is owned by whoever
+    /// owns its parent, moves with its parent, and can not be transferred, sold, or burned on
+    /// its own until it is detached
+    AttachToParent {
+        /// id of the token being attached
+        token_id: String,
+        /// id of the token that will own it
+        parent_token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// detach a token you own from its parent token.  Tokens can not be detached while the
+    /// top-level token they are attached to is listed for sale or auctioned
+    DetachFromParent {
+        /// id of the token being detached
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// move a non-transferable token to a new owner.  Only the token's issuer may do this,
+    /// so that a credential held by a lost wallet can be restored to its holder
+    RecoverNonTransferable {
+        /// id of the non-transferable token being recovered
+        token_id: String,
+        /// address of the new owner
+        new_owner: HumanAddr,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// burn a non-transferable token.  Only the token's issuer may do this, and it is
+    /// permitted even if burn has not been enabled on the contract
+    RevokeCredential {
+        /// id of the non-transferable token being revoked
+        token_id: String,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// SNIP-20 receiver interface.  Called by an accepted SNIP-20 contract when tokens are
+    /// sent to this contract with a ReceiveMsg
+    Receive {
+        /// address that sent the SNIP-20 tokens
+        sender: HumanAddr,
+        /// owner of the SNIP-20 tokens that were sent
+        from: HumanAddr,
+        /// amount of SNIP-20 tokens sent
+        amount: Uint128,
+        /// base64 encoded ReceiveMsg
+        msg: Option<Binary>,
+        /// optional memo of the SNIP-20 transfer
+        memo: Option<String>,
+    },
+    /// send the accumulated sale proceeds (native and SNIP-20)
+    WithdrawProceeds {
+        /// optional address to send the proceeds to.  Defaults to the admin if omitted
+        recipient: Option<HumanAddr>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+}
+
+/// messages that may be sent with SNIP-20 tokens
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    /// purchase tokens from the public sale.  The SNIP-20 tokens' owner receives the purchased
+    /// tokens
+    PurchaseMint {
+        /// optional number of tokens to purchase.  Defaults to 1 if omitted
+        quantity: Option<u32>,
+        /// optional entropy mixed into the assignment of committed blind mint metadata
+        entropy: Option<String>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+    },
+    /// buy a token that is listed for a price in the sent SNIP-20 token.  The SNIP-20 tokens'
+    /// owner receives the bought token.  Also accepted as `buy_listed_token`
+    #[serde(alias = "buy_listed_token")]
+    Buy {
+        /// id of the token being bought
+        token_id: String,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+    },
 }
 
 /// permission access level
@@ -438,10 +967,35 @@ pub struct Mint {
     pub royalty_info: Option<RoyaltyInfo>,
     /// optionally true if the token is transferable.  Defaults to true if omitted
     pub transferable: Option<bool>,
+    /// optional origin of a wrapped token minted by the bridge
+    pub origin: Option<OriginInfo>,
     /// optional memo for the tx
     pub memo: Option<String>,
 }
 
+/// a table of weighted values for a randomly chosen trait
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct TraitTable {
+    /// name of the trait
+    pub trait_type: String,
+    /// optional indication of how the trait should be displayed
+    pub display_type: Option<String>,
+    /// the possible values of the trait
+    pub values: Vec<WeightedValue>,
+    /// optionally true if the chosen trait should be added to the private metadata.  Defaults
+    /// to false
+    pub private: Option<bool>,
+}
+
+/// a possible trait value and its relative chance of being chosen
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct WeightedValue {
+    /// trait value
+    pub value: String,
+    /// relative weight of this value
+    pub weight: u32,
+}
+
 /// token burn info used when doing a BatchBurnNft
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
 pub struct Burn {
@@ -500,18 +1054,48 @@ pub enum HandleAnswer {
         /// token id of the last minted clone
         last_minted: String,
     },
+    MintRandomized {
+        token_id: String,
+    },
     SetMetadata {
         status: ResponseStatus,
     },
     SetRoyaltyInfo {
         status: ResponseStatus,
     },
+    SetTraitSchema {
+        status: ResponseStatus,
+    },
+    SetCollectionInfo {
+        status: ResponseStatus,
+    },
     MakeOwnershipPrivate {
         status: ResponseStatus,
     },
     Reveal {
         status: ResponseStatus,
     },
+    RevealAll {
+        /// number of tokens revealed
+        revealed: u32,
+        /// index to start the next page from, if there are tokens remaining
+        next_index: Option<u32>,
+    },
+    CommitMetadata {
+        status: ResponseStatus,
+    },
+    RevealCommittedMetadata {
+        /// number of committed metadata entries revealed so far
+        revealed: u32,
+        /// number of committed metadata entries that remain to be revealed
+        remaining: u32,
+    },
+    FreezeMetadata {
+        status: ResponseStatus,
+    },
+    FreezeAllMetadata {
+        status: ResponseStatus,
+    },
     Approve {
         status: ResponseStatus,
     },
@@ -564,6 +1148,9 @@ pub enum HandleAnswer {
     SetMinters {
         status: ResponseStatus,
     },
+    SetMinterQuota {
+        status: ResponseStatus,
+    },
     ChangeAdmin {
         status: ResponseStatus,
     },
@@ -573,6 +1160,117 @@ pub enum HandleAnswer {
     RevokePermit {
         status: ResponseStatus,
     },
+    BridgeLock {
+        status: ResponseStatus,
+    },
+    BridgeUnlock {
+        status: ResponseStatus,
+    },
+    BurnForBridge {
+        /// sequence number assigned to this burn
+        burn_seq: u64,
+    },
+    SetBridgeValidator {
+        status: ResponseStatus,
+    },
+    SetValidators {
+        status: ResponseStatus,
+    },
+    /// mint proposal result.  If the threshold was reached, token_id is the id of the
+    /// minted token
+    ProposeMint {
+        proposal_id: u64,
+        token_id: Option<String>,
+    },
+    /// mint vote result.  If the threshold was reached, token_id is the id of the minted token
+    VoteMint {
+        proposal_id: u64,
+        token_id: Option<String>,
+    },
+    CancelMintProposal {
+        status: ResponseStatus,
+    },
+    SetSaleConfig {
+        status: ResponseStatus,
+    },
+    PurchaseMint {
+        token_ids: Vec<String>,
+    },
+    SetPresaleConfig {
+        status: ResponseStatus,
+    },
+    SetPresaleList {
+        status: ResponseStatus,
+    },
+    AddToPresaleList {
+        status: ResponseStatus,
+    },
+    PresaleMint {
+        token_ids: Vec<String>,
+    },
+    AddPaymentTokens {
+        status: ResponseStatus,
+    },
+    /// also the answer to `list_token`, which used to answer with `list_token`
+    ListForSale {
+        status: ResponseStatus,
+    },
+    CancelListing {
+        status: ResponseStatus,
+    },
+    /// also the answer to `buy_listed_token`, which used to answer with `buy_listed_token`
+    Buy {
+        status: ResponseStatus,
+    },
+    MakeOffer {
+        status: ResponseStatus,
+    },
+    WithdrawOffer {
+        status: ResponseStatus,
+    },
+    AcceptOffer {
+        status: ResponseStatus,
+    },
+    StartAuction {
+        status: ResponseStatus,
+    },
+    PlaceBid {
+        status: ResponseStatus,
+    },
+    SettleAuction {
+        status: ResponseStatus,
+    },
+    CancelAuction {
+        status: ResponseStatus,
+    },
+    SetUser {
+        status: ResponseStatus,
+    },
+    Compose {
+        token_id: String,
+    },
+    Derive {
+        token_ids: Vec<String>,
+    },
+    AttachToParent {
+        status: ResponseStatus,
+    },
+    DetachFromParent {
+        status: ResponseStatus,
+    },
+    RecoverNonTransferable {
+        status: ResponseStatus,
+    },
+    RevokeCredential {
+        status: ResponseStatus,
+    },
+    /// SNIP-20 purchase result
+    Receive {
+        token_ids: Vec<String>,
+    },
+    WithdrawProceeds {
+        status: ResponseStatus,
+    },
 }
 
 /// the address and viewing key making an authenticated query request
@@ -621,6 +1319,46 @@ pub enum TxAction {
         /// burner's address if not owner
         burner: Option<HumanAddr>,
     },
+    /// burned a token to send it to another chain
+    BridgeBurn {
+        /// previous owner
+        owner: HumanAddr,
+        /// burner's address if not owner
+        burner: Option<HumanAddr>,
+        /// sequence number of the bridge burn
+        burn_seq: u64,
+        /// XP.network nonce of the destination chain
+        chain_nonce: u64,
+        /// address on the destination chain
+        destination_address: String,
+    },
+    /// issuer moved a non-transferable token to a new owner
+    Recover {
+        /// previous owner
+        from: HumanAddr,
+        /// issuer's address
+        issuer: HumanAddr,
+        /// new owner
+        recipient: HumanAddr,
+    },
+    /// issuer burned a non-transferable token
+    Revoke {
+        /// previous owner
+        owner: HumanAddr,
+        /// issuer's address
+        issuer: HumanAddr,
+    },
+    /// burned input tokens to mint a new token
+    Compose {
+        /// owners of the input tokens
+        owners: Vec<HumanAddr>,
+        /// composing minter's address
+        composer: HumanAddr,
+        /// new token's first owner
+        recipient: HumanAddr,
+        /// ids of the burned input tokens
+        inputs: Vec<String>,
+    },
 }
 
 /// tx for display
@@ -644,10 +1382,17 @@ pub struct Tx {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    /// display the contract's name and symbol
+    /// display the contract's name, symbol, and collection-level metadata
     ContractInfo {},
+    /// display the collection-level metadata
+    CollectionInfo {},
+    /// display the schema that metadata attributes must follow
+    TraitSchema {},
     /// display the contract's configuration
     ContractConfig {},
+    /// display the commitment to blind mint metadata and the offset used to assign it, so
+    /// that the assignment can be publicly verified
+    MetadataCommitment {},
     /// display the list of authorized minters
     Minters {},
     /// display the number of tokens controlled by the contract.  The token supply must
@@ -656,6 +1401,13 @@ pub enum QueryMsg {
         /// optional address and key requesting to view the number of tokens
         viewer: Option<ViewerInfo>,
     },
+    /// display the supply cap and the number of tokens minted, burned, and that may still be
+    /// minted.  The token supply must either be public, or the querier must be an
+    /// authenticated minter
+    SupplyInfo {
+        /// optional address and key requesting to view the supply
+        viewer: Option<ViewerInfo>,
+    },
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
@@ -755,7 +1507,7 @@ pub enum QueryMsg {
         include_expired: Option<bool>,
     },
     /// displays a list of all the tokens belonging to the input owner in which the viewer
-    /// has view_owner permission
+    /// has view_owner permission.  This includes any tokens attached to the owner's tokens
     Tokens {
         owner: HumanAddr,
         /// optional address of the querier if different from the owner
@@ -822,6 +1574,131 @@ pub enum QueryMsg {
     },
     /// display the contract's creator
     ContractCreator {},
+    /// display the configuration of the paid public mint and the presale
+    SaleInfo {},
+    /// display the number of tokens an address may still purchase during the presale
+    PresaleAllocation {
+        /// address whose allocation should be displayed
+        address: HumanAddr,
+        /// viewing key
+        viewing_key: String,
+    },
+    /// display the id of the wrapped token minted from the specified origin token.  The token
+    /// supply must either be public, or the querier must be an authenticated minter
+    TokenByOrigin {
+        /// XP.network nonce of the origin chain
+        chain_nonce: u64,
+        /// address of the token's contract on the origin chain
+        contract_address: String,
+        /// id of the token on the origin chain
+        token_id: String,
+        /// optional address and key requesting to view the token
+        viewer: Option<ViewerInfo>,
+    },
+    /// display whether a bridge action id has already been processed.  Each kind of bridge
+    /// action has its own action ids, and a mint's action id is only consumed once the mint
+    /// is performed
+    IsActionConsumed {
+        /// optional kind of bridge action.  If not provided, the id is reported as consumed
+        /// if any kind of bridge action has processed it
+        kind: Option<BridgeActionKind>,
+        /// the bridge action id
+        action_id: Uint128,
+    },
+    /// display the validators that approve bridge mints and the vote threshold
+    Validators {},
+    /// display an optionally paginated list of mint proposals that have not been executed
+    /// yet, with their vote counts.  The token supply must either be public, or the querier
+    /// must be an authenticated minter
+    MintProposals {
+        /// optional address and key requesting to view the proposals
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last proposal id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of proposals to display
+        limit: Option<u32>,
+    },
+    /// display bridge burns in the order they happened, so relayers can resume from the
+    /// last burn_seq they processed
+    BridgeBurns {
+        /// paginate by providing the last burn_seq received in the previous query
+        start_after: Option<u64>,
+        /// optional number of bridge burns to display
+        limit: Option<u32>,
+    },
+    /// display the tokens listed for sale, in the order they were minted.  Expired listings
+    /// are not displayed, and sellers are only displayed to those permitted to view the
+    /// token's owner.  The token supply must either be public, or the querier must be an
+    /// authenticated minter
+    Listings {
+        /// optional address and key requesting to view the listings
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of listings to display
+        limit: Option<u32>,
+    },
+    /// display a token's listing, if it is listed and the listing has not expired.  The
+    /// seller is only displayed to those permitted to view the token's owner
+    ListingOf {
+        token_id: String,
+        /// optional address and key requesting to view the listing
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the offers on a token.  The token's owner sees every offer, and anyone else
+    /// only sees their own offer
+    Offers {
+        /// id of the token the offers are for
+        token_id: String,
+        /// address and viewing key of the querier
+        viewer: ViewerInfo,
+    },
+    /// display the auctions that have not been settled or cancelled, in the order the tokens
+    /// were minted.  Sellers and bidders are only displayed to those permitted to view the
+    /// token's owner.  The token supply must either be public, or the querier must be an
+    /// authenticated minter
+    Auctions {
+        /// optional address and key requesting to view the auctions
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of auctions to display
+        limit: Option<u32>,
+    },
+    /// display a token's auction, if it has not been settled or cancelled.  The seller and
+    /// bidder are only displayed to those permitted to view the token's owner
+    AuctionOf {
+        token_id: String,
+        /// optional address and key requesting to view the auction
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the address renting a token if the rental has not expired.  Only viewable by
+    /// the user, or by anyone permitted to view the token's owner
+    UserOf {
+        token_id: String,
+        /// optional address and key requesting to view the token user
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the tokens a token was derived from and the tokens derived from it.  Only
+    /// tokens whose private metadata the viewer may see are displayed
+    TokenLineage {
+        token_id: String,
+        /// optional number of generations to display in each direction.  Defaults to 1
+        depth: Option<u32>,
+        /// optional address and key requesting to view the lineage
+        viewer: Option<ViewerInfo>,
+    },
+    /// display a token's prior metadata versions, most recent first.  Prior private
+    /// metadata is only displayed to those permitted to view the token's private metadata
+    MetadataHistory {
+        token_id: String,
+        /// optional address and key requesting to view the history
+        viewer: Option<ViewerInfo>,
+        /// optional page to display
+        page: Option<u32>,
+        /// optional number of versions per page
+        page_size: Option<u32>,
+    },
     /// perform queries by passing permits instead of viewing keys
     WithPermit {
         /// permit used to verify querier identity
@@ -862,6 +1739,14 @@ pub struct Cw721OwnerOfResponse {
     pub approvals: Vec<Cw721Approval>,
 }
 
+/// a token related to the queried token through derivation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LineageToken {
+    pub token_id: String,
+    /// number of generations between this token and the queried token
+    pub generation: u32,
+}
+
 /// the token id and nft dossier info of a single token response in a batch query
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BatchNftDossierElement {
@@ -876,6 +1761,16 @@ pub struct BatchNftDossierElement {
     pub transferable: bool,
     /// true if this token is unwrapped (returns true if the contract does not have selaed metadata enabled)
     pub unwrapped: bool,
+    /// true if this token is held in escrow while it is bridged to another chain
+    pub bridge_locked: bool,
+    /// true if this token's metadata has been permanently frozen
+    pub metadata_frozen: bool,
+    /// address renting the token, if the rental has not expired and the owner is viewable
+    pub user: Option<TokenUser>,
+    /// id of the token this token is attached to, if the owner is viewable
+    pub parent: Option<String>,
+    /// ids of the tokens attached to this token, if the owner is viewable
+    pub children: Vec<String>,
     pub owner_is_public: bool,
     pub public_ownership_expiration: Option<Expiration>,
     pub private_metadata_is_public: bool,
@@ -890,6 +1785,14 @@ pub enum QueryAnswer {
     ContractInfo {
         name: String,
         symbol: String,
+        /// collection-level metadata, if it has been set
+        collection_info: Option<CollectionInfo>,
+    },
+    CollectionInfo {
+        collection_info: Option<CollectionInfo>,
+    },
+    TraitSchema {
+        trait_schema: Option<TraitSchema>,
     },
     ContractConfig {
         token_supply_is_public: bool,
@@ -899,6 +1802,18 @@ pub enum QueryAnswer {
         minter_may_update_metadata: bool,
         owner_may_update_metadata: bool,
         burn_is_enabled: bool,
+        /// true if the metadata of every token has been permanently frozen
+        metadata_is_frozen: bool,
+    },
+    MetadataCommitment {
+        /// the commitment, if metadata has been committed
+        commitment: Option<Binary>,
+        /// number of metadata entries committed to
+        count: u32,
+        /// offset used to assign metadata to token indexes, once the reveal has started
+        offset: Option<u32>,
+        /// number of metadata entries revealed so far
+        revealed: u32,
     },
     Minters {
         minters: Vec<HumanAddr>,
@@ -906,6 +1821,18 @@ pub enum QueryAnswer {
     NumTokens {
         count: u32,
     },
+    SupplyInfo {
+        /// maximum number of tokens, if capped
+        max_supply: Option<u32>,
+        /// true if burned tokens count against max_supply
+        max_supply_includes_burned: bool,
+        /// number of tokens ever minted
+        minted: u32,
+        /// number of tokens burned
+        burned: u32,
+        /// number of tokens that may still be minted, if capped
+        remaining: Option<u32>,
+    },
     TokenList {
         tokens: Vec<String>,
     },
@@ -948,6 +1875,14 @@ pub enum QueryAnswer {
         mint_run_info: Option<MintRunInfo>,
         transferable: bool,
         unwrapped: bool,
+        bridge_locked: bool,
+        metadata_frozen: bool,
+        /// address renting the token, if the rental has not expired and the owner is viewable
+        user: Option<TokenUser>,
+        /// id of the token this token is attached to, if the owner is viewable
+        parent: Option<String>,
+        /// ids of the tokens attached to this token, if the owner is viewable
+        children: Vec<String>,
         owner_is_public: bool,
         public_ownership_expiration: Option<Expiration>,
         private_metadata_is_public: bool,
@@ -992,6 +1927,61 @@ pub enum QueryAnswer {
     ContractCreator {
         creator: Option<HumanAddr>,
     },
+    SaleInfo {
+        sale: Option<SaleConfig>,
+        presale: Option<SaleConfig>,
+        /// SNIP-20 tokens accepted as payment for the public sale
+        payment_tokens: Vec<PaymentToken>,
+    },
+    PresaleAllocation {
+        allocation: u32,
+    },
+    TokenByOrigin {
+        /// id of the wrapped token, if one currently exists
+        token_id: Option<String>,
+    },
+    IsActionConsumed {
+        consumed: bool,
+    },
+    Validators {
+        validators: Vec<HumanAddr>,
+        threshold: u32,
+    },
+    MintProposals {
+        proposals: Vec<MintProposal>,
+    },
+    BridgeBurns {
+        /// total number of bridge burns.  This is also the burn_seq of the next bridge burn
+        burn_count: u64,
+        burns: Vec<BridgeBurn>,
+    },
+    Listings {
+        listings: Vec<Listing>,
+    },
+    ListingOf {
+        listing: Option<Listing>,
+    },
+    Offers {
+        offers: Vec<Offer>,
+    },
+    Auctions {
+        auctions: Vec<Auction>,
+    },
+    AuctionOf {
+        auction: Option<Auction>,
+    },
+    UserOf {
+        user: Option<TokenUser>,
+    },
+    TokenLineage {
+        ancestors: Vec<LineageToken>,
+        descendants: Vec<LineageToken>,
+    },
+    MetadataHistory {
+        /// total number of versions the querier may view
+        total: u64,
+        versions: Vec<MetadataVersion>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
@@ -1093,6 +2083,53 @@ pub enum QueryWithPermit {
     /// display the number of tokens controlled by the contract.  The token supply must
     /// either be public, or the querier must be an authenticated minter
     NumTokens {},
+    /// display the supply cap and the number of tokens minted, burned, and that may still be
+    /// minted.  The token supply must either be public, or the querier must be a minter
+    SupplyInfo {},
+    /// display the number of tokens the permit's signer may still purchase during the presale
+    PresaleAllocation {},
+    /// display the offers on a token.  The token's owner sees every offer, and anyone else
+    /// only sees their own offer
+    Offers { token_id: String },
+    /// display the tokens listed for sale.  The token supply must either be public, or the
+    /// querier must be a minter
+    Listings {
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of listings to display
+        limit: Option<u32>,
+    },
+    /// display a token's listing, if it is listed and the listing has not expired
+    ListingOf { token_id: String },
+    /// display the auctions that have not been settled or cancelled.  The token supply must
+    /// either be public, or the querier must be a minter
+    Auctions {
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of auctions to display
+        limit: Option<u32>,
+    },
+    /// display a token's auction, if it has not been settled or cancelled
+    AuctionOf { token_id: String },
+    /// display the address renting a token if the rental has not expired.  Only viewable by
+    /// the user, or by anyone permitted to view the token's owner
+    UserOf { token_id: String },
+    /// display the tokens a token was derived from and the tokens derived from it.  Only
+    /// tokens whose private metadata the viewer may see are displayed
+    TokenLineage {
+        token_id: String,
+        /// optional number of generations to display in each direction.  Defaults to 1
+        depth: Option<u32>,
+    },
+    /// display a token's prior metadata versions, most recent first.  Prior private
+    /// metadata is only displayed to those permitted to view the token's private metadata
+    MetadataHistory {
+        token_id: String,
+        /// optional page to display
+        page: Option<u32>,
+        /// optional number of versions per page
+        page_size: Option<u32>,
+    },
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
@@ -1102,6 +2139,24 @@ pub enum QueryWithPermit {
         /// optional number of token ids to display
         limit: Option<u32>,
     },
+    /// display the id of the wrapped token minted from the specified origin token.  The token
+    /// supply must either be public, or the querier must be an authenticated minter
+    TokenByOrigin {
+        /// XP.network nonce of the origin chain
+        chain_nonce: u64,
+        /// address of the token's contract on the origin chain
+        contract_address: String,
+        /// id of the token on the origin chain
+        token_id: String,
+    },
+    /// display an optionally paginated list of mint proposals that have not been executed
+    /// yet.  The token supply must either be public, or the querier must be a minter
+    MintProposals {
+        /// paginate by providing the last proposal id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of proposals to display
+        limit: Option<u32>,
+    },
     /// list all the approvals in place for a specified token if given the owner's permit
     TokenApprovals {
         token_id: String,
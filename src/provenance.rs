@@ -0,0 +1,82 @@
+use cosmwasm_std::{to_vec, BlockInfo, StdResult};
+use serde::{Deserialize, Serialize};
+
+use crate::rand::{sha_256, Prng};
+use crate::token::Metadata;
+
+/// stored commitment to the shuffled metadata of a blind mint
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredProvenance {
+    /// hash committing to the ordered list of metadata
+    pub commitment: Vec<u8>,
+    /// number of metadata entries committed to
+    pub count: u32,
+    /// offset used to assign metadata entries to token indexes.  Set when the first
+    /// chunk is revealed
+    pub offset: Option<u32>,
+    /// height of the block containing the commit
+    pub commit_height: u64,
+    /// entropy accumulated from purchases made after the commit
+    pub entropy: Vec<u8>,
+    /// number of metadata entries revealed so far
+    pub revealed: u32,
+    /// chain link the next revealed chunk must hash to
+    pub expected_link: Vec<u8>,
+}
+
+impl StoredProvenance {
+    /// mixes the entropy of a purchase into the accumulated entropy
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - entropy of the purchase
+    pub fn add_entropy(&mut self, input: &[u8]) {
+        let mut preimage = self.entropy.clone();
+        preimage.extend_from_slice(input);
+        self.entropy = sha_256(&preimage).to_vec();
+    }
+
+    /// Returns u32 of the assignment offset.  The accumulated entropy, the commit height and
+    /// the block of the first reveal are all public, so the contract's private prng seed is
+    /// mixed in to keep the offset unpredictable even if no purchases add entropy
+    ///
+    /// # Arguments
+    ///
+    /// * `prng_seed` - the contract's prng seed
+    /// * `block` - a reference to the BlockInfo of the first reveal
+    pub fn generate_offset(&self, prng_seed: &[u8], block: &BlockInfo) -> u32 {
+        let mut rng_entropy = self.entropy.clone();
+        rng_entropy.extend_from_slice(&self.commit_height.to_be_bytes());
+        rng_entropy.extend_from_slice(&block.height.to_be_bytes());
+        rng_entropy.extend_from_slice(&block.time.to_be_bytes());
+        let rand_bytes = Prng::new(prng_seed, &rng_entropy).rand_bytes();
+        let mut offset_bytes = [0u8; 4];
+        offset_bytes.copy_from_slice(&rand_bytes[..4]);
+        u32::from_be_bytes(offset_bytes) % self.count
+    }
+
+    /// Returns u32 of the token index a metadata entry is assigned to
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - position of the metadata entry in the committed list
+    /// * `offset` - the assignment offset
+    pub fn assigned_index(&self, position: u32, offset: u32) -> u32 {
+        ((position as u64 + offset as u64) % self.count as u64) as u32
+    }
+}
+
+/// Returns StdResult<[u8; 32]> of the chain link of a metadata entry.  Each link is the
+/// sha256 hash of the sha256 hash of the entry's JSON serialization followed by the link of
+/// the next entry in the list.  The link following the last entry is 32 zero bytes, and the
+/// commitment is the link of the first entry
+///
+/// # Arguments
+///
+/// * `metadata` - a reference to the metadata entry
+/// * `next_link` - the chain link of the next entry in the list
+pub fn chain_link(metadata: &Metadata, next_link: &[u8]) -> StdResult<[u8; 32]> {
+    let mut preimage = sha_256(&to_vec(metadata)?).to_vec();
+    preimage.extend_from_slice(next_link);
+    Ok(sha_256(&preimage))
+}
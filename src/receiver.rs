@@ -62,7 +62,7 @@ impl HandleCallback for Snip721ReceiveMsg {
 /// * `token_id` - ID String of the token that was sent
 /// * `msg` - optional msg used to control ReceiveNft logic
 /// * `callback_code_hash` - String holding the code hash of the contract that was
-///                          sent the token
+///   sent the token
 /// * `contract_addr` - address of the contract that was sent the token
 pub fn receive_nft_msg(
     sender: HumanAddr,
@@ -89,7 +89,7 @@ pub fn receive_nft_msg(
 /// * `token_ids` - list of ID Strings of the tokens that were sent
 /// * `msg` - optional msg used to control ReceiveNft logic
 /// * `callback_code_hash` - String holding the code hash of the contract that was
-///                          sent the token
+///   sent the token
 /// * `contract_addr` - address of the contract that was sent the token
 pub fn batch_receive_nft_msg(
     sender: HumanAddr,
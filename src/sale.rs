@@ -0,0 +1,134 @@
+use cosmwasm_std::{BlockInfo, CanonicalAddr, CosmosMsg, HumanAddr, StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::utils::HandleCallback;
+
+use crate::contract::BLOCK_SIZE;
+use crate::expiration::Expiration;
+
+/// configuration of a paid public mint
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct SaleConfig {
+    /// denomination of the coin accepted as payment
+    pub denom: String,
+    /// price of each token
+    pub price: Uint128,
+    /// optional maximum number of tokens a single address may purchase
+    pub limit_per_address: Option<u32>,
+    /// optional time the sale opens.  The sale opens as soon as it is set if omitted
+    pub start: Option<Expiration>,
+    /// optional time the sale closes.  The sale stays open until removed if omitted
+    pub end: Option<Expiration>,
+}
+
+/// an address allowed to purchase during the presale, and how many tokens it may purchase
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct PresaleEntry {
+    /// allowlisted address
+    pub address: HumanAddr,
+    /// number of tokens the address may purchase during the presale
+    pub allocation: u32,
+}
+
+impl SaleConfig {
+    /// Returns StdResult<()> which is an error if the sale is not open
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn check_open(&self, block: &BlockInfo) -> StdResult<()> {
+        if let Some(start) = self.start.as_ref() {
+            if !start.is_expired(block) {
+                return Err(StdError::generic_err("The sale has not started yet"));
+            }
+        }
+        if let Some(end) = self.end.as_ref() {
+            if end.is_expired(block) {
+                return Err(StdError::generic_err("The sale has ended"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// a SNIP-20 token accepted as payment for the public sale
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct PaymentToken {
+    /// address of the SNIP-20 contract
+    pub address: HumanAddr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// price of each token, in the SNIP-20's smallest denomination
+    pub price: Uint128,
+}
+
+/// stored SNIP-20 token accepted as payment for the public sale
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredPaymentToken {
+    /// address of the SNIP-20 contract
+    pub address: CanonicalAddr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// price of each token, in the SNIP-20's smallest denomination
+    pub price: Uint128,
+    /// proceeds that have not been withdrawn
+    pub proceeds: Uint128,
+}
+
+/// SNIP-20 handle messages sent by this contract
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Snip20HandleMsg {
+    /// register this contract's code hash so it will be called when sent tokens
+    RegisterReceive {
+        code_hash: String,
+        padding: Option<String>,
+    },
+    /// transfer tokens from this contract
+    Transfer {
+        recipient: HumanAddr,
+        amount: Uint128,
+        padding: Option<String>,
+    },
+}
+
+impl HandleCallback for Snip20HandleMsg {
+    const BLOCK_SIZE: usize = BLOCK_SIZE;
+}
+
+/// Returns a StdResult<CosmosMsg> used to register this contract with a SNIP-20 contract
+///
+/// # Arguments
+///
+/// * `my_code_hash` - code hash of this contract
+/// * `token` - a reference to the SNIP-20 contract being registered with
+pub fn register_receive_msg(my_code_hash: String, token: &PaymentToken) -> StdResult<CosmosMsg> {
+    let msg = Snip20HandleMsg::RegisterReceive {
+        code_hash: my_code_hash,
+        padding: None,
+    };
+    msg.to_cosmos_msg(token.code_hash.clone(), token.address.clone(), None)
+}
+
+/// Returns a StdResult<CosmosMsg> used to transfer SNIP-20 tokens from this contract
+///
+/// # Arguments
+///
+/// * `recipient` - address receiving the SNIP-20 tokens
+/// * `amount` - amount of SNIP-20 tokens to send
+/// * `callback_code_hash` - code hash of the SNIP-20 contract
+/// * `contract_addr` - address of the SNIP-20 contract
+pub fn snip20_transfer_msg(
+    recipient: HumanAddr,
+    amount: Uint128,
+    callback_code_hash: String,
+    contract_addr: HumanAddr,
+) -> StdResult<CosmosMsg> {
+    let msg = Snip20HandleMsg::Transfer {
+        recipient,
+        amount,
+        padding: None,
+    };
+    msg.to_cosmos_msg(callback_code_hash, contract_addr, None)
+}
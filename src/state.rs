@@ -10,6 +10,7 @@ use secret_toolkit::{
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+use crate::bridge::BridgeBurn;
 use crate::expiration::Expiration;
 use crate::msg::{Tx, TxAction};
 
@@ -19,6 +20,18 @@ pub const CONFIG_KEY: &[u8] = b"config";
 pub const BLOCK_KEY: &[u8] = b"blockinfo";
 /// storage key for minters
 pub const MINTERS_KEY: &[u8] = b"minters";
+/// storage key for the configuration of the paid public mint
+pub const SALE_CONFIG_KEY: &[u8] = b"saleconfig";
+/// storage key for the configuration of the allowlist presale
+pub const PRESALE_CONFIG_KEY: &[u8] = b"presaleconfig";
+/// storage key for the current presale allowlist epoch
+pub const PRESALE_EPOCH_KEY: &[u8] = b"presaleepoch";
+/// storage key for the SNIP-20 tokens accepted as payment for the public sale
+pub const PAYMENT_TOKENS_KEY: &[u8] = b"paytokens";
+/// storage key for the sale proceeds that have not been withdrawn
+pub const PROCEEDS_KEY: &[u8] = b"proceeds";
+/// storage key for minter quotas
+pub const MINTER_QUOTAS_KEY: &[u8] = b"minterquotas";
 /// storage key for this contract's address
 pub const MY_ADDRESS_KEY: &[u8] = b"myaddr";
 /// storage key for prng seed
@@ -27,6 +40,18 @@ pub const PRNG_SEED_KEY: &[u8] = b"prngseed";
 pub const CREATOR_KEY: &[u8] = b"creator";
 /// storage key for the default RoyaltyInfo to use if none is supplied when minting
 pub const DEFAULT_ROYALTY_KEY: &[u8] = b"defaultroy";
+/// storage key for the address allowed to release bridge-locked tokens
+pub const BRIDGE_VALIDATOR_KEY: &[u8] = b"bridgeval";
+/// storage key for the validator set that approves bridge mints
+pub const VALIDATORS_KEY: &[u8] = b"validators";
+/// storage key for the ids of mint proposals that have not been executed
+pub const PENDING_PROPOSALS_KEY: &[u8] = b"pendingprops";
+/// storage key for the commitment to blind mint metadata
+pub const PROVENANCE_KEY: &[u8] = b"provenance";
+/// storage key for the collection-level metadata
+pub const COLLECTION_INFO_KEY: &[u8] = b"collinfo";
+/// storage key for the schema that metadata attributes must follow
+pub const TRAIT_SCHEMA_KEY: &[u8] = b"traitschema";
 /// prefix for storage that maps ids to indices
 pub const PREFIX_MAP_TO_INDEX: &[u8] = b"map2idx";
 /// prefix for storage that maps indices to ids
@@ -57,6 +82,44 @@ pub const PREFIX_VIEW_KEY: &[u8] = b"viewkeys";
 pub const PREFIX_RECEIVERS: &[u8] = b"receivers";
 /// prefix for the storage of mint run numbers
 pub const PREFIX_MINT_RUN_NUM: &[u8] = b"runnum";
+/// prefix for the storage of bridge lock information
+pub const PREFIX_BRIDGE_LOCK: &[u8] = b"bridgelock";
+/// prefix for the storage of bridge burns
+pub const PREFIX_BRIDGE_BURNS: &[u8] = b"bridgeburns";
+/// prefix for the storage of the origin of wrapped tokens
+pub const PREFIX_ORIGIN: &[u8] = b"origin";
+/// prefix for the storage of the index of tokens by their origin
+pub const PREFIX_ORIGIN_INDEX: &[u8] = b"originidx";
+/// prefix for the storage of mint proposals
+pub const PREFIX_MINT_PROPOSALS: &[u8] = b"mintprops";
+/// prefix for the storage of mint action ids that have been processed
+pub const PREFIX_CONSUMED_MINTS: &[u8] = b"consumedmint";
+/// prefix for the storage of unlock action ids that have been processed
+pub const PREFIX_CONSUMED_UNLOCKS: &[u8] = b"consumedunlock";
+/// prefix for the storage of burn action ids that have been processed
+pub const PREFIX_CONSUMED_BURNS: &[u8] = b"consumedburn";
+/// prefix for the storage of the ids of pending mint proposals by their mint action id
+pub const PREFIX_PROPOSED_MINTS: &[u8] = b"proposedmint";
+/// prefix for the storage of the number of tokens each address has purchased
+pub const PREFIX_PURCHASES: &[u8] = b"purchases";
+/// prefix for the storage of presale allocations
+pub const PREFIX_PRESALE: &[u8] = b"presale";
+/// prefix for the storage of tokens listed for sale
+pub const PREFIX_LISTINGS: &[u8] = b"listings";
+/// prefix for the storage of offers on tokens
+pub const PREFIX_OFFERS: &[u8] = b"offers";
+/// prefix for the storage of token auctions
+pub const PREFIX_AUCTIONS: &[u8] = b"auctions";
+/// prefix for the storage of the parent of an attached token
+pub const PREFIX_PARENT: &[u8] = b"parent";
+/// prefix for the storage of the tokens attached to a parent token
+pub const PREFIX_CHILDREN: &[u8] = b"children";
+/// prefix for the storage of the tokens a derived token was derived from
+pub const PREFIX_LINEAGE: &[u8] = b"lineage";
+/// prefix for the storage of the tokens derived from a token
+pub const PREFIX_DERIVED: &[u8] = b"derived";
+/// prefix for the storage of prior versions of token metadata
+pub const PREFIX_META_HISTORY: &[u8] = b"metahist";
 /// prefix for the storage of revoked permits
 pub const PREFIX_REVOKED_PERMITS: &str = "revoke";
 
@@ -91,6 +154,21 @@ pub struct Config {
     pub owner_may_update_metadata: bool,
     /// is burn enabled
     pub burn_is_enabled: bool,
+    /// count of bridge burns.  This is also the burn_seq of the next bridge burn
+    pub bridge_burn_cnt: u64,
+    /// count of mint proposals
+    pub mint_proposal_cnt: u64,
+    /// optional maximum number of tokens
+    pub max_supply: Option<u32>,
+    /// true if max_supply caps the number of tokens ever minted instead of the number of
+    /// tokens that exist
+    pub max_supply_includes_burned: bool,
+    /// maximum number of prior metadata versions kept for each token
+    pub metadata_history_limit: u32,
+    /// true if the metadata of every token has been permanently frozen
+    pub metadata_frozen: bool,
+    /// optional time before which sealed metadata may not be revealed
+    pub reveal_not_before: Option<Expiration>,
 }
 
 /// tx type and specifics
@@ -120,6 +198,46 @@ pub enum StoredTxAction {
         /// burner's address if not owner
         burner: Option<CanonicalAddr>,
     },
+    /// burned a token to send it to another chain
+    BridgeBurn {
+        /// previous owner
+        owner: CanonicalAddr,
+        /// burner's address if not owner
+        burner: Option<CanonicalAddr>,
+        /// sequence number of the bridge burn
+        burn_seq: u64,
+        /// XP.network nonce of the destination chain
+        chain_nonce: u64,
+        /// address on the destination chain
+        destination_address: String,
+    },
+    /// issuer moved a non-transferable token to a new owner
+    Recover {
+        /// previous owner
+        from: CanonicalAddr,
+        /// issuer's address
+        issuer: CanonicalAddr,
+        /// new owner
+        recipient: CanonicalAddr,
+    },
+    /// issuer burned a non-transferable token
+    Revoke {
+        /// previous owner
+        owner: CanonicalAddr,
+        /// issuer's address
+        issuer: CanonicalAddr,
+    },
+    /// burned input tokens to mint a new token
+    Compose {
+        /// owners of the input tokens
+        owners: Vec<CanonicalAddr>,
+        /// composing minter's address
+        composer: CanonicalAddr,
+        /// new token's first owner
+        recipient: CanonicalAddr,
+        /// ids of the burned input tokens
+        inputs: Vec<String>,
+    },
 }
 
 /// tx in storage
@@ -179,6 +297,53 @@ impl StoredTx {
                     burner: bnr,
                 }
             }
+            StoredTxAction::BridgeBurn {
+                owner,
+                burner,
+                burn_seq,
+                chain_nonce,
+                destination_address,
+            } => {
+                let bnr = if let Some(b) = burner {
+                    Some(api.human_address(&b)?)
+                } else {
+                    None
+                };
+                TxAction::BridgeBurn {
+                    owner: api.human_address(&owner)?,
+                    burner: bnr,
+                    burn_seq,
+                    chain_nonce,
+                    destination_address,
+                }
+            }
+            StoredTxAction::Recover {
+                from,
+                issuer,
+                recipient,
+            } => TxAction::Recover {
+                from: api.human_address(&from)?,
+                issuer: api.human_address(&issuer)?,
+                recipient: api.human_address(&recipient)?,
+            },
+            StoredTxAction::Revoke { owner, issuer } => TxAction::Revoke {
+                owner: api.human_address(&owner)?,
+                issuer: api.human_address(&issuer)?,
+            },
+            StoredTxAction::Compose {
+                owners,
+                composer,
+                recipient,
+                inputs,
+            } => TxAction::Compose {
+                owners: owners
+                    .iter()
+                    .map(|o| api.human_address(o))
+                    .collect::<StdResult<Vec<_>>>()?,
+                composer: api.human_address(&composer)?,
+                recipient: api.human_address(&recipient)?,
+                inputs,
+            },
         };
         let tx = Tx {
             tx_id: self.tx_id,
@@ -331,6 +496,213 @@ pub fn store_burn<S: Storage>(
     Ok(())
 }
 
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - token id being burnt
+/// * `owner` - the previous owner's address
+/// * `burner` - optional address that burnt the token
+/// * `bridge_burn` - a reference to the BridgeBurn this token is part of
+/// * `memo` - optional memo for the tx
+#[allow(clippy::too_many_arguments)]
+pub fn store_bridge_burn<S: Storage>(
+    storage: &mut S,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    owner: CanonicalAddr,
+    burner: Option<CanonicalAddr>,
+    bridge_burn: &BridgeBurn,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let action = StoredTxAction::BridgeBurn {
+        owner,
+        burner,
+        burn_seq: bridge_burn.burn_seq,
+        chain_nonce: bridge_burn.chain_nonce,
+        destination_address: bridge_burn.destination_address.clone(),
+    };
+    let tx = StoredTx {
+        tx_id: config.tx_cnt,
+        block_height: block.height,
+        block_time: block.time,
+        token_id,
+        action,
+        memo,
+    };
+    let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
+    json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
+    if let StoredTxAction::BridgeBurn { owner, burner, .. } = tx.action {
+        append_tx_for_addr(storage, config.tx_cnt, &owner)?;
+        if let Some(bnr) = burner.as_ref() {
+            append_tx_for_addr(storage, config.tx_cnt, bnr)?;
+        }
+    }
+    config.tx_cnt += 1;
+    Ok(())
+}
+
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - token id being recovered
+/// * `from` - the previous owner's address
+/// * `issuer` - the issuer's address
+/// * `recipient` - the new owner's address
+/// * `memo` - optional memo for the tx
+#[allow(clippy::too_many_arguments)]
+pub fn store_recover<S: Storage>(
+    storage: &mut S,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    from: CanonicalAddr,
+    issuer: CanonicalAddr,
+    recipient: CanonicalAddr,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let action = StoredTxAction::Recover {
+        from,
+        issuer,
+        recipient,
+    };
+    let tx = StoredTx {
+        tx_id: config.tx_cnt,
+        block_height: block.height,
+        block_time: block.time,
+        token_id,
+        action,
+        memo,
+    };
+    let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
+    json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
+    if let StoredTxAction::Recover {
+        from,
+        issuer,
+        recipient,
+    } = tx.action
+    {
+        append_tx_for_addr(storage, config.tx_cnt, &from)?;
+        append_tx_for_addr(storage, config.tx_cnt, &recipient)?;
+        if issuer != from && issuer != recipient {
+            append_tx_for_addr(storage, config.tx_cnt, &issuer)?;
+        }
+    }
+    config.tx_cnt += 1;
+    Ok(())
+}
+
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - token id being revoked
+/// * `owner` - the previous owner's address
+/// * `issuer` - the issuer's address
+/// * `memo` - optional memo for the tx
+pub fn store_revoke<S: Storage>(
+    storage: &mut S,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    owner: CanonicalAddr,
+    issuer: CanonicalAddr,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let action = StoredTxAction::Revoke { owner, issuer };
+    let tx = StoredTx {
+        tx_id: config.tx_cnt,
+        block_height: block.height,
+        block_time: block.time,
+        token_id,
+        action,
+        memo,
+    };
+    let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
+    json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
+    if let StoredTxAction::Revoke { owner, issuer } = tx.action {
+        append_tx_for_addr(storage, config.tx_cnt, &owner)?;
+        if issuer != owner {
+            append_tx_for_addr(storage, config.tx_cnt, &issuer)?;
+        }
+    }
+    config.tx_cnt += 1;
+    Ok(())
+}
+
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - id of the token that was composed
+/// * `owners` - the owners of the input tokens
+/// * `composer` - the composing minter's address
+/// * `recipient` - the new token's owner
+/// * `inputs` - ids of the burned input tokens
+/// * `memo` - optional memo for the tx
+#[allow(clippy::too_many_arguments)]
+pub fn store_compose<S: Storage>(
+    storage: &mut S,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    owners: Vec<CanonicalAddr>,
+    composer: CanonicalAddr,
+    recipient: CanonicalAddr,
+    inputs: Vec<String>,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let action = StoredTxAction::Compose {
+        owners,
+        composer,
+        recipient,
+        inputs,
+    };
+    let tx = StoredTx {
+        tx_id: config.tx_cnt,
+        block_height: block.height,
+        block_time: block.time,
+        token_id,
+        action,
+        memo,
+    };
+    let mut tx_store = PrefixedStorage::new(PREFIX_TXS, storage);
+    json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
+    if let StoredTxAction::Compose {
+        mut owners,
+        composer,
+        recipient,
+        ..
+    } = tx.action
+    {
+        owners.push(composer);
+        owners.push(recipient);
+        let mut logged: Vec<CanonicalAddr> = Vec::new();
+        for addr in owners.into_iter() {
+            if !logged.contains(&addr) {
+                append_tx_for_addr(storage, config.tx_cnt, &addr)?;
+                logged.push(addr);
+            }
+        }
+    }
+    config.tx_cnt += 1;
+    Ok(())
+}
+
 /// Returns StdResult<()> after saving tx id
 ///
 /// # Arguments
@@ -438,6 +810,17 @@ pub struct AuthList {
     pub tokens: [Vec<u32>; 3],
 }
 
+/// the number of tokens a minter may mint
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MinterQuota {
+    /// the minter's address
+    pub minter: CanonicalAddr,
+    /// maximum number of tokens the minter may mint
+    pub quota: u32,
+    /// number of tokens the minter has minted since the quota was set
+    pub minted: u32,
+}
+
 /// a contract's code hash and whether they implement BatchReceiveNft
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReceiveRegistration {
@@ -1,8 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::CanonicalAddr;
+use cosmwasm_std::{Api, BlockInfo, CanonicalAddr, HumanAddr, StdResult};
 
+use crate::expiration::Expiration;
 use crate::state::Permission;
 
 /// token
@@ -17,6 +18,100 @@ pub struct Token {
     pub unwrapped: bool,
     /// true if this token is transferable
     pub transferable: bool,
+    /// true if this token is held in the contract's escrow while it is bridged to another chain
+    #[serde(default)]
+    pub bridge_locked: bool,
+    /// address renting this token, which may view its private metadata until the rental
+    /// expires
+    #[serde(default)]
+    pub user: Option<StoredUser>,
+    /// true if this token's metadata has been permanently frozen
+    #[serde(default)]
+    pub metadata_frozen: bool,
+}
+
+impl Token {
+    /// Returns Option<&StoredUser> of the token's user if the rental has not expired
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn active_user(&self, block: &BlockInfo) -> Option<&StoredUser> {
+        self.user.as_ref().filter(|u| !u.expires.is_expired(block))
+    }
+}
+
+/// stored address with time-bound usage rights to a token
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredUser {
+    /// address renting the token
+    pub address: CanonicalAddr,
+    /// expiration of the rental
+    pub expires: Expiration,
+}
+
+/// display of the address with time-bound usage rights to a token
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct TokenUser {
+    /// address renting the token
+    pub address: HumanAddr,
+    /// expiration of the rental
+    pub expires: Expiration,
+}
+
+/// stored copy of a token's metadata from before an update
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredMetadataVersion {
+    /// public metadata before the update, if the update replaced it
+    pub public_metadata: Option<Metadata>,
+    /// private metadata before the update, if the update replaced it
+    pub private_metadata: Option<Metadata>,
+    /// address that made the update
+    pub updater: CanonicalAddr,
+    /// the block containing the update
+    pub block_height: u64,
+    /// the time (in seconds since 01/01/1970) of the block containing the update
+    pub block_time: u64,
+}
+
+impl StoredMetadataVersion {
+    /// Returns StdResult<MetadataVersion> from converting a StoredMetadataVersion to a
+    /// displayable MetadataVersion
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `hide_updater` - true if the updater should not be displayed
+    pub fn into_human<A: Api>(self, api: &A, hide_updater: bool) -> StdResult<MetadataVersion> {
+        let updater = if hide_updater {
+            None
+        } else {
+            Some(api.human_address(&self.updater)?)
+        };
+        Ok(MetadataVersion {
+            public_metadata: self.public_metadata,
+            private_metadata: self.private_metadata,
+            updater,
+            block_height: self.block_height,
+            block_time: self.block_time,
+        })
+    }
+}
+
+/// display of a token's metadata from before an update
+#[derive(Serialize, Deserialize, JsonSchema, Clone, PartialEq, Debug)]
+pub struct MetadataVersion {
+    /// public metadata before the update, if the update replaced it
+    pub public_metadata: Option<Metadata>,
+    /// private metadata before the update, if the update replaced it and the querier may
+    /// view the token's private metadata
+    pub private_metadata: Option<Metadata>,
+    /// address that made the update, if the querier may view the token's private metadata
+    pub updater: Option<HumanAddr>,
+    /// the block containing the update
+    pub block_height: u64,
+    /// the time (in seconds since 01/01/1970) of the block containing the update
+    pub block_time: u64,
 }
 
 /// token metadata
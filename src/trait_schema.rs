@@ -0,0 +1,196 @@
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::token::{Metadata, Trait};
+
+/// type of value a trait may have.  Numbers are integers, and dates are timestamps in
+/// seconds since 01/01/1970
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TraitValueType {
+    String,
+    Number,
+    Date,
+}
+
+/// rules for a single trait type
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct TraitRule {
+    /// name of the trait
+    pub trait_type: String,
+    /// type of the trait's value
+    pub value_type: TraitValueType,
+    /// optional minimum of the value and max_value of a number or date trait
+    pub min: Option<i64>,
+    /// optional maximum of the value and max_value of a number or date trait
+    pub max: Option<i64>,
+    /// optionally true if every metadata extension must include this trait.  Defaults to false
+    pub required: Option<bool>,
+}
+
+/// schema that the attributes of on-chain metadata must follow.  Only traits listed in the
+/// schema are allowed.  Metadata without an extension is only accepted if the schema does not
+/// require any traits
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct TraitSchema {
+    /// rules for each allowed trait type
+    pub traits: Vec<TraitRule>,
+}
+
+impl TraitSchema {
+    /// Returns StdResult<()> after verifying the schema itself is consistent
+    pub fn check(&self) -> StdResult<()> {
+        for (i, rule) in self.traits.iter().enumerate() {
+            if self.traits[..i]
+                .iter()
+                .any(|r| r.trait_type == rule.trait_type)
+            {
+                return Err(StdError::generic_err(format!(
+                    "Trait schema lists trait type {} more than once",
+                    rule.trait_type
+                )));
+            }
+            if rule.value_type == TraitValueType::String
+                && (rule.min.is_some() || rule.max.is_some())
+            {
+                return Err(StdError::generic_err(format!(
+                    "Trait {} is a string and can not have a range",
+                    rule.trait_type
+                )));
+            }
+            if let (Some(min), Some(max)) = (rule.min, rule.max) {
+                if min > max {
+                    return Err(StdError::generic_err(format!(
+                        "Trait {} has a minimum greater than its maximum",
+                        rule.trait_type
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()> after verifying the metadata's attributes follow the schema
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - a reference to the Metadata being validated
+    pub fn validate(&self, metadata: &Metadata) -> StdResult<()> {
+        let ext = match metadata.extension.as_ref() {
+            Some(e) => e,
+            None => {
+                // off-chain metadata can not be checked for the required traits
+                return match self.traits.iter().find(|r| r.required.unwrap_or(false)) {
+                    Some(rule) => Err(StdError::generic_err(format!(
+                        "Metadata must have an extension with the required trait {}",
+                        rule.trait_type
+                    ))),
+                    None => Ok(()),
+                };
+            }
+        };
+        let empty = Vec::new();
+        let attributes = ext.attributes.as_ref().unwrap_or(&empty);
+        for attribute in attributes.iter() {
+            let trait_type = attribute
+                .trait_type
+                .as_deref()
+                .ok_or_else(|| StdError::generic_err("Every attribute must have a trait_type"))?;
+            let rule = self
+                .traits
+                .iter()
+                .find(|r| r.trait_type == trait_type)
+                .ok_or_else(|| {
+                    StdError::generic_err(format!(
+                        "Trait type {} is not allowed by the trait schema",
+                        trait_type
+                    ))
+                })?;
+            rule.validate(attribute)?;
+        }
+        for rule in self.traits.iter().filter(|r| r.required.unwrap_or(false)) {
+            if !attributes
+                .iter()
+                .any(|a| a.trait_type.as_deref() == Some(rule.trait_type.as_str()))
+            {
+                return Err(StdError::generic_err(format!(
+                    "Required trait {} is missing",
+                    rule.trait_type
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TraitRule {
+    /// Returns StdResult<()> after verifying an attribute follows this rule
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - a reference to the Trait being validated
+    fn validate(&self, attribute: &Trait) -> StdResult<()> {
+        if self.value_type == TraitValueType::String {
+            if attribute.max_value.is_some() {
+                return Err(StdError::generic_err(format!(
+                    "Trait {} is a string and can not have a max_value",
+                    self.trait_type
+                )));
+            }
+            return Ok(());
+        }
+        let value = self.parse("value", &attribute.value)?;
+        if let Some(max_value) = attribute.max_value.as_ref() {
+            let max_value = self.parse("max_value", max_value)?;
+            if value > max_value {
+                return Err(StdError::generic_err(format!(
+                    "Trait {} value {} exceeds its max_value {}",
+                    self.trait_type, value, max_value
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<i64> from parsing a number or date field and checking its range
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - name of the field being parsed
+    /// * `raw` - string slice of the field's value
+    fn parse(&self, field: &str, raw: &str) -> StdResult<i64> {
+        let parsed = raw
+            .parse::<i64>()
+            .ok()
+            .filter(|n| self.value_type == TraitValueType::Number || *n >= 0);
+        let number = parsed.ok_or_else(|| {
+            let expected = if self.value_type == TraitValueType::Date {
+                "a timestamp in seconds"
+            } else {
+                "an integer"
+            };
+            StdError::generic_err(format!(
+                "Trait {} {} {} is not {}",
+                self.trait_type, field, raw, expected
+            ))
+        })?;
+        if let Some(min) = self.min {
+            if number < min {
+                return Err(StdError::generic_err(format!(
+                    "Trait {} {} {} is below the minimum of {}",
+                    self.trait_type, field, number, min
+                )));
+            }
+        }
+        if let Some(max) = self.max {
+            if number > max {
+                return Err(StdError::generic_err(format!(
+                    "Trait {} {} {} is above the maximum of {}",
+                    self.trait_type, field, number, max
+                )));
+            }
+        }
+        Ok(number)
+    }
+}
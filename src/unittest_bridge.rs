@@ -0,0 +1,1496 @@
+#[cfg(test)]
+mod tests {
+    use crate::bridge::{BridgeActionKind, BridgeBurn, MintProposal, OriginInfo, StoredBridgeLock};
+    use crate::contract::{handle, init, query, MAX_PENDING_PROPOSALS};
+    use crate::msg::{
+        HandleAnswer, HandleMsg, InitConfig, InitMsg, Mint, QueryAnswer, QueryMsg, TxAction,
+        ViewerInfo,
+    };
+    use crate::state::{
+        json_load, load, may_load, PREFIX_BRIDGE_LOCK, PREFIX_INFOS, PREFIX_MAP_TO_INDEX,
+        PREFIX_ORIGIN,
+    };
+    use crate::token::Token;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Api, BankMsg, Binary, Coin, CosmosMsg, Extern, HumanAddr, InitResponse,
+        StdError, StdResult, Uint128,
+    };
+    use cosmwasm_storage::ReadonlyPrefixedStorage;
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_with_config(
+        public_token_supply: bool,
+        public_owner: bool,
+        enable_sealed_metadata: bool,
+        unwrapped_metadata_is_private: bool,
+        minter_may_update_metadata: bool,
+        owner_may_update_metadata: bool,
+        enable_burn: bool,
+    ) -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+
+        let env = mock_env("instantiator", &[]);
+        let init_config: InitConfig = from_binary(&Binary::from(
+            format!(
+                "{{\"public_token_supply\":{},
+            \"public_owner\":{},
+            \"enable_sealed_metadata\":{},
+            \"unwrapped_metadata_is_private\":{},
+            \"minter_may_update_metadata\":{},
+            \"owner_may_update_metadata\":{},
+            \"enable_burn\":{}}}",
+                public_token_supply,
+                public_owner,
+                enable_sealed_metadata,
+                unwrapped_metadata_is_private,
+                minter_may_update_metadata,
+                owner_may_update_metadata,
+                enable_burn,
+            )
+            .as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn get_token(deps: &Extern<MockStorage, MockApi, MockQuerier>, token_id: &str) -> Token {
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: u32 = load(&map2idx, token_id.as_bytes()).unwrap();
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        json_load(&info_store, &idx.to_le_bytes()).unwrap()
+    }
+
+    // test locking a token in escrow and releasing it back to a Secret owner
+    #[test]
+    fn test_bridge_lock_unlock() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let alice_raw = deps.api.canonical_address(&alice).unwrap();
+        let bob = HumanAddr("bob".to_string());
+        let bob_raw = deps.api.canonical_address(&bob).unwrap();
+        let contract_raw = deps
+            .api
+            .canonical_address(&HumanAddr(MOCK_CONTRACT_ADDR.to_string()))
+            .unwrap();
+
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test locking a token you do not own
+        let handle_msg = HandleMsg::BridgeLock {
+            token_id: "NFT1".to_string(),
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test owner locking the token
+        let handle_msg = HandleMsg::BridgeLock {
+            token_id: "NFT1".to_string(),
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            memo: Some("to bsc".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let token = get_token(&deps, "NFT1");
+        assert_eq!(token.owner, contract_raw);
+        assert!(token.bridge_locked);
+        let lock_store = ReadonlyPrefixedStorage::new(PREFIX_BRIDGE_LOCK, &deps.storage);
+        let lock: StoredBridgeLock = load(&lock_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(lock.previous_owner, alice_raw);
+        assert_eq!(lock.chain_nonce, 4);
+        assert_eq!(lock.destination_address, "0xdeadbeef".to_string());
+
+        // verify the dossier shows the token is locked
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                owner,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                ..
+            } => {
+                assert_eq!(owner, Some(HumanAddr(MOCK_CONTRACT_ADDR.to_string())));
+                assert!(bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the previous owner can no longer transfer or burn it
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: bob.clone(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test unlock when there is no bridge validator
+        let handle_msg = HandleMsg::BridgeUnlock {
+            token_id: "NFT1".to_string(),
+            recipient: bob.clone(),
+            action_id: Uint128(1),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only the bridge validator may unlock bridged tokens"));
+
+        // test non-admin setting the validator
+        let handle_msg = HandleMsg::SetBridgeValidator {
+            address: HumanAddr("validator".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        let handle_msg = HandleMsg::SetBridgeValidator {
+            address: HumanAddr("validator".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test non-validator unlock
+        let handle_msg = HandleMsg::BridgeUnlock {
+            token_id: "NFT1".to_string(),
+            recipient: bob.clone(),
+            action_id: Uint128(2),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only the bridge validator may unlock bridged tokens"));
+
+        // test validator unlock
+        let handle_msg = HandleMsg::BridgeUnlock {
+            token_id: "NFT1".to_string(),
+            recipient: bob,
+            action_id: Uint128(3),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let token = get_token(&deps, "NFT1");
+        assert_eq!(token.owner, bob_raw);
+        assert!(!token.bridge_locked);
+        let lock_store = ReadonlyPrefixedStorage::new(PREFIX_BRIDGE_LOCK, &deps.storage);
+        let lock: Option<StoredBridgeLock> = may_load(&lock_store, &0u32.to_le_bytes()).unwrap();
+        assert!(lock.is_none());
+
+        // test unlocking a token that is not locked
+        let handle_msg = HandleMsg::BridgeUnlock {
+            token_id: "NFT1".to_string(),
+            recipient: alice.clone(),
+            action_id: Uint128(4),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not locked by the bridge"));
+
+        // test the new owner can transfer it again
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: alice.clone(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // verify the lock and unlock were recorded as transfers
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::TransactionHistory {
+            address: alice.clone(),
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { txs, .. } => {
+                assert_eq!(txs.len(), 3);
+                assert_eq!(
+                    txs[1].action,
+                    TxAction::Transfer {
+                        from: alice.clone(),
+                        sender: None,
+                        recipient: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                    }
+                );
+                assert_eq!(txs[1].memo, Some("to bsc".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: alice.clone(),
+                viewing_key: "akey".to_string(),
+            }),
+            include_expired: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                owner,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                ..
+            } => {
+                assert_eq!(owner, Some(alice));
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test burning tokens to send them to another chain
+    #[test]
+    fn test_burn_for_bridge() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let validator = HumanAddr("validator".to_string());
+
+        for id in ["NFT1", "NFT2", "NFT3"].iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                transferable: None,
+                origin: None,
+                memo: None,
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        }
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("SBT".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: Some(false),
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        let fee = vec![Coin {
+            denom: "uscrt".to_string(),
+            amount: Uint128(100),
+        }];
+
+        // test bridge burn before the validator is set
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(1),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &fee), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bridge validator has not been set"));
+
+        let handle_msg = HandleMsg::SetBridgeValidator {
+            address: validator.clone(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test sending the wrong fee
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(2),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bridge fee of 100uscrt must be sent with BurnForBridge"));
+        // test sending other coins along with the fee
+        let fee_and_extra = vec![
+            fee[0].clone(),
+            Coin {
+                denom: "uatom".to_string(),
+                amount: Uint128(5),
+            },
+        ];
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(3),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &fee_and_extra), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bridge fee of 100uscrt must be sent with BurnForBridge"));
+        // test sending coins when there is no fee
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(0),
+            action_id: Uint128(4),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &fee), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bridge fee of 0uscrt must be sent with BurnForBridge"));
+
+        // test bridge burning a non-transferable token
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["SBT".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(5),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &fee), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: SBT is non-transferable"));
+
+        // test someone else's tokens can not be bridge burned
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(6),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &fee), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test bridge burn succeeds even though burn is disabled
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(100),
+            action_id: Uint128(7),
+            memo: Some("to bsc".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &fee), handle_msg);
+        let handle_answer = handle_result.unwrap();
+        assert_eq!(
+            handle_answer.messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                to_address: validator,
+                amount: fee,
+            })]
+        );
+        match from_binary(&handle_answer.data.unwrap()).unwrap() {
+            HandleAnswer::BurnForBridge { burn_seq } => assert_eq!(burn_seq, 0),
+            _ => panic!("unexpected"),
+        }
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: Option<u32> = may_load(&map2idx, "NFT1".as_bytes()).unwrap();
+        assert!(idx.is_none());
+
+        // test regular burn is still disabled
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Burn functionality is not enabled for this token"));
+
+        // test a free bridge burn does not send a fee
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["NFT3".to_string()],
+            chain_nonce: 7,
+            destination_address: "0xcafe".to_string(),
+            fee: Uint128(0),
+            action_id: Uint128(8),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let handle_answer = handle_result.unwrap();
+        assert!(handle_answer.messages.is_empty());
+        match from_binary(&handle_answer.data.unwrap()).unwrap() {
+            HandleAnswer::BurnForBridge { burn_seq } => assert_eq!(burn_seq, 1),
+            _ => panic!("unexpected"),
+        }
+
+        // test the bridge burn query and its cursor
+        let query_msg = QueryMsg::BridgeBurns {
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::BridgeBurns { burn_count, burns } => {
+                assert_eq!(burn_count, 2);
+                assert_eq!(burns.len(), 2);
+                assert_eq!(
+                    burns[0],
+                    BridgeBurn {
+                        burn_seq: 0,
+                        relayer_action_id: Uint128(7),
+                        token_ids: vec!["NFT1".to_string(), "NFT2".to_string()],
+                        chain_nonce: 4,
+                        destination_address: "0xdeadbeef".to_string(),
+                        fee: Uint128(100),
+                        block_height: 12345,
+                        block_time: 1571797419,
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::BridgeBurns {
+            start_after: Some(0),
+            limit: Some(5),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::BridgeBurns { burns, .. } => {
+                assert_eq!(burns.len(), 1);
+                assert_eq!(burns[0].burn_seq, 1);
+                assert_eq!(burns[0].relayer_action_id, Uint128(8));
+                assert_eq!(burns[0].token_ids, vec!["NFT3".to_string()]);
+                assert_eq!(burns[0].chain_nonce, 7);
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::BridgeBurns {
+            start_after: Some(1),
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::BridgeBurns { burns, .. } => assert!(burns.is_empty()),
+            _ => panic!("unexpected"),
+        }
+
+        // verify the bridge burns were recorded in the tx history
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::TransactionHistory {
+            address: alice.clone(),
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { txs, .. } => {
+                assert_eq!(txs.len(), 7);
+                assert_eq!(txs[0].token_id, "NFT3".to_string());
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::BridgeBurn {
+                        owner: alice.clone(),
+                        burner: None,
+                        burn_seq: 1,
+                        chain_nonce: 7,
+                        destination_address: "0xcafe".to_string(),
+                    }
+                );
+                assert_eq!(txs[1].token_id, "NFT2".to_string());
+                assert_eq!(txs[1].memo, Some("to bsc".to_string()));
+                assert_eq!(
+                    txs[2].action,
+                    TxAction::BridgeBurn {
+                        owner: alice,
+                        burner: None,
+                        burn_seq: 0,
+                        chain_nonce: 4,
+                        destination_address: "0xdeadbeef".to_string(),
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test minting wrapped tokens with their origin and looking them up by it
+    #[test]
+    fn test_mint_with_origin() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let origin = OriginInfo {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            token_uri: "https://example.com/42.json".to_string(),
+        };
+
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("Wrapped".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: Some(origin.clone()),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let token = get_token(&deps, "Wrapped");
+        assert_eq!(token.owner, deps.api.canonical_address(&alice).unwrap());
+        let origin_store = ReadonlyPrefixedStorage::new(PREFIX_ORIGIN, &deps.storage);
+        let stored: OriginInfo = load(&origin_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(stored, origin);
+
+        // test the same origin token can not be minted twice
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![
+                Mint {
+                    token_id: Some("Other".to_string()),
+                    owner: None,
+                    public_metadata: None,
+                    private_metadata: None,
+                    serial_number: None,
+                    royalty_info: None,
+                    transferable: None,
+                    origin: Some(OriginInfo {
+                        token_id: "43".to_string(),
+                        ..origin.clone()
+                    }),
+                    memo: None,
+                },
+                Mint {
+                    token_id: Some("Copy".to_string()),
+                    owner: None,
+                    public_metadata: None,
+                    private_metadata: None,
+                    serial_number: None,
+                    royalty_info: None,
+                    transferable: None,
+                    origin: Some(origin.clone()),
+                    memo: None,
+                },
+            ],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error
+            .contains("Token ID 42 from contract 0xdeadbeef on chain 4 has already been minted"));
+
+        // test private supply hides the origin lookup from non-minters
+        let query_msg = QueryMsg::TokenByOrigin {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            viewer: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let viewer = Some(ViewerInfo {
+            address: HumanAddr("admin".to_string()),
+            viewing_key: "key".to_string(),
+        });
+        let query_msg = QueryMsg::TokenByOrigin {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            viewer: viewer.clone(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenByOrigin { token_id } => {
+                assert_eq!(token_id, Some("Wrapped".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+        // test coordinates that do not match
+        let query_msg = QueryMsg::TokenByOrigin {
+            chain_nonce: 5,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            viewer: viewer.clone(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenByOrigin { token_id } => assert!(token_id.is_none()),
+            _ => panic!("unexpected"),
+        }
+
+        // test burning the wrapped copy frees the origin to be wrapped again
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "Wrapped".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let origin_store = ReadonlyPrefixedStorage::new(PREFIX_ORIGIN, &deps.storage);
+        let stored: Option<OriginInfo> = may_load(&origin_store, &0u32.to_le_bytes()).unwrap();
+        assert!(stored.is_none());
+        let query_msg = QueryMsg::TokenByOrigin {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            viewer: viewer.clone(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenByOrigin { token_id } => assert!(token_id.is_none()),
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("Rewrapped".to_string()),
+            owner: Some(alice),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: Some(origin),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::TokenByOrigin {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            viewer,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenByOrigin { token_id } => {
+                assert_eq!(token_id, Some("Rewrapped".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test validator quorum approval of bridge mints
+    #[test]
+    fn test_validator_mint_quorum() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let val1 = HumanAddr("val1".to_string());
+        let val2 = HumanAddr("val2".to_string());
+        let val3 = HumanAddr("val3".to_string());
+        let origin = OriginInfo {
+            chain_nonce: 4,
+            contract_address: "0xdeadbeef".to_string(),
+            token_id: "42".to_string(),
+            token_uri: "https://example.com/42.json".to_string(),
+        };
+        let bridge_mint = Mint {
+            token_id: Some("Wrapped".to_string()),
+            owner: Some(alice.clone()),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: Some(origin.clone()),
+            memo: None,
+        };
+
+        // test non-admin setting validators
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![val1.clone(), val2.clone(), val3.clone()],
+            threshold: 2,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test invalid thresholds
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![val1.clone(), val2.clone(), val3.clone(), val3.clone()],
+            threshold: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Threshold must be between 1 and the number of validators"));
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![val1.clone()],
+            threshold: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Threshold must be between 1 and the number of validators"));
+
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![val1.clone(), val2.clone(), val3.clone()],
+            threshold: 2,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(&deps, QueryMsg::Validators {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Validators {
+                validators,
+                threshold,
+            } => {
+                assert_eq!(validators, vec![val1.clone(), val2.clone(), val3.clone()]);
+                assert_eq!(threshold, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test a minter can no longer mint bridged tokens unilaterally
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![bridge_mint.clone()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Mints of bridged tokens must be approved by the validators"));
+        // but can still mint tokens that did not come from the bridge
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("Native".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        // test a validator that is also a minter can not bypass the quorum
+        let handle_msg = HandleMsg::AddMinters {
+            minters: vec![val1.clone()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("Bypass".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge validators must mint through ProposeMint/VoteMint"));
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 2,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge validators must mint through ProposeMint/VoteMint"));
+        let handle_msg = HandleMsg::MintRandomized {
+            token_id: Some("Bypass".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            trait_tables: Vec::new(),
+            entropy: "entropy".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge validators must mint through ProposeMint/VoteMint"));
+
+        // test non-validator proposing
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: bridge_mint.clone(),
+            action_id: Uint128(5),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only validators may propose or vote on mints"));
+
+        // test proposal below threshold does not mint
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: bridge_mint.clone(),
+            action_id: Uint128(6),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::ProposeMint {
+                proposal_id,
+                token_id,
+            } => {
+                assert_eq!(proposal_id, 0);
+                assert!(token_id.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let idx: Option<u32> = may_load(&map2idx, "Wrapped".as_bytes()).unwrap();
+        assert!(idx.is_none());
+
+        let query_msg = QueryMsg::MintProposals {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MintProposals { proposals } => {
+                assert_eq!(
+                    proposals,
+                    vec![MintProposal {
+                        proposal_id: 0,
+                        action_id: Uint128(6),
+                        token_id: Some("Wrapped".to_string()),
+                        owner: Some(alice.clone()),
+                        origin: Some(origin.clone()),
+                        voters: vec![val1.clone()],
+                        vote_count: 1,
+                    }]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test double voting
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You have already voted for mint proposal 0"));
+
+        // test voting on a proposal that does not exist
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 3,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val2", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Mint proposal 3 is not pending"));
+
+        // test reaching quorum mints the token
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val2", &[]), handle_msg);
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::VoteMint {
+                proposal_id,
+                token_id,
+            } => {
+                assert_eq!(proposal_id, 0);
+                assert_eq!(token_id, Some("Wrapped".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+        let token = get_token(&deps, "Wrapped");
+        assert_eq!(token.owner, deps.api.canonical_address(&alice).unwrap());
+        let query_msg = QueryMsg::MintProposals {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MintProposals { proposals } => assert!(proposals.is_empty()),
+            _ => panic!("unexpected"),
+        }
+        // test late votes are rejected
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val3", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Mint proposal 0 is not pending"));
+
+        // test votes of removed validators no longer count
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: Mint {
+                token_id: Some("Wrapped2".to_string()),
+                origin: Some(OriginInfo {
+                    token_id: "43".to_string(),
+                    ..origin.clone()
+                }),
+                ..bridge_mint.clone()
+            },
+            action_id: Uint128(7),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: Mint {
+                token_id: Some("Wrapped3".to_string()),
+                origin: Some(OriginInfo {
+                    token_id: "44".to_string(),
+                    ..origin.clone()
+                }),
+                ..bridge_mint.clone()
+            },
+            action_id: Uint128(8),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("val2", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![val2, val3],
+            threshold: 2,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::MintProposals {
+            viewer: None,
+            start_after: Some(1),
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MintProposals { proposals } => {
+                assert_eq!(proposals.len(), 1);
+                assert_eq!(proposals[0].proposal_id, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::MintProposals {
+            viewer: None,
+            start_after: None,
+            limit: Some(1),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MintProposals { proposals } => {
+                assert_eq!(proposals.len(), 1);
+                assert_eq!(proposals[0].proposal_id, 1);
+                assert_eq!(proposals[0].voters, vec![val1]);
+                assert_eq!(proposals[0].vote_count, 0);
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 1,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val3", &[]), handle_msg);
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::VoteMint { token_id, .. } => assert!(token_id.is_none()),
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 1,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only validators may propose or vote on mints"));
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 2,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val3", &[]), handle_msg);
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::VoteMint { token_id, .. } => {
+                assert_eq!(token_id, Some("Wrapped3".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test removing the validators lets minters mint bridged tokens again
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![],
+            threshold: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![Mint {
+                token_id: Some("Wrapped4".to_string()),
+                origin: Some(OriginInfo {
+                    token_id: "45".to_string(),
+                    ..origin
+                }),
+                ..bridge_mint
+            }],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    // test cancelling mint proposals and the limit on pending proposals
+    #[test]
+    fn test_cancel_mint_proposal() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![HumanAddr("val1".to_string()), HumanAddr("val2".to_string())],
+            threshold: 2,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let mint = Mint {
+            token_id: Some("Wrapped".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+        };
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: mint.clone(),
+            action_id: Uint128(1),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test non-admin cancelling
+        let handle_msg = HandleMsg::CancelMintProposal {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        let handle_msg = HandleMsg::CancelMintProposal {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::MintProposals {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MintProposals { proposals } => assert!(proposals.is_empty()),
+            _ => panic!("unexpected"),
+        }
+        // test a cancelled proposal can not be voted on or cancelled again
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val2", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Mint proposal 0 is not pending"));
+        let handle_msg = HandleMsg::CancelMintProposal {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Mint proposal 0 is not pending"));
+
+        // test the action id of a cancelled proposal can be proposed again
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: mint.clone(),
+            action_id: Uint128(1),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test the limit on pending proposals
+        for i in 2..=MAX_PENDING_PROPOSALS as u128 {
+            let handle_msg = HandleMsg::ProposeMint {
+                mint: mint.clone(),
+                action_id: Uint128(i),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        let handle_msg = HandleMsg::ProposeMint {
+            mint,
+            action_id: Uint128(101),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("val1", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There can not be more than 100 pending mint proposals"));
+    }
+
+    // test bridge action ids can only be processed once
+    #[test]
+    fn test_action_replay_protection() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let handle_msg = HandleMsg::SetValidators {
+            validators: vec![
+                HumanAddr("validator".to_string()),
+                HumanAddr("validator2".to_string()),
+            ],
+            threshold: 2,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::SetBridgeValidator {
+            address: HumanAddr("validator".to_string()),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let mint = Mint {
+            token_id: Some("Wrapped".to_string()),
+            owner: Some(alice),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+        };
+
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: Some(BridgeActionKind::Mint),
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(!consumed),
+            _ => panic!("unexpected"),
+        }
+
+        // test a non-validator can not propose an action id
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: mint.clone(),
+            action_id: Uint128(7),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only validators may propose or vote on mints"));
+
+        // test a pending proposal does not consume its action id
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: mint.clone(),
+            action_id: Uint128(7),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: Some(BridgeActionKind::Mint),
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(!consumed),
+            _ => panic!("unexpected"),
+        }
+
+        // test a retried proposal is rejected while the first is pending
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: mint.clone(),
+            action_id: Uint128(7),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator2", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge action 7 has already been proposed as mint proposal 0"));
+
+        // test performing the mint consumes the action id
+        let handle_msg = HandleMsg::VoteMint {
+            proposal_id: 0,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator2", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: Some(BridgeActionKind::Mint),
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(consumed),
+            _ => panic!("unexpected"),
+        }
+
+        // test a retried mint is rejected
+        let handle_msg = HandleMsg::ProposeMint {
+            mint: Mint {
+                token_id: Some("Retry".to_string()),
+                ..mint
+            },
+            action_id: Uint128(7),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("validator", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge action 7 has already been processed"));
+
+        // test a user burn can not consume the ids of other kinds of actions
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["Wrapped".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(0),
+            action_id: Uint128(7),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: Some(BridgeActionKind::Burn),
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(consumed),
+            _ => panic!("unexpected"),
+        }
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: Some(BridgeActionKind::Unlock),
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(!consumed),
+            _ => panic!("unexpected"),
+        }
+        // test omitting the kind checks every kind of bridge action
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: None,
+                action_id: Uint128(7),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(consumed),
+            _ => panic!("unexpected"),
+        }
+        let query_result = query(
+            &deps,
+            QueryMsg::IsActionConsumed {
+                kind: None,
+                action_id: Uint128(99),
+            },
+        );
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::IsActionConsumed { consumed } => assert!(!consumed),
+            _ => panic!("unexpected"),
+        }
+
+        // test a retried burn is rejected
+        let handle_msg = HandleMsg::BurnForBridge {
+            token_ids: vec!["Wrapped".to_string()],
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            fee: Uint128(0),
+            action_id: Uint128(7),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Bridge action 7 has already been processed"));
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::msg::{HandleAnswer, HandleMsg, InitMsg, Mint, QueryAnswer, QueryMsg, TxAction};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Extern, HumanAddr, InitResponse, StdError, StdResult};
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn mint_to(token_id: &str, owner: &str) -> Mint {
+        Mint {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr(owner.to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+        }
+    }
+
+    fn compose_msg(inputs: &[&str]) -> HandleMsg {
+        HandleMsg::Compose {
+            inputs: inputs.iter().map(|i| i.to_string()).collect(),
+            output: mint_to("SWORD", "alice"),
+            memo: Some("forged".to_string()),
+            padding: None,
+        }
+    }
+
+    // test composing burns the inputs and mints the output even when burn is disabled
+    #[test]
+    fn test_compose() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+        let admin = HumanAddr("admin".to_string());
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![mint_to("BLADE", "alice"), mint_to("HILT", "alice")],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test only minters may compose
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            compose_msg(&["BLADE", "HILT"]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to compose"));
+
+        // test compose needs inputs
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), compose_msg(&[]));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Compose requires at least one input token"));
+
+        // test the minter needs transfer approval for the inputs
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            compose_msg(&["BLADE", "HILT"]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token BLADE"));
+
+        let handle_msg = HandleMsg::ApproveAll {
+            operator: admin.clone(),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            compose_msg(&["BLADE", "HILT"]),
+        );
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::Compose { token_id } => assert_eq!(token_id, "SWORD".to_string()),
+            _ => panic!("unexpected"),
+        }
+
+        // verify the inputs are gone and the output belongs to alice
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::Tokens {
+            owner: alice.clone(),
+            viewer: None,
+            viewing_key: Some("key".to_string()),
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens } => assert_eq!(tokens, vec!["SWORD".to_string()]),
+            _ => panic!("unexpected"),
+        }
+
+        // verify the compose tx links the inputs to the output
+        let query_msg = QueryMsg::TransactionHistory {
+            address: alice.clone(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 4);
+                assert_eq!(txs[0].token_id, "SWORD".to_string());
+                assert_eq!(txs[0].memo, Some("forged".to_string()));
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::Compose {
+                        owners: vec![alice.clone()],
+                        composer: admin.clone(),
+                        recipient: alice.clone(),
+                        inputs: vec!["BLADE".to_string(), "HILT".to_string()],
+                    }
+                );
+                assert_eq!(
+                    txs[1].action,
+                    TxAction::Mint {
+                        minter: admin,
+                        recipient: alice,
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test a consumed input can not be composed again
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), compose_msg(&["BLADE"]));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token BLADE"));
+    }
+}
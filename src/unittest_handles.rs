@@ -41,6 +41,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback: None,
         };
@@ -89,6 +90,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: Some(init_config),
             post_init_callback: None,
         };
@@ -186,6 +188,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback,
         };
@@ -245,6 +248,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -255,6 +259,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -265,6 +270,7 @@ mod tests {
                 royalty_info: None,
                 transferable: None,
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -275,6 +281,7 @@ mod tests {
                 royalty_info: None,
                 transferable: None,
                 serial_number: None,
+                origin: None,
                 memo: Some("has id 3".to_string()),
             },
         ];
@@ -429,6 +436,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -459,6 +467,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -483,6 +492,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -523,6 +533,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -600,6 +611,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -625,6 +637,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Admin wants his own".to_string()),
             padding: None,
         };
@@ -791,6 +804,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -858,6 +872,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -903,6 +918,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -984,6 +1000,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1014,6 +1031,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1197,6 +1215,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1292,6 +1311,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1346,6 +1366,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1402,6 +1423,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -1419,6 +1441,301 @@ mod tests {
         assert!(pub_meta.is_none());
     }
 
+    // test the scheduled reveal and admin batch reveal of sealed metadata
+    #[test]
+    fn test_reveal_all() {
+        let mut deps = mock_dependencies(20, &[]);
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(InitConfig {
+                enable_sealed_metadata: Some(true),
+                enable_burn: Some(true),
+                reveal_not_before: Some(Expiration::AtHeight(20000)),
+                ..InitConfig::default()
+            }),
+            post_init_callback: None,
+        };
+        let init_result = init(&mut deps, mock_env("instantiator", &[]), init_msg);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let sealed = |id: &str| Metadata {
+            token_uri: Some(format!("sealed {}", id)),
+            extension: None,
+        };
+        let mints = ["NFT0", "NFT1", "NFT2"]
+            .iter()
+            .map(|id| Mint {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: Some(sealed(id)),
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                origin: None,
+                memo: None,
+            })
+            .collect();
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test revealing before the reveal time
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT0".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Sealed metadata may not be revealed before expiration height: 20000")
+        );
+        let handle_msg = HandleMsg::RevealAll {
+            start_index: 0,
+            limit: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Sealed metadata may not be revealed before expiration height: 20000")
+        );
+
+        let env_at = |sender: &str| {
+            let mut env = mock_env(sender, &[]);
+            env.block.height = 20000;
+            env
+        };
+        // sanity check: owner reveals after the reveal time
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "NFT0".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at("alice"), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test non-admin batch reveal
+        let handle_msg = HandleMsg::RevealAll {
+            start_index: 0,
+            limit: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at("alice"), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test paginated batch reveal skips unwrapped and burned tokens
+        let handle_msg = HandleMsg::RevealAll {
+            start_index: 0,
+            limit: Some(2),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at("admin"), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::RevealAll {
+                revealed,
+                next_index,
+            } => {
+                assert_eq!(revealed, 0);
+                assert_eq!(next_index, Some(2));
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::RevealAll {
+            start_index: 2,
+            limit: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at("admin"), handle_msg);
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::RevealAll {
+                revealed,
+                next_index,
+            } => {
+                assert_eq!(revealed, 1);
+                assert_eq!(next_index, None);
+            }
+            _ => panic!("unexpected"),
+        }
+        let token_key = 2u32.to_le_bytes();
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &token_key).unwrap();
+        assert!(token.unwrapped);
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key).unwrap();
+        assert!(priv_meta.is_none());
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(pub_meta, sealed("NFT2"));
+    }
+
+    // test freezing the metadata of a token and of the whole collection
+    #[test]
+    fn test_freeze_metadata() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, true, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let new_meta = Metadata {
+            token_uri: Some("uri".to_string()),
+            extension: None,
+        };
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(new_meta.clone()),
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test owner may not freeze if they may not update metadata
+        let handle_msg = HandleMsg::FreezeMetadata {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to freeze metadata of token MyNFT"));
+
+        // sanity check: minter freezes the token
+        let handle_msg = HandleMsg::FreezeMetadata {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert!(token.metadata_frozen);
+
+        // test frozen metadata can not be updated or frozen again
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(new_meta.clone()),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata of token MyNFT is frozen"));
+        let handle_msg = HandleMsg::FreezeMetadata {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata of token MyNFT is frozen"));
+
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, true, false, false, true, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("MyNFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: Some(new_meta.clone()),
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+
+        // test non-admin trying to freeze all metadata
+        let handle_msg = HandleMsg::FreezeAllMetadata { padding: None };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // sanity check: admin freezes all metadata
+        let handle_msg = HandleMsg::FreezeAllMetadata { padding: None };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert!(config.metadata_frozen);
+
+        // test owner may no longer update or reveal
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "MyNFT".to_string(),
+            public_metadata: Some(new_meta),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata of token MyNFT is frozen"));
+        let handle_msg = HandleMsg::Reveal {
+            token_id: "MyNFT".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata of token MyNFT is frozen"));
+
+        // test frozen status is displayed
+        let query_msg = QueryMsg::ContractConfig {};
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ContractConfig {
+                metadata_is_frozen, ..
+            } => assert!(metadata_is_frozen),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "MyNFT".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                metadata_frozen, ..
+            } => assert!(metadata_frozen),
+            _ => panic!("unexpected"),
+        }
+    }
+
     // test owner setting approval for specific addresses
     #[test]
     fn test_set_whitelisted_approval() {
@@ -1483,6 +1800,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1504,6 +1822,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1525,6 +1844,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1546,6 +1866,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3456,6 +3777,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3767,6 +4089,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3787,6 +4110,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3965,6 +4289,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -4343,6 +4668,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -4363,6 +4689,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -4499,6 +4826,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -4595,6 +4923,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -4800,6 +5129,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -4830,6 +5160,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -4996,6 +5327,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5048,6 +5380,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5061,6 +5394,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5091,6 +5425,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5103,6 +5438,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5115,6 +5451,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5127,6 +5464,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5139,6 +5477,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5151,6 +5490,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5302,6 +5642,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5315,6 +5656,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5327,6 +5669,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5339,6 +5682,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5351,6 +5695,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5363,6 +5708,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5375,6 +5721,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5387,6 +5734,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5533,6 +5881,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5546,6 +5895,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: Some(false),
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5558,6 +5908,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5570,6 +5921,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5582,6 +5934,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5594,6 +5947,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5606,6 +5960,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: Some(false),
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5618,6 +5973,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -5935,6 +6291,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -6026,6 +6383,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -6492,6 +6850,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -6588,6 +6947,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -6598,6 +6958,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -6608,6 +6969,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -6618,6 +6980,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -6628,6 +6991,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -6638,6 +7002,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
             ],
@@ -6807,6 +7172,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -6820,6 +7186,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -6832,6 +7199,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -6844,6 +7212,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -6856,6 +7225,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -6868,6 +7238,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -7189,6 +7560,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -7199,6 +7571,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -7209,6 +7582,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -7219,6 +7593,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -7229,6 +7604,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -7239,6 +7615,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
             ],
@@ -7400,6 +7777,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -7497,6 +7875,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -7985,6 +8364,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint it baby!".to_string()),
             padding: None,
         };
@@ -8083,6 +8463,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8096,6 +8477,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8108,6 +8490,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8120,6 +8503,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8132,6 +8516,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8144,6 +8529,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -8562,6 +8948,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -8572,6 +8959,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -8582,6 +8970,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -8592,6 +8981,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -8602,6 +8992,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
                 Mint {
@@ -8612,6 +9003,7 @@ mod tests {
                     royalty_info: None,
                     serial_number: None,
                     transferable: None,
+                    origin: None,
                     memo: None,
                 },
             ],
@@ -9412,6 +9804,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9424,6 +9817,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9436,6 +9830,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9630,6 +10025,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9642,6 +10038,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9654,6 +10051,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -9979,6 +10377,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10336,6 +10735,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10357,6 +10757,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10395,6 +10796,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10407,6 +10809,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10716,6 +11119,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10728,6 +11132,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10903,6 +11308,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -10915,6 +11321,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
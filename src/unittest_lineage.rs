@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::msg::{
+        AccessLevel, HandleAnswer, HandleMsg, InitMsg, LineageToken, Mint, QueryAnswer, QueryMsg,
+        ViewerInfo,
+    };
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Extern, HumanAddr, InitResponse, StdError, StdResult};
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn mint_to(token_id: &str, owner: &str) -> Mint {
+        Mint {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr(owner.to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+        }
+    }
+
+    fn derive_msg(parents: &[&str], mints: Vec<Mint>) -> HandleMsg {
+        HandleMsg::Derive {
+            parent_token_ids: parents.iter().map(|p| p.to_string()).collect(),
+            mints,
+            padding: None,
+        }
+    }
+
+    fn lineage(token_id: &str, generation: u32) -> LineageToken {
+        LineageToken {
+            token_id: token_id.to_string(),
+            generation,
+        }
+    }
+
+    fn query_lineage(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        token_id: &str,
+        depth: Option<u32>,
+        address: &str,
+    ) -> StdResult<(Vec<LineageToken>, Vec<LineageToken>)> {
+        let query_msg = QueryMsg::TokenLineage {
+            token_id: token_id.to_string(),
+            depth,
+            viewer: Some(ViewerInfo {
+                address: HumanAddr(address.to_string()),
+                viewing_key: "key".to_string(),
+            }),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg)?)?;
+        match query_answer {
+            QueryAnswer::TokenLineage {
+                ancestors,
+                descendants,
+            } => Ok((ancestors, descendants)),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test deriving tokens records lineage that is only displayed to permitted viewers
+    #[test]
+    fn test_token_lineage() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints: vec![mint_to("ORIGINAL", "alice")],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        for address in ["alice", "bob"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+
+        // test only minters may derive
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            derive_msg(&["ORIGINAL"], vec![mint_to("SHARD1", "alice")]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to mint"));
+
+        // test parents must exist
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            derive_msg(&["MISSING"], vec![mint_to("SHARD1", "alice")]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token MISSING"));
+
+        // split the original into two shards, and split a shard again
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            derive_msg(
+                &["ORIGINAL"],
+                vec![mint_to("SHARD1", "alice"), mint_to("SHARD2", "bob")],
+            ),
+        );
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::Derive { token_ids } => {
+                assert_eq!(token_ids, vec!["SHARD1".to_string(), "SHARD2".to_string()]);
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            derive_msg(&["SHARD1"], vec![mint_to("FRAGMENT", "alice")]),
+        );
+        assert!(handle_result.is_ok());
+
+        // test the walk only displays tokens whose metadata the viewer may see
+        let (ancestors, descendants) = query_lineage(&deps, "ORIGINAL", Some(2), "alice").unwrap();
+        assert!(ancestors.is_empty());
+        assert_eq!(
+            descendants,
+            vec![lineage("SHARD1", 1), lineage("FRAGMENT", 2)]
+        );
+        let (ancestors, descendants) = query_lineage(&deps, "FRAGMENT", None, "alice").unwrap();
+        assert_eq!(ancestors, vec![lineage("SHARD1", 1)]);
+        assert!(descendants.is_empty());
+        let (ancestors, _) = query_lineage(&deps, "FRAGMENT", Some(5), "alice").unwrap();
+        assert_eq!(
+            ancestors,
+            vec![lineage("SHARD1", 1), lineage("ORIGINAL", 2)]
+        );
+        let (ancestors, _) = query_lineage(&deps, "SHARD2", None, "bob").unwrap();
+        assert!(ancestors.is_empty());
+
+        // test the queried token itself requires metadata permission
+        let error = extract_error_msg(query_lineage(&deps, "ORIGINAL", None, "bob"));
+        assert!(error.contains("You are not authorized to view the lineage of token ORIGINAL"));
+
+        // test granting metadata access reveals the lineage
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: None,
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::All),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let (ancestors, _) = query_lineage(&deps, "SHARD2", None, "bob").unwrap();
+        assert_eq!(ancestors, vec![lineage("ORIGINAL", 1)]);
+        let (_, descendants) = query_lineage(&deps, "ORIGINAL", Some(2), "bob").unwrap();
+        assert_eq!(
+            descendants,
+            vec![
+                lineage("SHARD1", 1),
+                lineage("SHARD2", 1),
+                lineage("FRAGMENT", 2)
+            ]
+        );
+    }
+}
@@ -0,0 +1,1262 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query, MAX_OFFERS_PER_TOKEN};
+    use crate::expiration::Expiration;
+    use crate::market::{Auction, Listing, Offer};
+    use crate::msg::{
+        AccessLevel, HandleAnswer, HandleMsg, InitConfig, InitMsg, QueryAnswer, QueryMsg,
+        ReceiveMsg, ViewerInfo,
+    };
+    use crate::royalties::{Royalty, RoyaltyInfo};
+    use crate::sale::snip20_transfer_msg;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, to_binary, BankMsg, Binary, Coin, CosmosMsg, Env, Extern, HumanAddr,
+        InitResponse, StdError, StdResult, Uint128,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper(
+        public_owner: bool,
+    ) -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+
+        let env = mock_env("instantiator", &[]);
+        let init_config: InitConfig = from_binary(&Binary::from(
+            format!(
+                "{{\"public_token_supply\":true,
+            \"public_owner\":{},
+            \"enable_burn\":true,
+            \"payment_tokens\":[{{\"address\":\"sscrt\",\"code_hash\":\"sscrt hash\",\"price\":\"1\"}}]}}",
+                public_owner
+            )
+            .as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn coins(amount: u128, denom: &str) -> Vec<Coin> {
+        vec![Coin {
+            denom: denom.to_string(),
+            amount: Uint128(amount),
+        }]
+    }
+
+    fn env_at_time(sender: &str, sent: &[Coin], time: u64) -> Env {
+        let mut env = mock_env(sender, sent);
+        env.block.time = time;
+        env
+    }
+
+    fn bank_send(to: &str, amount: u128) -> CosmosMsg {
+        CosmosMsg::Bank(BankMsg::Send {
+            from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+            to_address: HumanAddr(to.to_string()),
+            amount: coins(amount, "uscrt"),
+        })
+    }
+
+    fn mint_with_royalties(deps: &mut Extern<MockStorage, MockApi, MockQuerier>, token_id: &str) {
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 3,
+                royalties: vec![
+                    Royalty {
+                        recipient: HumanAddr("creator".to_string()),
+                        rate: 50,
+                    },
+                    Royalty {
+                        recipient: HumanAddr("platform".to_string()),
+                        rate: 25,
+                    },
+                ],
+            }),
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    fn owner_of(deps: &Extern<MockStorage, MockApi, MockQuerier>, token_id: &str) -> HumanAddr {
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => owner,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test buying a token listed for native coins pays the royalties and the seller
+    #[test]
+    fn test_buy_listed_token() {
+        let (init_result, mut deps) = init_helper(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+
+        // test buying an unlisted token
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(1000, "uscrt")),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not listed for sale"));
+
+        // test listing by someone other than the owner
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(1000),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test listing without a currency
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(1000),
+            denom: None,
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("A listing must be priced in either a native denom or a payment token")
+        );
+
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(1000),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test paying the wrong price
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &coins(999, "uscrt")), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The listing price of 1000uscrt must be sent with the purchase"));
+
+        // test a successful purchase splits the price
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(1000, "uscrt")),
+            handle_msg,
+        );
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![
+                bank_send("creator", 50),
+                bank_send("platform", 25),
+                bank_send("alice", 925),
+            ]
+        );
+        assert_eq!(owner_of(&deps, "NFT1"), HumanAddr("bob".to_string()));
+
+        // test the listing was removed by the sale
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("charlie", &coins(1000, "uscrt")),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not listed for sale"));
+
+        // test a listing is invalid once the seller no longer owns the token
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(10),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("david".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("charlie", &coins(10, "uscrt")),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not listed for sale"));
+    }
+
+    // test buying a token listed for a SNIP-20 token
+    #[test]
+    fn test_buy_listed_token_snip20() {
+        let (init_result, mut deps) = init_helper(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+
+        // test listing for a token that is not accepted
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(2000),
+            denom: None,
+            payment_token: Some(HumanAddr("fake".to_string())),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("fake is not an accepted payment token"));
+
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(2000),
+            denom: None,
+            payment_token: Some(HumanAddr("sscrt".to_string())),
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test buying with native coins
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(2000, "uscrt")),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 must be bought with a SNIP-20 token"));
+
+        let buy = Some(
+            to_binary(&ReceiveMsg::Buy {
+                token_id: "NFT1".to_string(),
+                memo: None,
+            })
+            .unwrap(),
+        );
+        // test sending the wrong amount
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("bob".to_string()),
+            amount: Uint128(1000),
+            msg: buy.clone(),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The listing price of 2000 tokens must be sent with the purchase"));
+
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("bob".to_string()),
+            amount: Uint128(2000),
+            msg: buy,
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let handle_result = handle_result.unwrap();
+        let transfer = |to: &str, amount: u128| {
+            snip20_transfer_msg(
+                HumanAddr(to.to_string()),
+                Uint128(amount),
+                "sscrt hash".to_string(),
+                HumanAddr("sscrt".to_string()),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            handle_result.messages,
+            vec![
+                transfer("creator", 100),
+                transfer("platform", 50),
+                transfer("alice", 1850),
+            ]
+        );
+        let answer: HandleAnswer = from_binary(&handle_result.data.unwrap()).unwrap();
+        match answer {
+            HandleAnswer::Receive { token_ids } => assert_eq!(token_ids, vec!["NFT1".to_string()]),
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(owner_of(&deps, "NFT1"), HumanAddr("bob".to_string()));
+    }
+
+    // test cancelling, expiring and querying listings
+    #[test]
+    fn test_listing_lifecycle() {
+        let (init_result, mut deps) = init_helper(false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+        mint_with_royalties(&mut deps, "NFT2");
+        mint_with_royalties(&mut deps, "NFT3");
+
+        let list = |token_id: &str, expires: Option<Expiration>| HandleMsg::ListForSale {
+            token_id: token_id.to_string(),
+            price: Uint128(100),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires,
+            padding: None,
+        };
+        // test listing with an expiration that already passed
+        let mut env = mock_env("alice", &[]);
+        env.block.height = 100;
+        let handle_result = handle(
+            &mut deps,
+            env.clone(),
+            list("NFT1", Some(Expiration::AtHeight(100))),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The listing expiration has already passed"));
+        let handle_result = handle(
+            &mut deps,
+            env.clone(),
+            list("NFT1", Some(Expiration::AtHeight(20000))),
+        );
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, env.clone(), list("NFT2", None));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, env, list("NFT3", None));
+        assert!(handle_result.is_ok());
+
+        // test the seller is hidden when ownership is private
+        let query_msg = QueryMsg::Listings {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Listings { listings } => {
+                assert_eq!(
+                    listings
+                        .iter()
+                        .map(|l| l.token_id.as_str())
+                        .collect::<Vec<&str>>(),
+                    vec!["NFT1", "NFT2", "NFT3"]
+                );
+                assert_eq!(
+                    listings[1],
+                    Listing {
+                        token_id: "NFT2".to_string(),
+                        seller: None,
+                        price: Uint128(100),
+                        denom: Some("uscrt".to_string()),
+                        payment_token: None,
+                        expires: Expiration::Never,
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        // test the seller is displayed to those permitted to view the owner
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::ListingOf {
+            token_id: "NFT2".to_string(),
+            viewer: Some(ViewerInfo {
+                address: HumanAddr("alice".to_string()),
+                viewing_key: "key".to_string(),
+            }),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ListingOf { listing } => {
+                assert_eq!(
+                    listing.unwrap().seller,
+                    Some(HumanAddr("alice".to_string()))
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: Some(AccessLevel::All),
+            view_private_metadata: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::Listings {
+            viewer: None,
+            start_after: None,
+            limit: Some(1),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Listings { listings } => {
+                assert_eq!(listings[0].seller, Some(HumanAddr("alice".to_string())));
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::SetGlobalApproval {
+            token_id: None,
+            view_owner: Some(AccessLevel::None),
+            view_private_metadata: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test pagination
+        let query_msg = QueryMsg::Listings {
+            viewer: None,
+            start_after: Some("NFT1".to_string()),
+            limit: Some(1),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Listings { listings } => {
+                assert_eq!(listings.len(), 1);
+                assert_eq!(listings[0].token_id, "NFT2".to_string());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test cancelling someone else's listing
+        let handle_msg = HandleMsg::CancelListing {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+        let handle_msg = HandleMsg::CancelListing {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::CancelListing {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 is not listed for sale"));
+
+        // test burning removes the listing
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::Listings {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Listings { listings } => {
+                assert_eq!(listings.len(), 1);
+                assert_eq!(listings[0].token_id, "NFT1".to_string());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test buying an expired listing
+        let mut env = mock_env("bob", &coins(100, "uscrt"));
+        env.block.height = 20000;
+        let handle_msg = HandleMsg::Buy {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env, handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The listing of token ID: NFT1 has expired"));
+        // queries see the block height of the last handle
+        let query_msg = QueryMsg::ListingOf {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ListingOf { listing } => assert!(listing.is_none()),
+            _ => panic!("unexpected"),
+        }
+
+        // test transferring removes the listing
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), list("NFT2", None));
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::ListingOf {
+            token_id: "NFT2".to_string(),
+            viewer: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ListingOf { listing } => assert!(listing.is_some()),
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::ListingOf {
+            token_id: "NFT2".to_string(),
+            viewer: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ListingOf { listing } => assert!(listing.is_none()),
+            _ => panic!("unexpected"),
+        }
+        // test a token that does not exist is shown as unlisted
+        let query_msg = QueryMsg::ListingOf {
+            token_id: "NFT9".to_string(),
+            viewer: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ListingOf { listing } => assert!(listing.is_none()),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test listings and auctions are only displayed to minters when the token supply is private
+    #[test]
+    fn test_market_private_supply() {
+        let mut deps = mock_dependencies(20, &[]);
+        let init_config: InitConfig =
+            from_binary(&Binary::from(r#"{"enable_burn":true}"#.as_bytes())).unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+        let init_result = init(&mut deps, mock_env("instantiator", &[]), init_msg);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(100),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        for address in ["alice", "admin"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+
+        // test the listings and the start_after lookup are hidden from non-minters
+        let listings = |address: Option<&str>, start_after: Option<&str>| QueryMsg::Listings {
+            viewer: address.map(|a| ViewerInfo {
+                address: HumanAddr(a.to_string()),
+                viewing_key: "key".to_string(),
+            }),
+            start_after: start_after.map(|s| s.to_string()),
+            limit: None,
+        };
+        let query_result = query(&deps, listings(None, None));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+        let query_result = query(&deps, listings(Some("alice"), Some("NFT9")));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+
+        // test a minter sees the listings, without the seller if they may not view the owner
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, listings(Some("admin"), None)).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Listings { listings } => {
+                assert_eq!(listings.len(), 1);
+                assert_eq!(listings[0].token_id, "NFT1".to_string());
+                assert!(listings[0].seller.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test auctions are hidden from non-minters the same way
+        mint_with_royalties(&mut deps, "NFT2");
+        let handle_msg = HandleMsg::StartAuction {
+            token_id: "NFT2".to_string(),
+            denom: "uscrt".to_string(),
+            reserve_price: Uint128(100),
+            end_time: mock_env("alice", &[]).block.time + 1000,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let auctions = |address: Option<&str>| QueryMsg::Auctions {
+            viewer: address.map(|a| ViewerInfo {
+                address: HumanAddr(a.to_string()),
+                viewing_key: "key".to_string(),
+            }),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(&deps, auctions(Some("alice")));
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, auctions(Some("admin"))).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Auctions { auctions } => {
+                assert_eq!(auctions.len(), 1);
+                assert!(auctions[0].seller.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+        // test the seller of a single auction is only displayed to those who may view the owner
+        let auction_of = |address: Option<&str>| QueryMsg::AuctionOf {
+            token_id: "NFT2".to_string(),
+            viewer: address.map(|a| ViewerInfo {
+                address: HumanAddr(a.to_string()),
+                viewing_key: "key".to_string(),
+            }),
+        };
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, auction_of(None)).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::AuctionOf { auction } => assert!(auction.unwrap().seller.is_none()),
+            _ => panic!("unexpected"),
+        }
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, auction_of(Some("alice"))).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::AuctionOf { auction } => {
+                assert_eq!(
+                    auction.unwrap().seller,
+                    Some(HumanAddr("alice".to_string()))
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test the old name of the Buy message is still accepted
+    #[test]
+    fn test_buy_listed_token_alias() {
+        let handle_msg: HandleMsg = from_binary(&Binary::from(
+            r#"{"buy_listed_token":{"token_id":"NFT1"}}"#.as_bytes(),
+        ))
+        .unwrap();
+        match handle_msg {
+            HandleMsg::Buy { token_id, .. } => assert_eq!(token_id, "NFT1".to_string()),
+            _ => panic!("unexpected"),
+        }
+        let receive_msg: ReceiveMsg = from_binary(&Binary::from(
+            r#"{"buy_listed_token":{"token_id":"NFT1"}}"#.as_bytes(),
+        ))
+        .unwrap();
+        match receive_msg {
+            ReceiveMsg::Buy { token_id, .. } => assert_eq!(token_id, "NFT1".to_string()),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test the old name of the ListForSale message is still accepted
+    #[test]
+    fn test_list_token_alias() {
+        let handle_msg: HandleMsg = from_binary(&Binary::from(
+            r#"{"list_token":{"token_id":"NFT1","price":"100","denom":"uscrt"}}"#.as_bytes(),
+        ))
+        .unwrap();
+        match handle_msg {
+            HandleMsg::ListForSale {
+                token_id,
+                price,
+                denom,
+                expires,
+                ..
+            } => {
+                assert_eq!(token_id, "NFT1".to_string());
+                assert_eq!(price, Uint128(100));
+                assert_eq!(denom, Some("uscrt".to_string()));
+                assert!(expires.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test making, withdrawing and accepting offers
+    #[test]
+    fn test_offers() {
+        let (init_result, mut deps) = init_helper(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+        for address in ["alice", "bob", "charlie", "eve"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        let offer = |expires: Option<Expiration>| HandleMsg::MakeOffer {
+            token_id: "NFT1".to_string(),
+            expires,
+            padding: None,
+        };
+
+        // test an offer without coins
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), offer(None));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("An offer must be made by sending a single native coin"));
+        // test an offer on your own token
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &coins(1000, "uscrt")),
+            offer(None),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You can not make an offer on a token you own"));
+
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(1000, "uscrt")),
+            offer(None),
+        );
+        assert!(handle_result.unwrap().messages.is_empty());
+        // test a new offer refunds the previous one
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(1200, "uscrt")),
+            offer(None),
+        );
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![bank_send("bob", 1000)]
+        );
+        let handle_result = handle(
+            &mut deps,
+            mock_env("charlie", &coins(500, "uscrt")),
+            offer(Some(Expiration::AtHeight(20000))),
+        );
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("david", &coins(300, "uscrt")),
+            offer(None),
+        );
+        assert!(handle_result.is_ok());
+
+        // test offers are only visible to the owner and the bidder
+        let offers_seen_by = |deps: &Extern<MockStorage, MockApi, MockQuerier>, address: &str| {
+            let query_msg = QueryMsg::Offers {
+                token_id: "NFT1".to_string(),
+                viewer: ViewerInfo {
+                    address: HumanAddr(address.to_string()),
+                    viewing_key: "key".to_string(),
+                },
+            };
+            let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::Offers { offers } => offers,
+                _ => panic!("unexpected"),
+            }
+        };
+        assert_eq!(offers_seen_by(&deps, "alice").len(), 3);
+        assert_eq!(
+            offers_seen_by(&deps, "bob"),
+            vec![Offer {
+                bidder: HumanAddr("bob".to_string()),
+                amount: coins(1200, "uscrt")[0].clone(),
+                expires: Expiration::Never,
+            }]
+        );
+        assert!(offers_seen_by(&deps, "eve").is_empty());
+
+        // test withdrawing an offer
+        let handle_msg = HandleMsg::WithdrawOffer {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![bank_send("charlie", 500)]
+        );
+        let handle_msg = HandleMsg::WithdrawOffer {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("charlie", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You do not have an offer on token NFT1"));
+
+        // test accepting an offer that does not exist
+        let handle_msg = HandleMsg::AcceptOffer {
+            token_id: "NFT1".to_string(),
+            bidder: HumanAddr("eve".to_string()),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("eve does not have an offer on token NFT1"));
+        // test accepting by someone other than the owner
+        let handle_msg = HandleMsg::AcceptOffer {
+            token_id: "NFT1".to_string(),
+            bidder: HumanAddr("bob".to_string()),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test accepting pays royalties and refunds the other offers
+        let handle_msg = HandleMsg::AcceptOffer {
+            token_id: "NFT1".to_string(),
+            bidder: HumanAddr("bob".to_string()),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![
+                bank_send("creator", 60),
+                bank_send("platform", 30),
+                bank_send("alice", 1110),
+                bank_send("david", 300),
+            ]
+        );
+        assert_eq!(owner_of(&deps, "NFT1"), HumanAddr("bob".to_string()));
+        assert!(offers_seen_by(&deps, "bob").is_empty());
+
+        // test burning refunds the outstanding offers
+        let handle_result = handle(
+            &mut deps,
+            mock_env("eve", &coins(100, "uscrt")),
+            offer(None),
+        );
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert_eq!(handle_result.unwrap().messages, vec![bank_send("eve", 100)]);
+    }
+
+    // test the number of offers on a token is capped and bridged tokens can not get offers
+    #[test]
+    fn test_offer_limits() {
+        let (init_result, mut deps) = init_helper(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+        mint_with_royalties(&mut deps, "NFT2");
+        let offer = |token_id: &str, expires: Option<Expiration>| HandleMsg::MakeOffer {
+            token_id: token_id.to_string(),
+            expires,
+            padding: None,
+        };
+
+        // fill the offers, with the first one expiring
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bidder0", &coins(10, "uscrt")),
+            offer("NFT1", Some(Expiration::AtHeight(20000))),
+        );
+        assert!(handle_result.is_ok());
+        for i in 1..MAX_OFFERS_PER_TOKEN {
+            let handle_result = handle(
+                &mut deps,
+                mock_env(format!("bidder{}", i), &coins(10, "uscrt")),
+                offer("NFT1", None),
+            );
+            assert!(handle_result.is_ok());
+        }
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(10, "uscrt")),
+            offer("NFT1", None),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 already has the maximum of 30 offers"));
+        // test a bidder may still replace their own offer
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bidder1", &coins(20, "uscrt")),
+            offer("NFT1", None),
+        );
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![bank_send("bidder1", 10)]
+        );
+        // test an expired offer is refunded to make room
+        let mut env = mock_env("bob", &coins(10, "uscrt"));
+        env.block.height = 20000;
+        let handle_result = handle(&mut deps, env, offer("NFT1", None));
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![bank_send("bidder0", 10)]
+        );
+
+        // test offers on a token locked by the bridge
+        let handle_msg = HandleMsg::BridgeLock {
+            token_id: "NFT2".to_string(),
+            chain_nonce: 4,
+            destination_address: "0xdeadbeef".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(
+            &mut deps,
+            mock_env("bob", &coins(10, "uscrt")),
+            offer("NFT2", None),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 is locked by the bridge"));
+    }
+
+    // test starting, bidding on, settling and cancelling auctions
+    #[test]
+    fn test_auctions() {
+        let (init_result, mut deps) = init_helper(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_with_royalties(&mut deps, "NFT1");
+        mint_with_royalties(&mut deps, "NFT2");
+
+        let start = |token_id: &str, end_time: u64| HandleMsg::StartAuction {
+            token_id: token_id.to_string(),
+            denom: "uscrt".to_string(),
+            reserve_price: Uint128(100),
+            end_time,
+            padding: None,
+        };
+        let bid = || HandleMsg::PlaceBid {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        // test an auction that would already be over
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("alice", &[], 5000),
+            start("NFT1", 5000),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The auction end time has already passed"));
+        // test starting someone else's auction
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("bob", &[], 5000),
+            start("NFT1", 10000),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("alice", &[], 5000),
+            start("NFT1", 10000),
+        );
+        assert!(handle_result.is_ok());
+
+        // test the token is locked
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("alice", &[], 5000), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(100),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("alice", &[], 5000), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+
+        // test bidding on your own auction
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("alice", &coins(200, "uscrt"), 5000),
+            bid(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You can not bid on your own auction"));
+        // test bids in the wrong denom or below the reserve
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("bob", &coins(200, "uatom"), 5000),
+            bid(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("A bid must be made by sending a single uscrt coin"));
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("bob", &coins(50, "uscrt"), 5000),
+            bid(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bid must be at least the reserve price of 100uscrt"));
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("bob", &coins(100, "uscrt"), 5000),
+            bid(),
+        );
+        assert!(handle_result.unwrap().messages.is_empty());
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("charlie", &coins(100, "uscrt"), 5000),
+            bid(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The bid must be higher than the current highest bid of 100uscrt"));
+        // test a late bid refunds the outbid bidder and extends the auction
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("charlie", &coins(150, "uscrt"), 9800),
+            bid(),
+        );
+        assert_eq!(handle_result.unwrap().messages, vec![bank_send("bob", 100)]);
+        let query_msg = QueryMsg::AuctionOf {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::AuctionOf { auction } => assert_eq!(
+                auction,
+                Some(Auction {
+                    token_id: "NFT1".to_string(),
+                    seller: Some(HumanAddr("alice".to_string())),
+                    denom: "uscrt".to_string(),
+                    reserve_price: Uint128(100),
+                    end_time: 10400,
+                    highest_bid: Some(Uint128(150)),
+                    highest_bidder: Some(HumanAddr("charlie".to_string())),
+                })
+            ),
+            _ => panic!("unexpected"),
+        }
+
+        // test cancelling after a bid
+        let handle_msg = HandleMsg::CancelAuction {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("alice", &[], 9900), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("An auction can not be cancelled after a bid has been placed"));
+
+        // test settling before the extended end
+        let settle = || HandleMsg::SettleAuction {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("eve", &[], 10000), settle());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The auction of token ID: NFT1 has not ended yet"));
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("david", &coins(200, "uscrt"), 10400),
+            bid(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The auction of token ID: NFT1 has ended"));
+        // test settling pays royalties and transfers the token
+        let handle_result = handle(&mut deps, env_at_time("eve", &[], 10400), settle());
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![
+                bank_send("creator", 7),
+                bank_send("platform", 3),
+                bank_send("alice", 140),
+            ]
+        );
+        assert_eq!(owner_of(&deps, "NFT1"), HumanAddr("charlie".to_string()));
+        let handle_result = handle(&mut deps, env_at_time("eve", &[], 10400), settle());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not being auctioned"));
+
+        // test cancelling an auction without bids unlocks the token
+        let handle_result = handle(
+            &mut deps,
+            env_at_time("alice", &[], 5000),
+            start("NFT2", 10000),
+        );
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::Auctions {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Auctions { auctions } => {
+                assert_eq!(auctions.len(), 1);
+                assert_eq!(auctions[0].token_id, "NFT2".to_string());
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::CancelAuction {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("bob", &[], 5000), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+        let handle_msg = HandleMsg::CancelAuction {
+            token_id: "NFT2".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("alice", &[], 5000), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_time("alice", &[], 5000), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::Auctions {
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Auctions { auctions } => assert!(auctions.is_empty()),
+            _ => panic!("unexpected"),
+        }
+    }
+}
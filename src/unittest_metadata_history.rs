@@ -0,0 +1,311 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{
+        handle, init, query, DEFAULT_METADATA_HISTORY_LIMIT, MAX_METADATA_HISTORY_LIMIT,
+    };
+    use crate::msg::{
+        AccessLevel, HandleMsg, InitConfig, InitMsg, QueryAnswer, QueryMsg, ViewerInfo,
+    };
+    use crate::token::{Metadata, MetadataVersion};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Binary, Extern, HumanAddr, InitResponse, StdError, StdResult};
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_with_history_limit(
+        limit: u32,
+    ) -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+        let init_config: InitConfig = from_binary(&Binary::from(
+            format!(
+                "{{\"owner_may_update_metadata\":true,
+            \"metadata_history_limit\":{}}}",
+                limit
+            )
+            .as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn meta(uri: &str) -> Metadata {
+        Metadata {
+            token_uri: Some(uri.to_string()),
+            extension: None,
+        }
+    }
+
+    fn set_meta(
+        deps: &mut Extern<MockStorage, MockApi, MockQuerier>,
+        public: Option<&str>,
+        private: Option<&str>,
+    ) {
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT".to_string(),
+            public_metadata: public.map(meta),
+            private_metadata: private.map(meta),
+            padding: None,
+        };
+        let handle_result = handle(deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    fn query_history(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> StdResult<(u64, Vec<MetadataVersion>)> {
+        let query_msg = QueryMsg::MetadataHistory {
+            token_id: "NFT".to_string(),
+            viewer: Some(ViewerInfo {
+                address: HumanAddr(address.to_string()),
+                viewing_key: "key".to_string(),
+            }),
+            page,
+            page_size,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg)?)?;
+        match query_answer {
+            QueryAnswer::MetadataHistory { total, versions } => Ok((total, versions)),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test metadata updates are kept in a capped history with private versions only
+    // displayed to those permitted to view private metadata
+    #[test]
+    fn test_metadata_history() {
+        let (init_result, mut deps) = init_helper_with_history_limit(2);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(meta("pub0")),
+            private_metadata: Some(meta("priv0")),
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        for address in ["alice", "bob"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+
+        // no updates yet
+        let (total, versions) = query_history(&deps, "alice", None, None).unwrap();
+        assert_eq!(total, 0);
+        assert!(versions.is_empty());
+
+        set_meta(&mut deps, Some("pub1"), None);
+        set_meta(&mut deps, Some("pub2"), Some("priv2"));
+        set_meta(&mut deps, Some("pub3"), None);
+
+        // the oldest version was dropped because of the cap
+        let (total, versions) = query_history(&deps, "alice", None, None).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(
+            versions,
+            vec![
+                MetadataVersion {
+                    public_metadata: Some(meta("pub2")),
+                    private_metadata: None,
+                    updater: Some(HumanAddr("alice".to_string())),
+                    block_height: 12345,
+                    block_time: 1571797419,
+                },
+                MetadataVersion {
+                    public_metadata: Some(meta("pub1")),
+                    private_metadata: Some(meta("priv0")),
+                    updater: Some(HumanAddr("alice".to_string())),
+                    block_height: 12345,
+                    block_time: 1571797419,
+                },
+            ]
+        );
+
+        // bob may not see private versions or updaters
+        let (total, versions) = query_history(&deps, "bob", Some(1), Some(1)).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(
+            versions,
+            vec![MetadataVersion {
+                public_metadata: Some(meta("pub1")),
+                private_metadata: None,
+                updater: None,
+                block_height: 12345,
+                block_time: 1571797419,
+            }]
+        );
+
+        // a private-only version is hidden from bob
+        set_meta(&mut deps, None, Some("priv4"));
+        let (total, versions) = query_history(&deps, "bob", None, None).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(versions[0].public_metadata, Some(meta("pub2")));
+        let (total, versions) = query_history(&deps, "alice", None, None).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(versions[0].public_metadata, None);
+        assert_eq!(versions[0].private_metadata, Some(meta("priv2")));
+
+        // once bob may view private metadata, all versions are displayed
+        let handle_msg = HandleMsg::SetWhitelistedApproval {
+            address: HumanAddr("bob".to_string()),
+            token_id: Some("NFT".to_string()),
+            view_owner: None,
+            view_private_metadata: Some(AccessLevel::ApproveToken),
+            transfer: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let (total, versions) = query_history(&deps, "bob", None, None).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(versions[0].private_metadata, Some(meta("priv2")));
+
+        // bad viewing key
+        let query_msg = QueryMsg::MetadataHistory {
+            token_id: "NFT".to_string(),
+            viewer: Some(ViewerInfo {
+                address: HumanAddr("bob".to_string()),
+                viewing_key: "wrong".to_string(),
+            }),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+
+        // a limit of 0 disables the history
+        let (_init_result, mut deps) = init_helper_with_history_limit(0);
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: Some(meta("pub0")),
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        set_meta(&mut deps, Some("pub1"), None);
+        let (total, _versions) = query_history(&deps, "alice", None, None).unwrap();
+        assert_eq!(total, 0);
+    }
+
+    // test the history is capped by default and can not be configured above the maximum
+    #[test]
+    fn test_metadata_history_default_limit() {
+        let (init_result, _deps) = init_helper_with_history_limit(MAX_METADATA_HISTORY_LIMIT + 1);
+        let error = extract_error_msg(init_result);
+        assert!(error.contains("The metadata history limit can not be more than 100"));
+
+        let mut deps = mock_dependencies(20, &[]);
+        let init_config: InitConfig = from_binary(&Binary::from(
+            r#"{"owner_may_update_metadata":true}"#.as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+        let init_result = init(&mut deps, mock_env("instantiator", &[]), init_msg);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // setting metadata on a token that had none does not record an empty version
+        set_meta(&mut deps, Some("pub0"), None);
+        let (total, _versions) = query_history(&deps, "alice", None, None).unwrap();
+        assert_eq!(total, 0);
+
+        for i in 1..=DEFAULT_METADATA_HISTORY_LIMIT + 2 {
+            set_meta(&mut deps, Some(&format!("pub{}", i)), None);
+        }
+        let (total, versions) = query_history(&deps, "alice", None, Some(1)).unwrap();
+        assert_eq!(total, DEFAULT_METADATA_HISTORY_LIMIT as u64);
+        assert_eq!(
+            versions[0].public_metadata,
+            Some(meta(&format!("pub{}", DEFAULT_METADATA_HISTORY_LIMIT + 1)))
+        );
+    }
+}
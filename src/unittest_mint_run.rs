@@ -22,6 +22,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback: None,
         };
@@ -0,0 +1,291 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::msg::{HandleMsg, InitConfig, InitMsg, QueryAnswer, QueryMsg, ViewerInfo};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Binary, Extern, HumanAddr, InitResponse, StdError, StdResult, Uint128,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_with_burn() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+        let init_config: InitConfig =
+            from_binary(&Binary::from(r#"{"enable_burn":true}"#.as_bytes())).unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn viewer(address: &str) -> Option<ViewerInfo> {
+        Some(ViewerInfo {
+            address: HumanAddr(address.to_string()),
+            viewing_key: "key".to_string(),
+        })
+    }
+
+    fn attach(token_id: &str, parent_token_id: &str) -> HandleMsg {
+        HandleMsg::AttachToParent {
+            token_id: token_id.to_string(),
+            parent_token_id: parent_token_id.to_string(),
+            padding: None,
+        }
+    }
+
+    fn tokens_of(deps: &Extern<MockStorage, MockApi, MockQuerier>, owner: &str) -> Vec<String> {
+        let query_msg = QueryMsg::Tokens {
+            owner: HumanAddr(owner.to_string()),
+            viewer: None,
+            viewing_key: Some("key".to_string()),
+            start_after: None,
+            limit: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TokenList { tokens } => tokens,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn family_of(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        token_id: &str,
+        address: &str,
+    ) -> (Option<String>, Vec<String>) {
+        let query_msg = QueryMsg::NftDossier {
+            token_id: token_id.to_string(),
+            viewer: viewer(address),
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                parent, children, ..
+            } => (parent, children),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test attaching tokens to a parent, moving them with the parent, and detaching them
+    #[test]
+    fn test_attach_to_parent() {
+        let (init_result, mut deps) = init_helper_with_burn();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in ["NFT1", "NFT2", "NFT3"].iter() {
+            let handle_msg = HandleMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                transferable: None,
+                origin: None,
+                memo: None,
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        for address in ["alice", "bob", "charlie"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+
+        // test only the owner may attach
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), attach("NFT2", "NFT1"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT2"));
+
+        // character NFT1 holds sword NFT2, which holds gem NFT3
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), attach("NFT2", "NFT1"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), attach("NFT3", "NFT2"));
+        assert!(handle_result.is_ok());
+
+        // test cycles are rejected
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), attach("NFT1", "NFT3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Attaching token NFT1 to token NFT3 would create a cycle"));
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), attach("NFT1", "NFT1"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("would create a cycle"));
+
+        // test a token can only have one parent
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), attach("NFT3", "NFT1"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT3 is attached to a parent token"));
+
+        // test attached tokens can not be transferred or burned on their own
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 is attached to a parent token"));
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT3 is attached to a parent token"));
+
+        // test a parent can not be burned while it has attached tokens
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 has attached tokens that must be detached first"));
+
+        // test the dossier displays the attached tokens only to those who may see the owner
+        assert_eq!(
+            family_of(&deps, "NFT1", "alice"),
+            (None, vec!["NFT2".to_string()])
+        );
+        assert_eq!(
+            family_of(&deps, "NFT2", "alice"),
+            (Some("NFT1".to_string()), vec!["NFT3".to_string()])
+        );
+        assert_eq!(family_of(&deps, "NFT2", "bob"), (None, Vec::new()));
+
+        // test nothing can be detached while the root token is listed or auctioned
+        let detach = || HandleMsg::DetachFromParent {
+            token_id: "NFT3".to_string(),
+            padding: None,
+        };
+        let handle_msg = HandleMsg::ListForSale {
+            token_id: "NFT1".to_string(),
+            price: Uint128(1000),
+            denom: Some("uscrt".to_string()),
+            payment_token: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), detach());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT3 can not be detached while token NFT1 is listed"));
+        let handle_msg = HandleMsg::CancelListing {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::StartAuction {
+            token_id: "NFT1".to_string(),
+            denom: "uscrt".to_string(),
+            reserve_price: Uint128(100),
+            end_time: mock_env("alice", &[]).block.time + 1000,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), detach());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+        let handle_msg = HandleMsg::CancelAuction {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test transferring the parent carries all its descendants
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert!(tokens_of(&deps, "alice").is_empty());
+        assert_eq!(
+            tokens_of(&deps, "bob"),
+            vec!["NFT1".to_string(), "NFT2".to_string(), "NFT3".to_string()]
+        );
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "NFT3".to_string(),
+            viewer: viewer("bob"),
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => {
+                assert_eq!(owner, HumanAddr("bob".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test only the owner may detach
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), detach());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT3"));
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), detach());
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), detach());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT3 is not attached to a parent token"));
+        assert_eq!(
+            family_of(&deps, "NFT2", "bob"),
+            (Some("NFT1".to_string()), Vec::new())
+        );
+
+        // test a detached token moves on its own again
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(tokens_of(&deps, "charlie"), vec!["NFT3".to_string()]);
+        assert_eq!(
+            tokens_of(&deps, "bob"),
+            vec!["NFT1".to_string(), "NFT2".to_string()]
+        );
+    }
+}
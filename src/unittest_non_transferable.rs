@@ -5,7 +5,7 @@ mod tests {
     use crate::inventory::Inventory;
     use crate::msg::{
         Burn, ContractStatus, HandleMsg, InitConfig, InitMsg, Mint, PostInitCallback, QueryAnswer,
-        QueryMsg, Send, Transfer,
+        QueryMsg, Send, Transfer, TxAction,
     };
     use crate::royalties::{DisplayRoyalty, DisplayRoyaltyInfo, Royalty, RoyaltyInfo};
     use crate::state::{
@@ -36,6 +36,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback: None,
         };
@@ -84,6 +85,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: Some(init_config),
             post_init_callback: None,
         };
@@ -133,6 +135,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info,
+            collection_info: None,
             config: Some(init_config),
             post_init_callback: None,
         };
@@ -223,6 +226,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback,
         };
@@ -357,6 +361,7 @@ mod tests {
                 royalty_info: Some(royalties.clone()),
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -367,6 +372,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -452,6 +458,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -462,6 +469,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -570,6 +578,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -580,6 +589,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -590,6 +600,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -662,6 +673,276 @@ mod tests {
         assert!(!inventory.contains(&deps.storage, 2).unwrap());
     }
 
+    #[test]
+    fn test_recover_non_transferable() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let tok_key = 0u32.to_le_bytes();
+        let alice = HumanAddr("alice".to_string());
+        let alice_raw = deps.api.canonical_address(&alice).unwrap();
+        let bob = HumanAddr("bob".to_string());
+        let bob_raw = deps.api.canonical_address(&bob).unwrap();
+        let admin = HumanAddr("admin".to_string());
+
+        let mints = vec![
+            Mint {
+                token_id: Some("NFT1".to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                transferable: Some(false),
+                serial_number: None,
+                origin: None,
+                memo: None,
+            },
+            Mint {
+                token_id: Some("NFT2".to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                transferable: None,
+                serial_number: None,
+                origin: None,
+                memo: None,
+            },
+        ];
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test the owner can not recover their own non-transferable token
+        let handle_msg = HandleMsg::RecoverNonTransferable {
+            token_id: "NFT1".to_string(),
+            new_owner: bob.clone(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not the issuer of token NFT1"));
+
+        // test the issuer can not recover a transferable token
+        let handle_msg = HandleMsg::RecoverNonTransferable {
+            token_id: "NFT2".to_string(),
+            new_owner: bob.clone(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not the issuer of token NFT2"));
+
+        // test recovering to the current owner
+        let handle_msg = HandleMsg::RecoverNonTransferable {
+            token_id: "NFT1".to_string(),
+            new_owner: alice.clone(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("to the address that already owns it"));
+
+        // attach a transferable token so it has to move with the recovered parent
+        let handle_msg = HandleMsg::AttachToParent {
+            token_id: "NFT2".to_string(),
+            parent_token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // sanity check
+        let handle_msg = HandleMsg::RecoverNonTransferable {
+            token_id: "NFT1".to_string(),
+            new_owner: bob.clone(),
+            memo: Some("lost wallet".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Token = json_may_load(&info_store, &tok_key).unwrap().unwrap();
+        assert_eq!(token.owner, bob_raw);
+        assert!(!token.transferable);
+        // verify the attached token moved with its parent
+        let token: Token = json_may_load(&info_store, &1u32.to_le_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(token.owner, bob_raw);
+        let inventory = Inventory::new(&deps.storage, alice_raw).unwrap();
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+        assert!(!inventory.contains(&deps.storage, 1).unwrap());
+        let inventory = Inventory::new(&deps.storage, bob_raw).unwrap();
+        assert!(inventory.contains(&deps.storage, 0).unwrap());
+        assert!(inventory.contains(&deps.storage, 1).unwrap());
+
+        // verify the recovery is recorded in the new owner's history
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let query_msg = QueryMsg::TransactionHistory {
+            address: bob.clone(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                // the recovery and the attached token's transfer
+                assert_eq!(total, 2);
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::Recover {
+                        from: alice.clone(),
+                        issuer: admin.clone(),
+                        recipient: bob.clone(),
+                    }
+                );
+                assert_eq!(txs[0].memo, Some("lost wallet".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_revoke_credential() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let tok_key = 0u32.to_le_bytes();
+        let alice = HumanAddr("alice".to_string());
+        let alice_raw = deps.api.canonical_address(&alice).unwrap();
+        let admin = HumanAddr("admin".to_string());
+
+        let mints = vec![
+            Mint {
+                token_id: Some("NFT1".to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                transferable: Some(false),
+                serial_number: None,
+                origin: None,
+                memo: None,
+            },
+            Mint {
+                token_id: Some("NFT2".to_string()),
+                owner: Some(alice.clone()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                transferable: None,
+                serial_number: None,
+                origin: None,
+                memo: None,
+            },
+        ];
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test someone other than the issuer can not revoke
+        let handle_msg = HandleMsg::RevokeCredential {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not the issuer of token NFT1"));
+
+        // test a transferable token can not be revoked when burn is disabled
+        let handle_msg = HandleMsg::RevokeCredential {
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not the issuer of token NFT2"));
+
+        // test private token supply does not leak nonexistent tokens
+        let handle_msg = HandleMsg::RevokeCredential {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not the issuer of token NFT3"));
+
+        // sanity check
+        let handle_msg = HandleMsg::RevokeCredential {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let map2idx = ReadonlyPrefixedStorage::new(PREFIX_MAP_TO_INDEX, &deps.storage);
+        let index: Option<u32> = may_load(&map2idx, "NFT1".as_bytes()).unwrap();
+        assert!(index.is_none());
+        let info_store = ReadonlyPrefixedStorage::new(PREFIX_INFOS, &deps.storage);
+        let token: Option<Token> = json_may_load(&info_store, &tok_key).unwrap();
+        assert!(token.is_none());
+        let inventory = Inventory::new(&deps.storage, alice_raw).unwrap();
+        assert_eq!(inventory.info.count, 1);
+        assert!(!inventory.contains(&deps.storage, 0).unwrap());
+
+        // verify the revocation is recorded in the owner's history
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let query_msg = QueryMsg::TransactionHistory {
+            address: alice.clone(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 3);
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::Revoke {
+                        owner: alice.clone(),
+                        issuer: admin.clone(),
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
     // query tests
 
     // test new transferable and unwrapped fields of NftDossier query
@@ -703,6 +984,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: Some(false),
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -726,6 +1008,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -734,6 +1021,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert!(private_metadata.is_none());
@@ -813,6 +1105,7 @@ mod tests {
                 royalty_info: None,
                 transferable: Some(false),
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -823,6 +1116,7 @@ mod tests {
                 royalty_info: None,
                 transferable: None,
                 serial_number: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -901,6 +1195,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: Some(false),
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -0,0 +1,373 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::msg::{HandleAnswer, HandleMsg, InitMsg, Mint, QueryAnswer, QueryMsg};
+    use crate::provenance::{chain_link, StoredProvenance};
+    use crate::sale::SaleConfig;
+    use crate::state::{load, PRNG_SEED_KEY, PROVENANCE_KEY};
+    use crate::token::Metadata;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coins, from_binary, Binary, Extern, HandleResponse, HumanAddr, InitResponse, StdError,
+        StdResult, Uint128,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn mint_blind(deps: &mut Extern<MockStorage, MockApi, MockQuerier>, count: u32) {
+        let mints = (0..count)
+            .map(|i| Mint {
+                token_id: Some(format!("T{}", i)),
+                owner: Some(HumanAddr("alice".to_string())),
+                public_metadata: None,
+                private_metadata: None,
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                origin: None,
+                memo: None,
+            })
+            .collect();
+        let handle_msg = HandleMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        let handle_result = handle(deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+
+    fn meta(i: u32) -> Metadata {
+        Metadata {
+            token_uri: Some(format!("meta {}", i)),
+            extension: None,
+        }
+    }
+
+    // returns the chain links of the metadata list, where links[i] is the link of entry i
+    // and the last element is the zero link following the last entry
+    fn chain(metas: &[Metadata]) -> Vec<Binary> {
+        let mut links = vec![vec![0u8; 32]];
+        for m in metas.iter().rev() {
+            let next = chain_link(m, links.last().unwrap()).unwrap().to_vec();
+            links.push(next);
+        }
+        links.into_iter().rev().map(Binary).collect()
+    }
+
+    fn reveal_msg(metas: &[Metadata], next_link: &Binary) -> HandleMsg {
+        HandleMsg::RevealCommittedMetadata {
+            metadata: metas.to_vec(),
+            next_link: next_link.clone(),
+            padding: None,
+        }
+    }
+
+    fn revealed_count(resp: StdResult<HandleResponse>) -> (u32, u32) {
+        let handle_answer: HandleAnswer = from_binary(&resp.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::RevealCommittedMetadata {
+                revealed,
+                remaining,
+            } => (revealed, remaining),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test committing to shuffled metadata and revealing it in verified chunks
+    #[test]
+    fn test_commit_reveal_metadata() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let metas: Vec<Metadata> = (0..4).map(meta).collect();
+        let links = chain(&metas);
+
+        // test revealing before committing
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[..2], &links[2]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("No metadata has been committed"));
+
+        // test non-admin commit
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[0].clone(),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test invalid commitment
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: Binary(vec![1u8; 31]),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata commitment must be a 32-byte sha256 hash"));
+
+        // sanity check
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[0].clone(),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, QueryMsg::MetadataCommitment {}).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MetadataCommitment {
+                commitment,
+                count,
+                offset,
+                revealed,
+            } => {
+                assert_eq!(commitment, Some(links[0].clone()));
+                assert_eq!(count, 4);
+                assert!(offset.is_none());
+                assert_eq!(revealed, 0);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test committing twice
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[1].clone(),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata has already been committed"));
+        mint_blind(&mut deps, 4);
+
+        // test revealing out of order
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[1..2], &links[2]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata does not match the commitment"));
+
+        // test revealing altered metadata
+        let altered = vec![meta(0), meta(5)];
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&altered, &links[2]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The metadata does not match the commitment"));
+
+        // sanity check: reveal the first chunk
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[..2], &links[2]),
+        );
+        assert_eq!(revealed_count(handle_result), (2, 2));
+
+        // test revealing too many entries
+        let too_many = vec![meta(2), meta(3), meta(0)];
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&too_many, &links[4]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Expected between 1 and 2 metadata entries"));
+
+        // test last chunk not followed by the zero link
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[2..], &links[0]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error
+            .contains("The last chunk of metadata must be followed by a link of 32 zero bytes"));
+
+        // sanity check: reveal the last chunk
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[2..], &links[4]),
+        );
+        assert_eq!(revealed_count(handle_result), (4, 0));
+
+        // verify the assignment using the published offset
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, QueryMsg::MetadataCommitment {}).unwrap()).unwrap();
+        let offset = match query_answer {
+            QueryAnswer::MetadataCommitment {
+                offset, revealed, ..
+            } => {
+                assert_eq!(revealed, 4);
+                offset.unwrap()
+            }
+            _ => panic!("unexpected"),
+        };
+        assert!(offset < 4);
+        for (position, m) in metas.iter().enumerate() {
+            let idx = (position as u32 + offset) % 4;
+            let query_msg = QueryMsg::NftInfo {
+                token_id: format!("T{}", idx),
+            };
+            let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+            match query_answer {
+                QueryAnswer::NftInfo { token_uri, .. } => assert_eq!(token_uri, m.token_uri),
+                _ => panic!("unexpected"),
+            }
+        }
+
+        // test everything has been revealed
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[3..], &links[4]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("All committed metadata has already been revealed"));
+
+        // test committing after tokens have been minted
+        let (_init_result, mut deps) = init_helper_default();
+        mint_blind(&mut deps, 1);
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[0].clone(),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata must be committed before any tokens are minted"));
+
+        // test revealing before all committed tokens are minted
+        let (_init_result, mut deps) = init_helper_default();
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[0].clone(),
+            count: 4,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        mint_blind(&mut deps, 3);
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[..2], &links[2]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error
+            .contains("All 4 committed tokens must be minted before their metadata is revealed"));
+    }
+
+    // test purchases mix their entropy into the assignment offset
+    #[test]
+    fn test_purchase_entropy() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let metas: Vec<Metadata> = (0..2).map(meta).collect();
+        let links = chain(&metas);
+        let handle_msg = HandleMsg::CommitMetadata {
+            commitment: links[0].clone(),
+            count: 2,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: Some(SaleConfig {
+                denom: "uscrt".to_string(),
+                price: Uint128(10),
+                limit_per_address: None,
+                start: None,
+                end: None,
+            }),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let provenance: StoredProvenance = load(&deps.storage, PROVENANCE_KEY).unwrap();
+        assert_eq!(provenance.entropy, links[0].0);
+        let mut entropies = vec![provenance.entropy];
+
+        for buyer in ["alice", "bob"].iter() {
+            let handle_msg = HandleMsg::PurchaseMint {
+                quantity: None,
+                entropy: Some(format!("{} was here", buyer)),
+                memo: None,
+                padding: None,
+            };
+            let handle_result =
+                handle(&mut deps, mock_env(*buyer, &coins(10, "uscrt")), handle_msg);
+            assert!(handle_result.is_ok());
+            let provenance: StoredProvenance = load(&deps.storage, PROVENANCE_KEY).unwrap();
+            assert!(!entropies.contains(&provenance.entropy));
+            entropies.push(provenance.entropy);
+        }
+
+        // the offset mixes the prng seed, the accumulated entropy, the commit height and the
+        // block of the first reveal
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            reveal_msg(&metas[..1], &links[1]),
+        );
+        assert_eq!(revealed_count(handle_result), (1, 1));
+        let provenance: StoredProvenance = load(&deps.storage, PROVENANCE_KEY).unwrap();
+        let prng_seed: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY).unwrap();
+        let block = mock_env("admin", &[]).block;
+        assert_eq!(
+            provenance.offset,
+            Some(provenance.generate_offset(&prng_seed, &block))
+        );
+    }
+}
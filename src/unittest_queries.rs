@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use crate::collection::CollectionInfo;
     use crate::contract::{handle, init, query};
     use crate::expiration::Expiration;
     use crate::mint_run::MintRunInfo;
@@ -30,6 +31,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback: None,
         };
@@ -78,6 +80,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: Some(init_config),
             post_init_callback: None,
         };
@@ -113,12 +116,129 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::ContractInfo { name, symbol } => {
+            QueryAnswer::ContractInfo {
+                name,
+                symbol,
+                collection_info,
+            } => {
                 assert_eq!(name, "sec721".to_string());
                 assert_eq!(symbol, "S721".to_string());
+                assert!(collection_info.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test setting and querying collection-level metadata
+    #[test]
+    fn test_query_collection_info() {
+        let collection = CollectionInfo {
+            description: Some("A collection".to_string()),
+            image: Some("image uri".to_string()),
+            external_link: Some("website".to_string()),
+            banner_image: Some("banner uri".to_string()),
+            category: Some("art".to_string()),
+            seller_fee_basis_points: Some(250),
+            fee_recipient: Some(HumanAddr("alice".to_string())),
+        };
+        let init_msg = |collection_info: CollectionInfo| InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: Some(collection_info),
+            config: None,
+            post_init_callback: None,
+        };
+
+        // test seller fee above 100%
+        let mut deps = mock_dependencies(20, &[]);
+        let init_result = init(
+            &mut deps,
+            mock_env("instantiator", &[]),
+            init_msg(CollectionInfo {
+                seller_fee_basis_points: Some(10001),
+                ..collection.clone()
+            }),
+        );
+        let error = extract_error_msg(init_result);
+        assert!(error.contains("Seller fee basis points can not exceed 10000"));
+
+        // sanity check
+        let mut deps = mock_dependencies(20, &[]);
+        let init_result = init(
+            &mut deps,
+            mock_env("instantiator", &[]),
+            init_msg(collection.clone()),
+        );
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::CollectionInfo {};
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::CollectionInfo { collection_info } => {
+                assert_eq!(collection_info, Some(collection.clone()))
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::ContractInfo {};
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::ContractInfo {
+                collection_info, ..
+            } => assert_eq!(collection_info, Some(collection.clone())),
+            _ => panic!("unexpected"),
+        }
+
+        // test non-admin update
+        let handle_msg = HandleMsg::SetCollectionInfo {
+            collection_info: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test admin update
+        let updated = CollectionInfo {
+            description: Some("Updated".to_string()),
+            fee_recipient: None,
+            ..collection
+        };
+        let handle_msg = HandleMsg::SetCollectionInfo {
+            collection_info: Some(updated.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::CollectionInfo {};
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::CollectionInfo { collection_info } => {
+                assert_eq!(collection_info, Some(updated))
             }
             _ => panic!("unexpected"),
         }
+
+        // test deleting the collection info
+        let handle_msg = HandleMsg::SetCollectionInfo {
+            collection_info: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::CollectionInfo {};
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::CollectionInfo { collection_info } => assert!(collection_info.is_none()),
+            _ => panic!("unexpected"),
+        }
     }
 
     // test ContractConfig query
@@ -148,6 +268,7 @@ mod tests {
                 minter_may_update_metadata,
                 owner_may_update_metadata,
                 burn_is_enabled,
+                metadata_is_frozen,
             } => {
                 assert_eq!(token_supply_is_public, false);
                 assert_eq!(owner_is_public, true);
@@ -156,6 +277,7 @@ mod tests {
                 assert_eq!(minter_may_update_metadata, true);
                 assert_eq!(owner_may_update_metadata, false);
                 assert_eq!(burn_is_enabled, true);
+                assert_eq!(metadata_is_frozen, false);
             }
             _ => panic!("unexpected"),
         }
@@ -233,6 +355,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -253,6 +376,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -341,6 +465,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -361,6 +486,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -414,6 +540,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -434,6 +561,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -454,6 +582,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -551,6 +680,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -571,6 +701,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -591,6 +722,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -611,6 +743,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -631,6 +764,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -772,6 +906,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -834,6 +969,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -842,6 +982,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert!(private_metadata.is_none());
@@ -879,6 +1024,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -938,6 +1084,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -946,6 +1097,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert_eq!(private_metadata, Some(private_meta.clone()));
@@ -1039,6 +1195,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -1047,6 +1208,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert_eq!(private_metadata, Some(private_meta.clone()));
@@ -1143,6 +1309,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -1151,6 +1322,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert_eq!(private_metadata, Some(private_meta.clone()));
@@ -1189,6 +1365,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -1197,6 +1378,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert_eq!(private_metadata, Some(private_meta.clone()));
@@ -1248,6 +1434,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1322,6 +1509,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -1330,6 +1522,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert_eq!(owner, Some(alice.clone()));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert!(private_metadata.is_none());
@@ -1377,6 +1574,11 @@ mod tests {
                 mint_run_info: _,
                 transferable,
                 unwrapped,
+                bridge_locked,
+                metadata_frozen,
+                user,
+                parent,
+                children,
                 display_private_metadata_error,
                 owner_is_public,
                 public_ownership_expiration,
@@ -1385,6 +1587,11 @@ mod tests {
                 token_approvals,
                 inventory_approvals,
             } => {
+                assert!(!bridge_locked);
+                assert!(!metadata_frozen);
+                assert!(user.is_none());
+                assert!(parent.is_none());
+                assert!(children.is_empty());
                 assert!(owner.is_none());
                 assert_eq!(public_metadata, Some(public_meta.clone()));
                 assert!(private_metadata.is_none());
@@ -1436,6 +1643,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1448,6 +1656,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1460,6 +1669,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1472,6 +1682,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1484,6 +1695,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1496,6 +1708,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1508,6 +1721,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1520,6 +1734,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1767,6 +1982,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1779,6 +1995,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1791,6 +2008,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1803,6 +2021,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1815,6 +2034,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1827,6 +2047,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1839,6 +2060,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1851,6 +2073,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -1863,6 +2086,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2066,6 +2290,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2123,6 +2348,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2196,6 +2422,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2447,6 +2674,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2517,6 +2745,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2532,6 +2761,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2573,6 +2803,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2643,6 +2874,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2739,6 +2971,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -2924,6 +3157,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3343,6 +3577,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3355,6 +3590,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3367,6 +3603,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3379,6 +3616,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3391,6 +3629,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3537,6 +3776,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -3549,6 +3789,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: Some("Mint 2".to_string()),
             padding: None,
         };
@@ -3789,6 +4030,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -3799,6 +4041,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -3809,6 +4052,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -4347,6 +4591,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -4357,6 +4602,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
             Mint {
@@ -4367,6 +4613,7 @@ mod tests {
                 royalty_info: None,
                 serial_number: None,
                 transferable: None,
+                origin: None,
                 memo: None,
             },
         ];
@@ -4414,6 +4661,11 @@ mod tests {
                 mint_run_info: Some(mint_run_info.clone()),
                 transferable: true,
                 unwrapped: true,
+                bridge_locked: false,
+                metadata_frozen: false,
+                user: None,
+                parent: None,
+                children: vec![],
                 owner_is_public: false,
                 public_ownership_expiration: None,
                 private_metadata_is_public: false,
@@ -4431,6 +4683,11 @@ mod tests {
                 mint_run_info: Some(mint_run_info.clone()),
                 transferable: true,
                 unwrapped: true,
+                bridge_locked: false,
+                metadata_frozen: false,
+                user: None,
+                parent: None,
+                children: vec![],
                 owner_is_public: false,
                 public_ownership_expiration: None,
                 private_metadata_is_public: false,
@@ -4451,6 +4708,11 @@ mod tests {
                 mint_run_info: Some(mint_run_info.clone()),
                 transferable: true,
                 unwrapped: true,
+                bridge_locked: false,
+                metadata_frozen: false,
+                user: None,
+                parent: None,
+                children: vec![],
                 owner_is_public: false,
                 public_ownership_expiration: None,
                 private_metadata_is_public: false,
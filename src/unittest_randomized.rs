@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init};
+    use crate::msg::{HandleAnswer, HandleMsg, InitMsg, TraitTable, WeightedValue};
+    use crate::state::{load, PREFIX_PRIV_META, PREFIX_PUB_META, PRNG_SEED_KEY};
+    use crate::token::{Metadata, Trait};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Extern, HumanAddr, InitResponse, StdError, StdResult};
+    use cosmwasm_storage::ReadonlyPrefixedStorage;
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn table(trait_type: &str, values: &[(&str, u32)], private: bool) -> TraitTable {
+        TraitTable {
+            trait_type: trait_type.to_string(),
+            display_type: None,
+            values: values
+                .iter()
+                .map(|(value, weight)| WeightedValue {
+                    value: value.to_string(),
+                    weight: *weight,
+                })
+                .collect(),
+            private: Some(private),
+        }
+    }
+
+    fn randomized_msg(
+        token_id: &str,
+        public_metadata: Option<Metadata>,
+        trait_tables: Vec<TraitTable>,
+    ) -> HandleMsg {
+        HandleMsg::MintRandomized {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata,
+            private_metadata: None,
+            trait_tables,
+            entropy: "pseudo".to_string(),
+            memo: None,
+            padding: None,
+        }
+    }
+
+    fn chosen_trait(trait_type: &str, value: &str) -> Trait {
+        Trait {
+            display_type: None,
+            trait_type: Some(trait_type.to_string()),
+            value: value.to_string(),
+            max_value: None,
+        }
+    }
+
+    // test minting tokens with randomly chosen traits
+    #[test]
+    fn test_mint_randomized() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let tables = || {
+            vec![
+                table("Background", &[("Gold", 0), ("Blue", 3)], false),
+                table("Power", &[("High", 2), ("Low", 0)], true),
+            ]
+        };
+
+        // test non-minter
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            randomized_msg("NFT1", None, tables()),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to mint"));
+
+        // test table without a positive weight
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            randomized_msg("NFT1", None, vec![table("Hat", &[("Cap", 0)], false)]),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Trait table Hat must have at least one value with a positive weight")
+        );
+
+        // sanity check
+        let seed_before: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY).unwrap();
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            randomized_msg("NFT1", None, tables()),
+        );
+        let handle_answer: HandleAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            HandleAnswer::MintRandomized { token_id } => assert_eq!(token_id, "NFT1"),
+            _ => panic!("unexpected"),
+        }
+        let token_key = 0u32.to_le_bytes();
+        let pub_store = ReadonlyPrefixedStorage::new(PREFIX_PUB_META, &deps.storage);
+        let pub_meta: Metadata = load(&pub_store, &token_key).unwrap();
+        assert_eq!(
+            pub_meta.extension.unwrap().attributes,
+            Some(vec![chosen_trait("Background", "Blue")])
+        );
+        let priv_store = ReadonlyPrefixedStorage::new(PREFIX_PRIV_META, &deps.storage);
+        let priv_meta: Metadata = load(&priv_store, &token_key).unwrap();
+        assert_eq!(
+            priv_meta.extension.unwrap().attributes,
+            Some(vec![chosen_trait("Power", "High")])
+        );
+
+        // test the seed advances with every randomized mint
+        let seed_after: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY).unwrap();
+        assert_ne!(seed_before, seed_after);
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            randomized_msg("NFT2", None, tables()),
+        );
+        assert!(handle_result.is_ok());
+        let seed_last: Vec<u8> = load(&deps.storage, PRNG_SEED_KEY).unwrap();
+        assert_ne!(seed_after, seed_last);
+
+        // test chosen traits can not be added to metadata with a token_uri
+        let uri_meta = Metadata {
+            token_uri: Some("uri".to_string()),
+            extension: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            mock_env("admin", &[]),
+            randomized_msg("NFT3", Some(uri_meta), tables()),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata can not have BOTH token_uri AND extension"));
+    }
+}
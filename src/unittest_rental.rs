@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{HandleMsg, InitMsg, QueryAnswer, QueryMsg, ViewerInfo};
+    use crate::token::{Metadata, TokenUser};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Env, Extern, HumanAddr, InitResponse, QueryResult, StdError, StdResult,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn env_at_height(sender: &str, height: u64) -> Env {
+        let mut env = mock_env(sender, &[]);
+        env.block.height = height;
+        env
+    }
+
+    fn viewer(address: &str) -> Option<ViewerInfo> {
+        Some(ViewerInfo {
+            address: HumanAddr(address.to_string()),
+            viewing_key: "key".to_string(),
+        })
+    }
+
+    fn query_private_meta(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+    ) -> QueryResult {
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "NFT1".to_string(),
+            viewer: viewer(address),
+        };
+        query(deps, query_msg)
+    }
+
+    fn query_user_of(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+    ) -> StdResult<Option<TokenUser>> {
+        let query_msg = QueryMsg::UserOf {
+            token_id: "NFT1".to_string(),
+            viewer: viewer(address),
+        };
+        let query_answer: QueryAnswer = from_binary(&query(deps, query_msg)?)?;
+        match query_answer {
+            QueryAnswer::UserOf { user } => Ok(user),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test renting a token grants the user private metadata access until the rental expires
+    #[test]
+    fn test_set_user() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: Some("game item".to_string()),
+                extension: None,
+            }),
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        for address in ["alice", "bob", "charlie"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+            assert!(handle_result.is_ok());
+        }
+        let rent = |user: &str, expires: Option<Expiration>| HandleMsg::SetUser {
+            token_id: "NFT1".to_string(),
+            user: Some(HumanAddr(user.to_string())),
+            expires,
+            padding: None,
+        };
+
+        // test renting a token you do not own
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), rent("bob", None));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        // test a rental that already expired
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            rent("bob", Some(Expiration::AtHeight(100))),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The rental expiration has already passed"));
+        let handle_result = handle(
+            &mut deps,
+            mock_env("alice", &[]),
+            rent("bob", Some(Expiration::AtHeight(20000))),
+        );
+        assert!(handle_result.is_ok());
+
+        // test the user may view the private metadata but others may not
+        let query_answer: QueryAnswer =
+            from_binary(&query_private_meta(&deps, "bob").unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PrivateMetadata { token_uri, .. } => {
+                assert_eq!(token_uri, Some("game item".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+        let error = extract_error_msg(query_private_meta(&deps, "charlie"));
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test the user is visible to the user and the owner
+        let bob_user = Some(TokenUser {
+            address: HumanAddr("bob".to_string()),
+            expires: Expiration::AtHeight(20000),
+        });
+        assert_eq!(query_user_of(&deps, "bob").unwrap(), bob_user);
+        assert_eq!(query_user_of(&deps, "alice").unwrap(), bob_user);
+        let error = extract_error_msg(query_user_of(&deps, "charlie"));
+        assert!(error.contains("You are not authorized to view the user of token NFT1"));
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: viewer("bob"),
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftDossier {
+                owner,
+                private_metadata,
+                user,
+                ..
+            } => {
+                assert!(owner.is_none());
+                assert!(private_metadata.is_some());
+                assert_eq!(user, bob_user);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the user can not transfer the token
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("bob".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("bob", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test the rental expires
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_height("alice", 20000), handle_msg);
+        assert!(handle_result.is_ok());
+        let error = extract_error_msg(query_private_meta(&deps, "bob"));
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+        assert_eq!(query_user_of(&deps, "alice").unwrap(), None);
+
+        // test transferring ends the rental
+        let handle_result = handle(&mut deps, env_at_height("alice", 20000), rent("bob", None));
+        assert!(handle_result.is_ok());
+        assert!(query_private_meta(&deps, "bob").is_ok());
+        let handle_msg = HandleMsg::TransferNft {
+            recipient: HumanAddr("charlie".to_string()),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_height("alice", 20000), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(query_user_of(&deps, "charlie").unwrap(), None);
+        assert!(query_private_meta(&deps, "bob").is_err());
+
+        // test removing the user
+        let handle_result = handle(
+            &mut deps,
+            env_at_height("charlie", 20000),
+            rent("bob", None),
+        );
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetUser {
+            token_id: "NFT1".to_string(),
+            user: None,
+            expires: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at_height("charlie", 20000), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(query_user_of(&deps, "charlie").unwrap(), None);
+    }
+}
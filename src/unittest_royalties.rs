@@ -31,6 +31,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info,
+            collection_info: None,
             config: None,
             post_init_callback: None,
         };
@@ -80,6 +81,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info,
+            collection_info: None,
             config: Some(init_config),
             post_init_callback: None,
         };
@@ -285,6 +287,7 @@ mod tests {
             admin: Some(HumanAddr("admin".to_string())),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
+            collection_info: None,
             config: None,
             post_init_callback,
         };
@@ -607,6 +610,7 @@ mod tests {
             royalty_info: None,
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -655,6 +659,7 @@ mod tests {
             royalty_info: Some(individual.clone()),
             serial_number: None,
             transferable: None,
+            origin: None,
             memo: None,
             padding: None,
         };
@@ -0,0 +1,1033 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{
+        HandleAnswer, HandleMsg, InitConfig, InitMsg, QueryAnswer, QueryMsg, ReceiveMsg, ViewerInfo,
+    };
+    use crate::sale::{
+        register_receive_msg, snip20_transfer_msg, PaymentToken, PresaleEntry, SaleConfig,
+    };
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::to_binary;
+    use cosmwasm_std::{
+        from_binary, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Env, Extern, HumanAddr,
+        InitResponse, MessageInfo, StdError, StdResult, Uint128,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_with_supply(
+        public_token_supply: bool,
+        max_supply: Option<u32>,
+    ) -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+
+        let env = mock_env("instantiator", &[]);
+        let max_supply = max_supply.map_or("null".to_string(), |m| m.to_string());
+        let init_config: InitConfig = from_binary(&Binary::from(
+            format!(
+                "{{\"public_token_supply\":{},
+            \"public_owner\":true,
+            \"enable_burn\":true,
+            \"max_supply\":{}}}",
+                public_token_supply, max_supply,
+            )
+            .as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn env_at(sender: &str, sent: &[Coin], height: u64) -> Env {
+        let env = mock_env(sender, sent);
+        Env {
+            block: BlockInfo {
+                height,
+                ..env.block
+            },
+            message: MessageInfo {
+                sender: HumanAddr(sender.to_string()),
+                sent_funds: sent.to_vec(),
+            },
+            ..env
+        }
+    }
+
+    fn coins(amount: u128, denom: &str) -> Vec<Coin> {
+        vec![Coin {
+            denom: denom.to_string(),
+            amount: Uint128(amount),
+        }]
+    }
+
+    fn mint_msg(token_id: &str) -> HandleMsg {
+        HandleMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        }
+    }
+
+    // test the supply cap and the SupplyInfo query
+    #[test]
+    fn test_max_supply() {
+        let (init_result, mut deps) = init_helper_with_supply(false, Some(3));
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT1"));
+        assert!(handle_result.is_ok());
+        // test clones that would exceed the cap
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 3,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the maximum supply of 3 tokens"));
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 2,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT4"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the maximum supply of 3 tokens"));
+
+        // test private supply info
+        let query_msg = QueryMsg::SupplyInfo { viewer: None };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("The token supply of this contract is private"));
+
+        // test burning frees room under the cap
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let viewer = Some(ViewerInfo {
+            address: HumanAddr("admin".to_string()),
+            viewing_key: "key".to_string(),
+        });
+        let query_msg = QueryMsg::SupplyInfo {
+            viewer: viewer.clone(),
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SupplyInfo {
+                max_supply,
+                max_supply_includes_burned,
+                minted,
+                burned,
+                remaining,
+            } => {
+                assert_eq!(max_supply, Some(3));
+                assert!(!max_supply_includes_burned);
+                assert_eq!(minted, 3);
+                assert_eq!(burned, 1);
+                assert_eq!(remaining, Some(1));
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT4"));
+        assert!(handle_result.is_ok());
+        let query_msg = QueryMsg::SupplyInfo { viewer };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SupplyInfo { remaining, .. } => assert_eq!(remaining, Some(0)),
+            _ => panic!("unexpected"),
+        }
+
+        // test uncapped supply
+        let (init_result, deps) = init_helper_with_supply(true, None);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::SupplyInfo { viewer: None };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SupplyInfo {
+                max_supply,
+                max_supply_includes_burned,
+                minted,
+                burned,
+                remaining,
+            } => {
+                assert!(max_supply.is_none());
+                assert!(!max_supply_includes_burned);
+                assert_eq!(minted, 0);
+                assert_eq!(burned, 0);
+                assert!(remaining.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test a supply cap that counts burned tokens
+    #[test]
+    fn test_max_supply_includes_burned() {
+        let mut deps = mock_dependencies(20, &[]);
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(InitConfig {
+                public_token_supply: Some(true),
+                enable_burn: Some(true),
+                max_supply: Some(2),
+                max_supply_includes_burned: Some(true),
+                ..InitConfig::default()
+            }),
+            post_init_callback: None,
+        };
+        let init_result = init(&mut deps, mock_env("instantiator", &[]), init_msg);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT1"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT2"));
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test burning does not free room under the cap
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the maximum supply of 2 tokens"));
+        let query_msg = QueryMsg::SupplyInfo { viewer: None };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SupplyInfo {
+                max_supply,
+                max_supply_includes_burned,
+                minted,
+                burned,
+                remaining,
+            } => {
+                assert_eq!(max_supply, Some(2));
+                assert!(max_supply_includes_burned);
+                assert_eq!(minted, 2);
+                assert_eq!(burned, 1);
+                assert_eq!(remaining, Some(0));
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test per-minter quotas
+    #[test]
+    fn test_minter_quotas() {
+        let (init_result, mut deps) = init_helper_with_supply(true, None);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let handle_msg = HandleMsg::AddMinters {
+            minters: vec![HumanAddr("minter".to_string())],
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("Before"));
+        assert!(handle_result.is_ok());
+
+        // test non-admin setting a quota
+        let handle_msg = HandleMsg::SetMinterQuota {
+            minter: HumanAddr("minter".to_string()),
+            quota: Some(2),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        let handle_msg = HandleMsg::SetMinterQuota {
+            minter: HumanAddr("minter".to_string()),
+            quota: Some(2),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT1"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT2"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT3"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the minter's quota of 2 tokens"));
+        // test other minters are not affected
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), mint_msg("NFT3"));
+        assert!(handle_result.is_ok());
+
+        // test raising the quota keeps the minted count
+        let handle_msg = HandleMsg::SetMinterQuota {
+            minter: HumanAddr("minter".to_string()),
+            quota: Some(3),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT4"));
+        assert!(handle_result.is_ok());
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT5"));
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the minter's quota of 3 tokens"));
+
+        // test removing the quota
+        let handle_msg = HandleMsg::SetMinterQuota {
+            minter: HumanAddr("minter".to_string()),
+            quota: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_result = handle(&mut deps, mock_env("minter", &[]), mint_msg("NFT5"));
+        assert!(handle_result.is_ok());
+    }
+
+    // test the paid public mint and withdrawing its proceeds
+    #[test]
+    fn test_purchase_mint() {
+        let (init_result, mut deps) = init_helper_with_supply(true, Some(4));
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let alice = HumanAddr("alice".to_string());
+
+        // test purchasing before there is a sale
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 100),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There is no sale in progress"));
+
+        let sale = SaleConfig {
+            denom: "uscrt".to_string(),
+            price: Uint128(10),
+            limit_per_address: Some(2),
+            start: Some(Expiration::AtHeight(100)),
+            end: Some(Expiration::AtHeight(200)),
+        };
+        // test non-admin setting the sale
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: Some(sale.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: Some(sale.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let query_result = query(&deps, QueryMsg::SaleInfo {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SaleInfo { sale: shown, .. } => assert_eq!(shown, Some(sale.clone())),
+            _ => panic!("unexpected"),
+        }
+
+        // test the sale window
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 99),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The sale has not started yet"));
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 200),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The sale has ended"));
+
+        // test wrong payments
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The purchase price of 20uscrt must be sent with the purchase"));
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(20, "uatom"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The purchase price of 20uscrt must be sent with the purchase"));
+
+        // test a successful purchase
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: Some("launch".to_string()),
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(20, "uscrt"), 150),
+            handle_msg,
+        );
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::PurchaseMint { token_ids } => {
+                assert_eq!(token_ids, vec!["0".to_string(), "1".to_string()])
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(&deps, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, alice),
+            _ => panic!("unexpected"),
+        }
+        // test the purchaser can not rewrite the royalties of a purchased token
+        let handle_msg = HandleMsg::SetRoyaltyInfo {
+            token_id: Some("1".to_string()),
+            royalty_info: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, env_at("alice", &[], 150), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner"
+        ));
+
+        // test the per address limit
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Purchasing 1 tokens would exceed the limit of 2 tokens per address")
+        );
+
+        // test the supply cap still applies
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: Some(SaleConfig {
+                limit_per_address: None,
+                ..sale
+            }),
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: Some(3),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("bob", &coins(30, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting would exceed the maximum supply of 4 tokens"));
+
+        // test withdrawing the proceeds
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: Some(HumanAddr("treasury".to_string())),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                to_address: HumanAddr("treasury".to_string()),
+                amount: coins(20, "uscrt"),
+            })]
+        );
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There are no proceeds to withdraw"));
+
+        // test ending the sale
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: None,
+            padding: None,
+        };
+        let _handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let handle_msg = HandleMsg::PurchaseMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("bob", &coins(10, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There is no sale in progress"));
+    }
+
+    // test the allowlist presale
+    #[test]
+    fn test_presale_mint() {
+        let (init_result, mut deps) = init_helper_with_supply(true, None);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let presale = SaleConfig {
+            denom: "uscrt".to_string(),
+            price: Uint128(5),
+            limit_per_address: None,
+            start: None,
+            end: Some(Expiration::AtHeight(200)),
+        };
+        let entry = |address: &str, allocation: u32| PresaleEntry {
+            address: HumanAddr(address.to_string()),
+            allocation,
+        };
+        let allocation_of = |deps: &Extern<MockStorage, MockApi, MockQuerier>, address: &str| {
+            let query_msg = QueryMsg::PresaleAllocation {
+                address: HumanAddr(address.to_string()),
+                viewing_key: "key".to_string(),
+            };
+            let query_result = query(deps, query_msg);
+            match from_binary(&query_result.unwrap()).unwrap() {
+                QueryAnswer::PresaleAllocation { allocation } => allocation,
+                _ => panic!("unexpected"),
+            }
+        };
+        for address in ["alice", "bob", "charlie"].iter() {
+            let handle_msg = HandleMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            let _handle_result = handle(&mut deps, mock_env(*address, &[]), handle_msg);
+        }
+
+        // test presale purchase before there is a presale
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(5, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There is no presale in progress"));
+
+        // test non-admin managing the presale
+        let handle_msg = HandleMsg::SetPresaleConfig {
+            presale: Some(presale.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let handle_msg = HandleMsg::AddToPresaleList {
+            entries: vec![entry("alice", 5)],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        let handle_msg = HandleMsg::SetPresaleConfig {
+            presale: Some(presale.clone()),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        // test sending the list in chunks
+        let handle_msg = HandleMsg::SetPresaleList {
+            entries: vec![entry("alice", 2)],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        let handle_msg = HandleMsg::AddToPresaleList {
+            entries: vec![entry("bob", 1)],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(allocation_of(&deps, "alice"), 2);
+        assert_eq!(allocation_of(&deps, "bob"), 1);
+        assert_eq!(allocation_of(&deps, "charlie"), 0);
+
+        // test a wrong viewing key
+        let query_msg = QueryMsg::PresaleAllocation {
+            address: HumanAddr("alice".to_string()),
+            viewing_key: "wrong".to_string(),
+        };
+        let query_result = query(&deps, query_msg);
+        let error = extract_error_msg(query_result);
+        assert!(error.contains("Wrong viewing key"));
+
+        // test an address that is not on the list
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("charlie", &coins(5, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Purchasing 1 tokens would exceed your presale allocation of 0 tokens")
+        );
+
+        // test the presale price and window
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(5, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The purchase price of 10uscrt must be sent with the purchase"));
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 200),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The sale has ended"));
+
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: Some(2),
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(10, "uscrt"), 150),
+            handle_msg,
+        );
+        match from_binary(&handle_result.unwrap().data.unwrap()).unwrap() {
+            HandleAnswer::PresaleMint { token_ids } => assert_eq!(token_ids.len(), 2),
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(allocation_of(&deps, "alice"), 0);
+        let handle_msg = HandleMsg::PresaleMint {
+            quantity: None,
+            entropy: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(
+            &mut deps,
+            env_at("alice", &coins(5, "uscrt"), 150),
+            handle_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Purchasing 1 tokens would exceed your presale allocation of 0 tokens")
+        );
+
+        // test replacing the list discards the old allocations
+        let handle_msg = HandleMsg::SetPresaleList {
+            entries: vec![entry("charlie", 3)],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+        assert_eq!(allocation_of(&deps, "bob"), 0);
+        assert_eq!(allocation_of(&deps, "charlie"), 3);
+        let query_result = query(&deps, QueryMsg::SaleInfo {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SaleInfo {
+                sale,
+                presale: shown,
+                ..
+            } => {
+                assert!(sale.is_none());
+                assert_eq!(shown, Some(presale));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the presale proceeds can be withdrawn
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                from_address: HumanAddr(MOCK_CONTRACT_ADDR.to_string()),
+                to_address: HumanAddr("admin".to_string()),
+                amount: coins(10, "uscrt"),
+            })]
+        );
+    }
+
+    // test purchasing from the public sale with SNIP-20 tokens
+    #[test]
+    fn test_snip20_purchase() {
+        let mut deps = mock_dependencies(20, &[]);
+        let sscrt = PaymentToken {
+            address: HumanAddr("sscrt".to_string()),
+            code_hash: "sscrt hash".to_string(),
+            price: Uint128(100),
+        };
+        let init_config: InitConfig = from_binary(&Binary::from(
+            r#"{"public_token_supply":true,"public_owner":true}"#.as_bytes(),
+        ))
+        .unwrap();
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: Some(InitConfig {
+                payment_tokens: Some(vec![sscrt.clone()]),
+                ..init_config
+            }),
+            post_init_callback: None,
+        };
+        let env = mock_env("instantiator", &[]);
+        let my_code_hash = env.contract_code_hash.clone();
+        let init_result = init(&mut deps, env, init_msg);
+        // test the contract registers with the payment token
+        assert_eq!(
+            init_result.unwrap().messages,
+            vec![register_receive_msg(my_code_hash.clone(), &sscrt).unwrap()]
+        );
+        let handle_msg = HandleMsg::SetSaleConfig {
+            sale: Some(SaleConfig {
+                denom: "uscrt".to_string(),
+                price: Uint128(10),
+                limit_per_address: Some(3),
+                start: None,
+                end: None,
+            }),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        let purchase = |quantity: u32| {
+            Some(
+                to_binary(&ReceiveMsg::PurchaseMint {
+                    quantity: Some(quantity),
+                    entropy: None,
+                    memo: None,
+                })
+                .unwrap(),
+            )
+        };
+        // test payment from an unregistered token
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("alice".to_string()),
+            from: HumanAddr("alice".to_string()),
+            amount: Uint128(200),
+            msg: purchase(2),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("fake", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Payments from unregistered token contracts are not accepted"));
+
+        // test paying the wrong amount
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("alice".to_string()),
+            from: HumanAddr("alice".to_string()),
+            amount: Uint128(100),
+            msg: purchase(2),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The purchase price of 200 tokens must be sent with the purchase"));
+
+        // test missing ReceiveMsg
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("alice".to_string()),
+            from: HumanAddr("alice".to_string()),
+            amount: Uint128(200),
+            msg: None,
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("A ReceiveMsg must be sent with the SNIP-20 tokens"));
+
+        // test a successful purchase mints to the token owner
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("alice".to_string()),
+            from: HumanAddr("alice".to_string()),
+            amount: Uint128(200),
+            msg: purchase(2),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let answer: HandleAnswer = from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        let token_ids = match answer {
+            HandleAnswer::Receive { token_ids } => token_ids,
+            _ => panic!("unexpected"),
+        };
+        assert_eq!(token_ids.len(), 2);
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: token_ids[0].clone(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer = from_binary(&query(&deps, query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => {
+                assert_eq!(owner, HumanAddr("alice".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+        // test the purchase limit is shared with native purchases
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("alice".to_string()),
+            from: HumanAddr("alice".to_string()),
+            amount: Uint128(200),
+            msg: purchase(2),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("Purchasing 2 tokens would exceed the limit of 3 tokens per address")
+        );
+
+        // test changing the price and adding a token
+        let handle_msg = HandleMsg::AddPaymentTokens {
+            tokens: vec![PaymentToken {
+                price: Uint128(50),
+                ..sscrt.clone()
+            }],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("alice", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let repriced = PaymentToken {
+            price: Uint128(50),
+            ..sscrt
+        };
+        let handle_msg = HandleMsg::AddPaymentTokens {
+            tokens: vec![repriced.clone()],
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![register_receive_msg(my_code_hash, &repriced).unwrap()]
+        );
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, QueryMsg::SaleInfo {}).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::SaleInfo { payment_tokens, .. } => {
+                assert_eq!(payment_tokens, vec![repriced])
+            }
+            _ => panic!("unexpected"),
+        }
+        let handle_msg = HandleMsg::Receive {
+            sender: HumanAddr("bob".to_string()),
+            from: HumanAddr("bob".to_string()),
+            amount: Uint128(50),
+            msg: purchase(1),
+            memo: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("sscrt", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test withdrawing the SNIP-20 proceeds
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: Some(HumanAddr("treasury".to_string())),
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert_eq!(
+            handle_result.unwrap().messages,
+            vec![snip20_transfer_msg(
+                HumanAddr("treasury".to_string()),
+                Uint128(250),
+                "sscrt hash".to_string(),
+                HumanAddr("sscrt".to_string()),
+            )
+            .unwrap()]
+        );
+        let handle_msg = HandleMsg::WithdrawProceeds {
+            recipient: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There are no proceeds to withdraw"));
+    }
+}
@@ -0,0 +1,335 @@
+#[cfg(test)]
+mod tests {
+    use crate::contract::{handle, init, query};
+    use crate::msg::{HandleMsg, InitMsg, QueryAnswer, QueryMsg};
+    use crate::token::{Extension, Metadata, Trait};
+    use crate::trait_schema::{TraitRule, TraitSchema, TraitValueType};
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Extern, HandleResponse, HumanAddr, InitResponse, StdError, StdResult,
+    };
+    use std::any::Any;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<InitResponse>,
+        Extern<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies(20, &[]);
+        let env = mock_env("instantiator", &[]);
+
+        let init_msg = InitMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some(HumanAddr("admin".to_string())),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            collection_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (init(&mut deps, env, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn rule(
+        trait_type: &str,
+        value_type: TraitValueType,
+        min: Option<i64>,
+        max: Option<i64>,
+        required: bool,
+    ) -> TraitRule {
+        TraitRule {
+            trait_type: trait_type.to_string(),
+            value_type,
+            min,
+            max,
+            required: Some(required),
+        }
+    }
+
+    fn game_schema() -> TraitSchema {
+        TraitSchema {
+            traits: vec![
+                rule("Rarity", TraitValueType::String, None, None, true),
+                rule("Level", TraitValueType::Number, Some(1), Some(100), false),
+                rule("Birthday", TraitValueType::Date, None, None, false),
+            ],
+        }
+    }
+
+    fn attr(trait_type: &str, value: &str, max_value: Option<&str>) -> Trait {
+        Trait {
+            display_type: None,
+            trait_type: Some(trait_type.to_string()),
+            value: value.to_string(),
+            max_value: max_value.map(|m| m.to_string()),
+        }
+    }
+
+    fn meta_with(attributes: Vec<Trait>) -> Metadata {
+        Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                attributes: Some(attributes),
+                ..Extension::default()
+            }),
+        }
+    }
+
+    fn mint_msg(token_id: &str, public: Option<Metadata>, private: Option<Metadata>) -> HandleMsg {
+        HandleMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(HumanAddr("alice".to_string())),
+            public_metadata: public,
+            private_metadata: private,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            origin: None,
+            memo: None,
+            padding: None,
+        }
+    }
+
+    fn set_schema(
+        deps: &mut Extern<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        trait_schema: Option<TraitSchema>,
+    ) -> StdResult<HandleResponse> {
+        let handle_msg = HandleMsg::SetTraitSchema {
+            trait_schema,
+            padding: None,
+        };
+        handle(deps, mock_env(sender, &[]), handle_msg)
+    }
+
+    // test registering a trait schema
+    #[test]
+    fn test_set_trait_schema() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test non-admin
+        let error = extract_error_msg(set_schema(&mut deps, "alice", Some(game_schema())));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test inconsistent schemas
+        let mut schema = game_schema();
+        schema
+            .traits
+            .push(rule("Rarity", TraitValueType::String, None, None, false));
+        let error = extract_error_msg(set_schema(&mut deps, "admin", Some(schema)));
+        assert!(error.contains("Trait schema lists trait type Rarity more than once"));
+        let schema = TraitSchema {
+            traits: vec![rule("Name", TraitValueType::String, Some(1), None, false)],
+        };
+        let error = extract_error_msg(set_schema(&mut deps, "admin", Some(schema)));
+        assert!(error.contains("Trait Name is a string and can not have a range"));
+        let schema = TraitSchema {
+            traits: vec![rule(
+                "Level",
+                TraitValueType::Number,
+                Some(5),
+                Some(1),
+                false,
+            )],
+        };
+        let error = extract_error_msg(set_schema(&mut deps, "admin", Some(schema)));
+        assert!(error.contains("Trait Level has a minimum greater than its maximum"));
+
+        // sanity check
+        assert!(set_schema(&mut deps, "admin", Some(game_schema())).is_ok());
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, QueryMsg::TraitSchema {}).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TraitSchema { trait_schema } => {
+                assert_eq!(trait_schema, Some(game_schema()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test deleting the schema
+        assert!(set_schema(&mut deps, "admin", None).is_ok());
+        let query_answer: QueryAnswer =
+            from_binary(&query(&deps, QueryMsg::TraitSchema {}).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::TraitSchema { trait_schema } => assert!(trait_schema.is_none()),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test minted and updated metadata must follow the trait schema
+    #[test]
+    fn test_trait_schema_validation() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        assert!(set_schema(&mut deps, "admin", Some(game_schema())).is_ok());
+        let valid = || {
+            vec![
+                attr("Rarity", "Rare", None),
+                attr("Level", "5", Some("10")),
+                attr("Birthday", "1600000000", None),
+            ]
+        };
+        let cases = vec![
+            (
+                vec![attr("Level", "5", None)],
+                "Required trait Rarity is missing",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Hat", "Cap", None)],
+                "Trait type Hat is not allowed by the trait schema",
+            ),
+            (
+                vec![
+                    attr("Rarity", "Rare", None),
+                    Trait {
+                        trait_type: None,
+                        ..attr("Rarity", "Rare", None)
+                    },
+                ],
+                "Every attribute must have a trait_type",
+            ),
+            (
+                vec![attr("Rarity", "Rare", Some("9"))],
+                "Trait Rarity is a string and can not have a max_value",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Level", "five", None)],
+                "Trait Level value five is not an integer",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Level", "1.5", None)],
+                "Trait Level value 1.5 is not an integer",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Level", "0", None)],
+                "Trait Level value 0 is below the minimum of 1",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Level", "500", None)],
+                "Trait Level value 500 is above the maximum of 100",
+            ),
+            (
+                vec![
+                    attr("Rarity", "Rare", None),
+                    attr("Level", "5", Some("200")),
+                ],
+                "Trait Level max_value 200 is above the maximum of 100",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Level", "8", Some("5"))],
+                "Trait Level value 8 exceeds its max_value 5",
+            ),
+            (
+                vec![attr("Rarity", "Rare", None), attr("Birthday", "-5", None)],
+                "Trait Birthday value -5 is not a timestamp in seconds",
+            ),
+        ];
+        for (attributes, expected) in cases.into_iter() {
+            let handle_msg = mint_msg("NFT", Some(meta_with(attributes)), None);
+            let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+            let error = extract_error_msg(handle_result);
+            assert!(error.contains(expected), "{} != {}", error, expected);
+        }
+
+        // test private metadata is validated
+        let handle_msg = mint_msg(
+            "NFT",
+            Some(meta_with(valid())),
+            Some(meta_with(vec![attr("Level", "5", None)])),
+        );
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Required trait Rarity is missing"));
+
+        // test clones are validated
+        let handle_msg = HandleMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 2,
+            owner: None,
+            public_metadata: Some(meta_with(vec![attr("Level", "5", None)])),
+            private_metadata: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Required trait Rarity is missing"));
+
+        // test metadata without an extension is rejected when a trait is required
+        let uri_meta = || Metadata {
+            token_uri: Some("uri".to_string()),
+            extension: None,
+        };
+        let handle_msg = mint_msg("URI", Some(uri_meta()), None);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Metadata must have an extension with the required trait Rarity"));
+
+        // sanity check: valid metadata
+        let handle_msg = mint_msg("NFT", Some(meta_with(valid())), None);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test updated metadata is validated
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT".to_string(),
+            public_metadata: Some(meta_with(vec![attr("Rarity", "Rare", Some("1"))])),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Trait Rarity is a string and can not have a max_value"));
+        let handle_msg = HandleMsg::SetMetadata {
+            token_id: "NFT".to_string(),
+            public_metadata: Some(meta_with(vec![attr("Rarity", "Common", None)])),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test metadata without an extension is accepted when no trait is required
+        let mut optional_schema = game_schema();
+        optional_schema.traits[0].required = None;
+        assert!(set_schema(&mut deps, "admin", Some(optional_schema)).is_ok());
+        let handle_msg = mint_msg("URI", Some(uri_meta()), None);
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+
+        // test metadata is no longer validated once the schema is deleted
+        assert!(set_schema(&mut deps, "admin", None).is_ok());
+        let handle_msg = mint_msg(
+            "FREE",
+            Some(meta_with(vec![attr("Hat", "Cap", Some("9"))])),
+            None,
+        );
+        let handle_result = handle(&mut deps, mock_env("admin", &[]), handle_msg);
+        assert!(handle_result.is_ok());
+    }
+}